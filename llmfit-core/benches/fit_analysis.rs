@@ -0,0 +1,33 @@
+//! One `ModelFit::analyze` pass over the whole bundled model database, the
+//! work the TUI does at startup, on a fixed 64 GB / RTX 4090 system.
+//!
+//! Run with `cargo bench -p llmfit-core --bench fit_analysis`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use llmfit_core::fit::ModelFit;
+use llmfit_core::hardware::{GpuBackend, GpuInfo, SystemSpecs};
+use llmfit_core::models::ModelDatabase;
+
+fn fit_analysis(c: &mut Criterion) {
+    let db = ModelDatabase::new();
+    let models = db.get_all_models();
+    let system = SystemSpecs::new(64.0, 48.0, 16).with_gpu(GpuInfo {
+        name: "NVIDIA GeForce RTX 4090".to_string(),
+        vram_gb: Some(24.0),
+        backend: GpuBackend::Cuda,
+        count: 1,
+        unified_memory: false,
+        driver_version: None,
+    });
+    c.bench_function("analyze_all_models", |b| {
+        b.iter(|| {
+            models
+                .iter()
+                .map(|m| ModelFit::analyze(m, &system))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+criterion_group!(benches, fit_analysis);
+criterion_main!(benches);
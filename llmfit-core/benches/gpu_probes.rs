@@ -0,0 +1,42 @@
+//! Parallel vs sequential GPU probing with mock probes that each stand in for
+//! a slow vendor tool (nvidia-smi, rocm-smi, vulkaninfo, ...).
+//!
+//! Run with `cargo bench -p llmfit-core --bench gpu_probes`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use llmfit_core::hardware::{GpuBackend, GpuInfo, GpuProbe, run_gpu_probes};
+use std::time::Duration;
+
+const PROBES: usize = 8;
+const PROBE_LATENCY: Duration = Duration::from_millis(5);
+
+fn mock_probe(index: usize) -> Vec<GpuInfo> {
+    std::thread::sleep(PROBE_LATENCY);
+    vec![GpuInfo {
+        name: format!("Mock GPU {index}"),
+        vram_gb: Some(8.0),
+        backend: GpuBackend::Vulkan,
+        count: 1,
+        unified_memory: false,
+        driver_version: None,
+    }]
+}
+
+fn gpu_probes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gpu_probes");
+    group.bench_function("sequential", |b| {
+        b.iter(|| (0..PROBES).map(mock_probe).collect::<Vec<_>>())
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| {
+            let probes: Vec<GpuProbe> = (0..PROBES)
+                .map(|i| Box::new(move || mock_probe(i)) as GpuProbe)
+                .collect();
+            run_gpu_probes(probes, Duration::from_secs(5))
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, gpu_probes);
+criterion_main!(benches);
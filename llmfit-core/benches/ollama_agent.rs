@@ -0,0 +1,76 @@
+//! `OllamaProvider::is_available()` over its shared keep-alive agent vs a
+//! fresh connection per call (plain `ureq::get`, the previous behaviour),
+//! against a local stub that answers `/api/tags` like Ollama.
+//!
+//! Run with `cargo bench -p llmfit-core --bench ollama_agent`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use llmfit_core::providers::{ModelProvider, OllamaProvider};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+const CALLS: usize = 100;
+const TAGS_BODY: &str = r#"{"models":[]}"#;
+
+/// Answer every request on `stream` until the client hangs up, keeping the
+/// connection open between requests.
+fn serve_connection(stream: TcpStream) -> std::io::Result<()> {
+    // Like Go's net/http (which Ollama uses), disable Nagle so small
+    // responses aren't held back on a reused connection.
+    stream.set_nodelay(true)?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{TAGS_BODY}",
+        TAGS_BODY.len()
+    );
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        // Skip the remaining headers; GET requests carry no body.
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? <= 2 {
+                break;
+            }
+        }
+        writer.write_all(response.as_bytes())?;
+    }
+}
+
+fn start_stub_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind stub server");
+    let addr = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            std::thread::spawn(move || serve_connection(stream));
+        }
+    });
+    format!("http://{addr}")
+}
+
+fn ollama_agent(c: &mut Criterion) {
+    let base_url = start_stub_server();
+    // SAFETY: the stub server thread never touches the environment.
+    unsafe { std::env::set_var("OLLAMA_HOST", &base_url) };
+    let provider = OllamaProvider::new();
+    let tags_url = format!("{base_url}/api/tags");
+
+    let mut group = c.benchmark_group("ollama_is_available_x100");
+    group.bench_function("new_connection_per_call", |b| {
+        b.iter(|| {
+            (0..CALLS)
+                .filter(|_| ureq::get(&tags_url).call().is_ok())
+                .count()
+        })
+    });
+    group.bench_function("shared_agent", |b| {
+        b.iter(|| (0..CALLS).filter(|_| provider.is_available()).count())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, ollama_agent);
+criterion_main!(benches);
@@ -2,8 +2,8 @@ use crate::fit::{InferenceRuntime, ModelFit};
 use crate::hardware::SystemSpecs;
 use crate::models::ModelDatabase;
 use crate::providers::{
-    self, DockerModelRunnerProvider, LlamaCppProvider, LmStudioProvider, MlxProvider,
-    ModelProvider, OllamaProvider, RamaLamaProvider, VllmProvider,
+    self, DockerModelRunnerProvider, Gpt4AllProvider, JanProvider, LlamaCppProvider,
+    LmStudioProvider, MlxProvider, ModelProvider, OllamaProvider, RamaLamaProvider, VllmProvider,
 };
 use std::collections::HashSet;
 
@@ -27,6 +27,10 @@ pub struct InstalledIndex {
     pub vllm_count: usize,
     pub ramalama: HashSet<String>,
     pub ramalama_count: usize,
+    pub jan: HashSet<String>,
+    pub jan_count: usize,
+    pub gpt4all: HashSet<String>,
+    pub gpt4all_count: usize,
 }
 
 impl InstalledIndex {
@@ -46,66 +50,76 @@ impl InstalledIndex {
             vllm_count: 0,
             ramalama: HashSet::new(),
             ramalama_count: 0,
+            jan: HashSet::new(),
+            jan_count: 0,
+            gpt4all: HashSet::new(),
+            gpt4all_count: 0,
         }
     }
 
+    /// Installed Ollama tags with no catalog entry among `catalog_names`
+    /// (see [`providers::ollama_orphan_tags`]).
+    pub fn ollama_orphans<'a>(
+        &self,
+        catalog_names: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<String> {
+        // The set holds each installed `name:tag` plus its bare family name
+        // for matching; only the full tags are installs.
+        let tags = self
+            .ollama
+            .iter()
+            .filter(|name| name.contains(':'))
+            .map(String::as_str);
+        providers::ollama_orphan_tags(tags, catalog_names)
+    }
+
     /// Detect installed models across all providers in parallel.
     ///
     /// Each provider query is issued on its own thread so that a single
     /// offline/slow backend (worst case ~1.5 s timeout) doesn't serialize
     /// into ~9 s of total blocking time for the CLI path.
     pub fn detect_all() -> Self {
-        std::thread::scope(|s| {
-            let ollama = s.spawn(|| {
-                let p = OllamaProvider::new();
-                p.installed_models_counted()
-            });
-            let mlx = s.spawn(|| MlxProvider::new().installed_models());
-            let llamacpp = s.spawn(|| {
-                let p = LlamaCppProvider::new();
-                p.installed_models_counted()
-            });
-            let docker_mr = s.spawn(|| {
-                let p = DockerModelRunnerProvider::new();
-                p.installed_models_counted()
-            });
-            let lmstudio = s.spawn(|| {
-                let p = LmStudioProvider::new();
-                p.installed_models_counted()
-            });
-            let vllm = s.spawn(|| {
-                let p = VllmProvider::new();
-                p.installed_models_counted()
-            });
-            let ramalama = s.spawn(|| {
-                let p = RamaLamaProvider::new();
-                p.installed_models_counted()
-            });
-
-            let (ollama, ollama_count) = ollama.join().unwrap();
-            let mlx = mlx.join().unwrap();
-            let (llamacpp, llamacpp_count) = llamacpp.join().unwrap();
-            let (docker_mr, docker_mr_count) = docker_mr.join().unwrap();
-            let (lmstudio, lmstudio_count) = lmstudio.join().unwrap();
-            let (vllm, vllm_count) = vllm.join().unwrap();
-            let (ramalama, ramalama_count) = ramalama.join().unwrap();
-
-            Self {
-                ollama,
-                ollama_count,
-                mlx,
-                llamacpp,
-                llamacpp_count,
-                docker_mr,
-                docker_mr_count,
-                lmstudio,
-                lmstudio_count,
-                vllm,
-                vllm_count,
-                ramalama,
-                ramalama_count,
-            }
-        })
+        let [
+            (ollama, ollama_count),
+            (mlx, _),
+            (llamacpp, llamacpp_count),
+            (docker_mr, docker_mr_count),
+            (lmstudio, lmstudio_count),
+            (vllm, vllm_count),
+            (ramalama, ramalama_count),
+            (jan, jan_count),
+            (gpt4all, gpt4all_count),
+        ] = probe_concurrently([
+            || OllamaProvider::new().installed_models_counted(),
+            || (MlxProvider::new().installed_models(), 0),
+            || LlamaCppProvider::new().installed_models_counted(),
+            || DockerModelRunnerProvider::new().installed_models_counted(),
+            || LmStudioProvider::new().installed_models_counted(),
+            || VllmProvider::new().installed_models_counted(),
+            || RamaLamaProvider::new().installed_models_counted(),
+            || JanProvider::new().installed_models_counted(),
+            || Gpt4AllProvider::new().installed_models_counted(),
+        ]);
+
+        Self {
+            ollama,
+            ollama_count,
+            mlx,
+            llamacpp,
+            llamacpp_count,
+            docker_mr,
+            docker_mr_count,
+            lmstudio,
+            lmstudio_count,
+            vllm,
+            vllm_count,
+            ramalama,
+            ramalama_count,
+            jan,
+            jan_count,
+            gpt4all,
+            gpt4all_count,
+        }
     }
 
     /// Returns `true` when the model is installed in **any** provider.
@@ -117,6 +131,23 @@ impl InstalledIndex {
             || providers::is_model_installed_lmstudio(model_name, &self.lmstudio)
             || providers::is_model_installed_vllm(model_name, &self.vllm)
             || providers::is_model_installed_ramalama(model_name, &self.ramalama)
+            // Jan and GPT4All store GGUF files, so reuse the llama.cpp matcher.
+            || providers::is_model_installed_llamacpp(model_name, &self.jan)
+            || providers::is_model_installed_llamacpp(model_name, &self.gpt4all)
+    }
+
+    /// The provider llmfit uses for this model when several have it: the
+    /// first of [`Self::installed_providers`], so Ollama, then MLX, then the
+    /// GGUF scanners.
+    pub fn primary_provider(&self, model_name: &str) -> Option<&'static str> {
+        self.installed_providers(model_name).first().copied()
+    }
+
+    /// Set `fit.installed` and `fit.installed_provider` from this index.
+    pub fn mark(&self, fit: &mut ModelFit) {
+        let provider = self.primary_provider(&fit.model.name);
+        fit.installed = provider.is_some();
+        fit.installed_provider = provider.map(str::to_string);
     }
 
     /// Returns the display names of all providers that have this model
@@ -144,10 +175,27 @@ impl InstalledIndex {
         if providers::is_model_installed_ramalama(model_name, &self.ramalama) {
             out.push("RamaLama");
         }
+        if providers::is_model_installed_llamacpp(model_name, &self.jan) {
+            out.push("Jan");
+        }
+        if providers::is_model_installed_llamacpp(model_name, &self.gpt4all) {
+            out.push("GPT4All");
+        }
         out
     }
 }
 
+/// Run each provider probe on its own thread and return the results in
+/// input order, so startup waits for the slowest probe rather than the sum
+/// of them all, and the merge never depends on which thread finished first.
+pub fn probe_concurrently<T: Send, const N: usize>(probes: [fn() -> T; N]) -> [T; N] {
+    std::thread::scope(|s| {
+        probes
+            .map(|probe| s.spawn(probe))
+            .map(|handle| handle.join().expect("provider probe panicked"))
+    })
+}
+
 /// Build a complete `Vec<ModelFit>` with installed markers populated.
 ///
 /// Filters models that are backend-incompatible, runs fit analysis, marks
@@ -159,6 +207,36 @@ pub fn build_model_fits(
     installed: &InstalledIndex,
     context_limit: Option<u32>,
     forced_runtime: Option<InferenceRuntime>,
+) -> Vec<ModelFit> {
+    build_model_fits_with_batch_size(db, specs, installed, context_limit, forced_runtime, 1)
+}
+
+/// [`build_model_fits`] for a server handling `batch_size` concurrent
+/// requests, each with its own KV cache.
+pub fn build_model_fits_with_batch_size(
+    db: &ModelDatabase,
+    specs: &SystemSpecs,
+    installed: &InstalledIndex,
+    context_limit: Option<u32>,
+    forced_runtime: Option<InferenceRuntime>,
+    batch_size: u32,
+) -> Vec<ModelFit> {
+    let config = crate::fit::CalcConfig {
+        batch_size,
+        ..crate::fit::CalcConfig::default()
+    };
+    build_model_fits_with_config(db, specs, installed, context_limit, forced_runtime, &config)
+}
+
+/// [`build_model_fits`] with a custom calculation configuration: batch size,
+/// forced quant, scoring weights, quant preference and so on.
+pub fn build_model_fits_with_config(
+    db: &ModelDatabase,
+    specs: &SystemSpecs,
+    installed: &InstalledIndex,
+    context_limit: Option<u32>,
+    forced_runtime: Option<InferenceRuntime>,
+    config: &crate::fit::CalcConfig,
 ) -> Vec<ModelFit> {
     use crate::fit::backend_compatible;
 
@@ -174,9 +252,14 @@ pub fn build_model_fits(
         .iter()
         .filter(|m| backend_compatible(m, specs))
         .map(|m| {
-            let mut fit =
-                ModelFit::analyze_with_forced_runtime(m, specs, context_limit, forced_runtime);
-            fit.installed = installed.is_installed(&m.name);
+            let mut fit = ModelFit::analyze_with_runtime_and_config(
+                m,
+                specs,
+                context_limit,
+                forced_runtime,
+                config.clone(),
+            );
+            installed.mark(&mut fit);
             fit.measured_tps = local_index
                 .as_ref()
                 .and_then(|idx| idx.lookup(&m.name))
@@ -190,9 +273,48 @@ pub fn build_model_fits(
         })
         .collect();
     apply_local_calibration(&mut fits);
+    apply_installed_family_bonus(&mut fits, &config.scoring_weights);
     fits
 }
 
+/// Prefix of the note [`apply_installed_family_bonus`] adds, so a re-run
+/// can find and replace it.
+const FAMILY_BONUS_NOTE: &str = "Same family as an installed model";
+
+/// Raise the score of every model that shares a family with an installed
+/// one by `weights.installed_family_bonus`, so someone already running Qwen
+/// sees more Qwen variants first (shared tooling, prompt formats, quants).
+/// A zero bonus, the default, leaves fits untouched.
+///
+/// Idempotent: scores are rebuilt from `score_components`, so re-applying
+/// after installs change never compounds. Call it after
+/// [`InstalledIndex::mark`] and before ranking.
+pub fn apply_installed_family_bonus(fits: &mut [ModelFit], weights: &crate::fit::ScoringWeights) {
+    let bonus = weights.installed_family_bonus;
+    if bonus <= 0.0 {
+        return;
+    }
+
+    let families: HashSet<String> = fits
+        .iter()
+        .filter(|f| f.installed && !f.model.family.is_empty())
+        .map(|f| f.model.family.to_lowercase())
+        .collect();
+    for fit in fits.iter_mut() {
+        let base = fit.score_components.total_weighted(weights, fit.use_case);
+        fit.notes.retain(|n| !n.starts_with(FAMILY_BONUS_NOTE));
+        if families.contains(&fit.model.family.to_lowercase()) {
+            fit.score = (base + bonus).min(100.0);
+            fit.add_note(format!(
+                "{FAMILY_BONUS_NOTE} ({}): +{bonus:.0} ranking bonus",
+                fit.model.family
+            ));
+        } else {
+            fit.score = base;
+        }
+    }
+}
+
 /// Calibrate formula estimates from benchmark runs made on this exact
 /// hardware: the user's own local runs, plus llmfit community submissions
 /// recorded on an identical configuration (so a fresh install benefits the
@@ -246,6 +368,185 @@ pub fn apply_local_calibration(fits: &mut [ModelFit]) {
     }
 }
 
+/// Tool an install script uses to fetch a model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallSource {
+    /// `ollama pull <tag>`
+    Ollama,
+    /// `mlx_lm.convert --hf-path <repo>`
+    Mlx,
+    /// `huggingface-cli download <gguf repo>`
+    Gguf,
+}
+
+impl InstallSource {
+    /// Parse a provider name as accepted by `--provider`.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "ollama" => Some(Self::Ollama),
+            "mlx" => Some(Self::Mlx),
+            "gguf" | "llamacpp" | "llama.cpp" | "llama_cpp" | "huggingface" | "hf" => {
+                Some(Self::Gguf)
+            }
+            _ => None,
+        }
+    }
+
+    /// Pick the source for a fit: MLX for models that run on MLX, then an
+    /// Ollama tag when one is known, then the model's GGUF repo.
+    fn preferred_for(fit: &ModelFit) -> Option<Self> {
+        if fit.runtime == InferenceRuntime::Mlx {
+            return Some(Self::Mlx);
+        }
+        [Self::Ollama, Self::Gguf]
+            .into_iter()
+            .find(|source| install_command(fit, *source).is_some())
+    }
+}
+
+/// The shell command that downloads `fit` with `source`, or `None` when the
+/// model has nothing to fetch from that source.
+pub fn install_command(fit: &ModelFit, source: InstallSource) -> Option<String> {
+    match source {
+        InstallSource::Ollama => {
+            providers::ollama_pull_tag(&fit.model.name).map(|tag| format!("ollama pull {tag}"))
+        }
+        InstallSource::Mlx => Some(format!("mlx_lm.convert --hf-path {}", fit.model.name)),
+        InstallSource::Gguf => {
+            let repo = fit
+                .model
+                .gguf_sources
+                .first()
+                .map(|s| s.repo.clone())
+                .or_else(|| providers::gguf_pull_tag(&fit.model.name))?;
+            Some(format!(
+                "huggingface-cli download {repo} --include \"*{}*.gguf\"",
+                fit.best_quant
+            ))
+        }
+    }
+}
+
+/// A Bash script that installs every fit in `fits`, one command per model
+/// with a comment naming it, its score and its download size. With `source`
+/// set, only models available from that source are included; otherwise each
+/// model uses the source it would be pulled from in the TUI.
+pub fn install_script(fits: &[&ModelFit], source: Option<InstallSource>) -> String {
+    let mut out = String::from(
+        "#!/usr/bin/env bash\n\
+         # Install models that fit this machine. Generated by llmfit.\n\
+         set -euo pipefail\n",
+    );
+    for fit in fits {
+        let Some(command) = source
+            .or_else(|| InstallSource::preferred_for(fit))
+            .and_then(|s| install_command(fit, s))
+        else {
+            continue;
+        };
+        out.push_str(&format!(
+            "\n# {} — score {:.0}, {:.1} GB ({})\n{command}\n",
+            fit.model.name,
+            fit.score,
+            fit.model.estimate_disk_gb(&fit.best_quant),
+            fit.best_quant,
+        ));
+    }
+    out
+}
+
+/// Shortest native context a RAG generator needs: retrieved passages fill
+/// most of the prompt.
+pub const RAG_MIN_CONTEXT: u32 = 32_768;
+
+/// (quality, speed, fit, context) weights for ranking RAG generators.
+/// Context counts for more than in plain chat.
+const RAG_GENERATOR_WEIGHTS: [f64; 4] = [0.40, 0.20, 0.10, 0.30];
+
+/// A generator and an embedding model that run on this machine together,
+/// from [`recommend_rag_pair`].
+#[derive(Clone)]
+pub struct RagPair {
+    pub generator: ModelFit,
+    pub embedder: ModelFit,
+    /// Generator score under the RAG weighting (context-heavy).
+    pub generator_score: f64,
+    /// Memory the two need together.
+    pub combined_memory_gb: f64,
+    /// Whether both draw on the same memory pool (both on the GPU, or both
+    /// on the CPU). Only then must `combined_memory_gb` fit in one pool.
+    pub shared_pool: bool,
+}
+
+/// Whether `fit` can serve as a RAG generator: a text model that follows
+/// instructions, has at least [`RAG_MIN_CONTEXT`] of native context, and
+/// fits this machine.
+pub fn is_rag_generator(fit: &ModelFit) -> bool {
+    use crate::models::{Capability, UseCase};
+    let name = fit.model.name.to_lowercase();
+    let described = fit.model.use_case.to_lowercase();
+    let instruct = ["instruct", "chat", "-it"].iter().any(|k| name.contains(k))
+        || described.contains("instruction")
+        || described.contains("chat");
+    fit.fit_level != crate::fit::FitLevel::TooTight
+        && matches!(
+            fit.use_case,
+            UseCase::General | UseCase::Chat | UseCase::Reasoning
+        )
+        && !fit.model.capabilities.contains(&Capability::Tts)
+        && fit.model.context_length >= RAG_MIN_CONTEXT
+        && instruct
+}
+
+/// Score of a RAG generator candidate: its score components re-weighted
+/// to favour long context.
+pub fn rag_generator_score(fit: &ModelFit) -> f64 {
+    let weights = crate::fit::ScoringWeights::uniform(RAG_GENERATOR_WEIGHTS);
+    fit.score_components.total_weighted(&weights, fit.use_case)
+}
+
+/// The best generator + embedding model pair for retrieval-augmented
+/// generation that fits on this machine at the same time. Generators are
+/// ranked by [`rag_generator_score`] and take the best-scoring embedding
+/// model that fits alongside. When both use the same memory pool, their
+/// requirements together must fit in it. `None` when no pair fits.
+pub fn recommend_rag_pair(fits: &[ModelFit]) -> Option<RagPair> {
+    let mut generators: Vec<(&ModelFit, f64)> = fits
+        .iter()
+        .filter(|f| is_rag_generator(f))
+        .map(|f| (f, rag_generator_score(f)))
+        .collect();
+    generators.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    let mut embedders: Vec<&ModelFit> = fits
+        .iter()
+        .filter(|f| {
+            f.use_case == crate::models::UseCase::Embedding
+                && f.fit_level != crate::fit::FitLevel::TooTight
+        })
+        .collect();
+    embedders.sort();
+
+    generators
+        .into_iter()
+        .find_map(|(generator, generator_score)| {
+            embedders.iter().find_map(|embedder| {
+                let combined_memory_gb = generator.memory_required_gb + embedder.memory_required_gb;
+                let shared_pool = (generator.run_mode == crate::fit::RunMode::CpuOnly)
+                    == (embedder.run_mode == crate::fit::RunMode::CpuOnly);
+                let pool_gb = generator
+                    .memory_available_gb
+                    .min(embedder.memory_available_gb);
+                (!shared_pool || combined_memory_gb <= pool_gb).then(|| RagPair {
+                    generator: generator.clone(),
+                    embedder: (*embedder).clone(),
+                    generator_score,
+                    combined_memory_gb,
+                    shared_pool,
+                })
+            })
+        })
+}
+
 fn median(sorted: &[f64]) -> f64 {
     let n = sorted.len();
     if n % 2 == 1 {
@@ -255,6 +556,65 @@ fn median(sorted: &[f64]) -> f64 {
     }
 }
 
+#[cfg(test)]
+mod rag_tests {
+    use super::*;
+    use crate::models::UseCase;
+
+    fn fits_for(ram_gb: f64) -> Vec<ModelFit> {
+        let specs = SystemSpecs::new(ram_gb, ram_gb * 0.8, 8);
+        ModelFit::analyze_many(ModelDatabase::new().get_all_models(), &specs)
+    }
+
+    #[test]
+    fn rag_pair_fits_together_and_suits_rag() {
+        for ram_gb in [16.0, 64.0] {
+            let fits = fits_for(ram_gb);
+            let pair = recommend_rag_pair(&fits).expect("catalog has a RAG pair");
+
+            assert_eq!(pair.embedder.use_case, UseCase::Embedding);
+            assert!(is_rag_generator(&pair.generator));
+            assert!(pair.generator.model.context_length >= RAG_MIN_CONTEXT);
+            // CPU-only: one pool, so both must fit in it at once.
+            assert!(pair.shared_pool);
+            assert!(pair.combined_memory_gb <= pair.generator.memory_available_gb);
+            assert_eq!(
+                pair.combined_memory_gb,
+                pair.generator.memory_required_gb + pair.embedder.memory_required_gb
+            );
+            // No eligible generator ranks above the one picked, unless it
+            // leaves no room for any embedding model.
+            let smallest_embedder = fits
+                .iter()
+                .filter(|f| f.use_case == UseCase::Embedding)
+                .map(|f| f.memory_required_gb)
+                .fold(f64::INFINITY, f64::min);
+            assert!(fits.iter().filter(|f| is_rag_generator(f)).all(|f| {
+                rag_generator_score(f) <= pair.generator_score
+                    || f.memory_required_gb + smallest_embedder > f.memory_available_gb
+            }));
+        }
+    }
+
+    #[test]
+    fn no_rag_pair_without_room_for_both() {
+        let fits = fits_for(64.0);
+        let mut cramped: Vec<ModelFit> = fits
+            .into_iter()
+            .filter(|f| is_rag_generator(f) || f.use_case == UseCase::Embedding)
+            .collect();
+        // Each fits alone but no generator leaves room for an embedder.
+        for fit in &mut cramped {
+            fit.memory_available_gb = if fit.use_case == UseCase::Embedding {
+                fit.memory_required_gb
+            } else {
+                fit.memory_required_gb + 0.001
+            };
+        }
+        assert!(recommend_rag_pair(&cramped).is_none());
+    }
+}
+
 #[cfg(test)]
 mod calibration_tests {
     use super::*;
@@ -266,3 +626,298 @@ mod calibration_tests {
         assert_eq!(median(&[0.1, 0.2, 0.9]), 0.2);
     }
 }
+
+#[cfg(test)]
+mod install_script_tests {
+    use super::*;
+    use crate::hardware::GpuBackend;
+
+    fn fits() -> Vec<ModelFit> {
+        let specs = SystemSpecs {
+            total_ram_gb: 64.0,
+            available_ram_gb: 48.0,
+            total_cpu_cores: 8,
+            cpu_name: "Test CPU".to_string(),
+            has_gpu: true,
+            gpu_vram_gb: Some(24.0),
+            total_gpu_vram_gb: Some(24.0),
+            gpu_available_gb: None,
+            gpu_name: Some("Test GPU".to_string()),
+            gpu_count: 1,
+            unified_memory: false,
+            backend: GpuBackend::Cuda,
+            gpus: vec![],
+            cluster_mode: false,
+            cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
+        };
+        ModelDatabase::new()
+            .get_all_models()
+            .iter()
+            .map(|m| ModelFit::analyze(m, &specs))
+            .collect()
+    }
+
+    #[test]
+    fn script_pulls_ollama_tags_with_a_comment_per_model() {
+        let fits = fits();
+        let fit = fits
+            .iter()
+            .find(|f| providers::ollama_pull_tag(&f.model.name).is_some())
+            .expect("catalog has models with Ollama tags");
+        let tag = providers::ollama_pull_tag(&fit.model.name).unwrap();
+
+        let script = install_script(&[fit], Some(InstallSource::Ollama));
+        assert!(script.starts_with("#!/usr/bin/env bash\n"));
+        assert!(script.contains(&format!("# {} — score", fit.model.name)));
+        assert!(script.contains(&format!("\nollama pull {tag}\n")));
+    }
+
+    #[test]
+    fn script_skips_models_missing_from_the_requested_source() {
+        let fits = fits();
+        let fit = fits
+            .iter()
+            .find(|f| {
+                providers::ollama_pull_tag(&f.model.name).is_none()
+                    && !f.model.gguf_sources.is_empty()
+            })
+            .expect("catalog has GGUF-only models");
+
+        let script = install_script(&[fit], Some(InstallSource::Ollama));
+        assert!(!script.contains(&fit.model.name));
+
+        let script = install_script(&[fit], Some(InstallSource::Gguf));
+        assert!(script.contains(&format!(
+            "huggingface-cli download {}",
+            fit.model.gguf_sources[0].repo
+        )));
+    }
+}
+
+#[cfg(test)]
+mod installed_index_tests {
+    use super::*;
+    use crate::fit::{ScoreComponents, ScoringWeights};
+
+    fn cpu_specs() -> SystemSpecs {
+        SystemSpecs {
+            total_ram_gb: 64.0,
+            available_ram_gb: 48.0,
+            total_cpu_cores: 8,
+            cpu_name: "Test CPU".to_string(),
+            has_gpu: false,
+            gpu_vram_gb: None,
+            total_gpu_vram_gb: None,
+            gpu_available_gb: None,
+            gpu_name: None,
+            gpu_count: 0,
+            unified_memory: false,
+            backend: crate::hardware::GpuBackend::CpuX86,
+            gpus: vec![],
+            cluster_mode: false,
+            cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
+        }
+    }
+
+    #[test]
+    fn primary_provider_prefers_ollama_then_mlx() {
+        let name = "Qwen/Qwen2.5-Coder-14B-Instruct";
+        let db = ModelDatabase::new();
+        let model = db
+            .get_all_models()
+            .iter()
+            .find(|m| m.name == name)
+            .expect("catalog has Qwen2.5-Coder-14B");
+        let specs = cpu_specs();
+        let mut index = InstalledIndex::empty();
+
+        let mut fit = ModelFit::analyze(model, &specs);
+        index.mark(&mut fit);
+        assert!(!fit.installed);
+        assert_eq!(fit.installed_provider, None);
+
+        index
+            .mlx
+            .insert("qwen2.5-coder-14b-instruct-8bit".to_string());
+        index.mark(&mut fit);
+        assert!(fit.installed);
+        assert_eq!(fit.installed_provider.as_deref(), Some("MLX"));
+
+        // Present in both: Ollama wins.
+        index.ollama.insert("qwen2.5-coder:14b".to_string());
+        index.ollama.insert("qwen2.5-coder".to_string());
+        index.mark(&mut fit);
+        assert_eq!(fit.installed_provider.as_deref(), Some("Ollama"));
+        assert_eq!(index.installed_providers(name), ["Ollama", "MLX"]);
+    }
+
+    #[test]
+    fn ollama_orphans_ignore_family_stems_in_the_index() {
+        let mut index = InstalledIndex::empty();
+        // Shaped like build_installed_set's output: full tag plus family.
+        for name in [
+            "qwen2.5-coder:14b",
+            "qwen2.5-coder",
+            "pirate-llm:7b",
+            "pirate-llm",
+        ] {
+            index.ollama.insert(name.to_string());
+        }
+        let catalog = ["Qwen/Qwen2.5-Coder-14B-Instruct"];
+        assert_eq!(index.ollama_orphans(catalog), ["pirate-llm:7b"]);
+    }
+
+    #[test]
+    fn build_model_fits_applies_the_configured_family_bonus() {
+        let db = ModelDatabase::embedded();
+        let mut installed = InstalledIndex::empty();
+        installed.ollama.insert("qwen2.5-coder:14b".to_string());
+        installed.ollama.insert("qwen2.5-coder".to_string());
+        let config = crate::fit::CalcConfig {
+            scoring_weights: ScoringWeights {
+                installed_family_bonus: 5.0,
+                ..ScoringWeights::default()
+            },
+            ..crate::fit::CalcConfig::default()
+        };
+
+        let fits = build_model_fits_with_config(&db, &cpu_specs(), &installed, None, None, &config);
+        assert!(
+            fits.iter().any(|f| {
+                !f.installed && f.notes.iter().any(|n| n.starts_with(FAMILY_BONUS_NOTE))
+            })
+        );
+    }
+
+    #[test]
+    fn installed_family_bonus_reorders_toward_the_installed_family() {
+        let db = ModelDatabase::new();
+        let specs = cpu_specs();
+        let fit_for = |family: &str, name: &str, points: f64, installed: bool| {
+            let model = db
+                .get_all_models()
+                .iter()
+                .find(|m| m.family == family)
+                .expect("catalog has the family");
+            let mut fit = ModelFit::analyze(model, &specs);
+            fit.model.name = name.to_string();
+            fit.score_components = ScoreComponents {
+                quality: points,
+                speed: points,
+                fit: points,
+                context: points,
+            };
+            fit.score = points;
+            fit.fit_level = crate::fit::FitLevel::Good;
+            fit.installed = installed;
+            fit
+        };
+        let order = |fits: &[ModelFit]| -> Vec<String> {
+            crate::fit::rank_models_by_fit(fits.to_vec())
+                .into_iter()
+                .map(|f| f.model.name)
+                .collect()
+        };
+        let installed_family = db
+            .get_all_models()
+            .iter()
+            .map(|m| m.family.clone())
+            .find(|f| !f.is_empty())
+            .unwrap();
+        let other = db
+            .get_all_models()
+            .iter()
+            .map(|m| m.family.clone())
+            .find(|f| !f.is_empty() && *f != installed_family)
+            .unwrap();
+        let mut fits = vec![
+            fit_for(&other, "other-large", 80.0, false),
+            fit_for(&installed_family, "installed-small", 60.0, true),
+            fit_for(&installed_family, "same-family-large", 75.0, false),
+        ];
+
+        // Off by default: plain score order.
+        apply_installed_family_bonus(&mut fits, &ScoringWeights::default());
+        assert_eq!(
+            order(&fits),
+            ["other-large", "same-family-large", "installed-small"]
+        );
+
+        // With a 10-point bonus the installed family's variant moves up.
+        let weights = ScoringWeights {
+            installed_family_bonus: 10.0,
+            ..ScoringWeights::default()
+        };
+        apply_installed_family_bonus(&mut fits, &weights);
+        assert_eq!(
+            order(&fits),
+            ["same-family-large", "other-large", "installed-small"]
+        );
+
+        // Re-applying doesn't compound the bonus or duplicate the note.
+        apply_installed_family_bonus(&mut fits, &weights);
+        let boosted = fits
+            .iter()
+            .find(|f| f.model.name == "same-family-large")
+            .unwrap();
+        assert_eq!(boosted.score, 85.0);
+        assert_eq!(
+            boosted
+                .notes
+                .iter()
+                .filter(|n| n.starts_with(FAMILY_BONUS_NOTE))
+                .count(),
+            1
+        );
+    }
+}
+
+#[cfg(test)]
+mod probe_tests {
+    use super::*;
+    use std::sync::{Condvar, Mutex};
+    use std::time::Duration;
+
+    const PROBES: usize = 4;
+    static ARRIVED: Mutex<usize> = Mutex::new(0);
+    static ALL_HERE: Condvar = Condvar::new();
+
+    /// Check in and wait for every other probe. Run one at a time, the
+    /// first probe to arrive would time out and report `false`.
+    fn rendezvous() -> bool {
+        let mut arrived = ARRIVED.lock().unwrap();
+        *arrived += 1;
+        ALL_HERE.notify_all();
+        let (arrived, wait) = ALL_HERE
+            .wait_timeout_while(arrived, Duration::from_secs(5), |n| *n < PROBES)
+            .unwrap();
+        drop(arrived);
+        !wait.timed_out()
+    }
+
+    #[test]
+    fn probes_run_concurrently_and_merge_in_order() {
+        let results = probe_concurrently([
+            || ("ollama", rendezvous()),
+            || ("mlx", rendezvous()),
+            || ("lmstudio", rendezvous()),
+            || ("jan", rendezvous()),
+        ]);
+
+        // Results follow the input order, not completion order, and every
+        // probe was running at the same time as the others.
+        assert_eq!(
+            results,
+            [
+                ("ollama", true),
+                ("mlx", true),
+                ("lmstudio", true),
+                ("jan", true)
+            ]
+        );
+    }
+}
@@ -975,6 +975,8 @@ mod tests {
             gpus: vec![],
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
         }
     }
 
@@ -253,7 +253,7 @@ mod tests {
         assert_eq!(v["claimName"], "test-model-7b-fit");
         assert_eq!(v["quant"], "Q4_K_M");
         assert_eq!(v["memoryGi"], 6);
-        assert_eq!(v["minBandwidthGBs"], 148);
+        assert_eq!(v["minBandwidthGBs"], 154);
         assert_eq!(v["minTps"], 20.0);
         assert_eq!(v["efficiencyPct"], 55);
         assert_eq!(v["deviceClass"], "llmfit.ai");
@@ -271,11 +271,11 @@ mod tests {
 
     #[test]
     fn bounds_use_db_memory_for_entry_quant() {
-        // Q4_K_M: 7B × 0.58 bpp = 4.06 GB weights; db min_vram 6.0 wins.
+        // Q4_K_M: 7B × 0.60375 bpp = 4.23 GB weights; db min_vram 6.0 wins.
         let b = fit_bounds(&model("Q4_K_M", Some(6.0)), &ClaimTarget::default()).unwrap();
         assert_eq!(b.memory_gi, 6);
-        // bw ≥ 20 × 4.06 / 0.55 = 147.6… → 148
-        assert_eq!(b.min_bandwidth_gbs, 148);
+        // bw ≥ 20 × 4.23 / 0.55 = 153.7… → 154
+        assert_eq!(b.min_bandwidth_gbs, 154);
         assert_eq!(b.quant, "Q4_K_M");
     }
 
@@ -285,10 +285,10 @@ mod tests {
             quant: Some("Q8_0".to_string()),
             ..ClaimTarget::default()
         };
-        // Q8_0: 7B × 1.05 bpp = 7.35 GB weights; ×1.2 headroom = 8.82 → 9 Gi.
+        // Q8_0: 7B × 1.0625 bpp = 7.44 GB weights; ×1.2 headroom = 8.93 → 9 Gi.
         let b = fit_bounds(&model("Q4_K_M", Some(6.0)), &t).unwrap();
         assert_eq!(b.memory_gi, 9);
-        assert_eq!(b.min_bandwidth_gbs, 268); // 20 × 7.35 / 0.55 = 267.3…
+        assert_eq!(b.min_bandwidth_gbs, 271); // 20 × 7.44 / 0.55 = 270.5…
     }
 
     #[test]
@@ -329,7 +329,7 @@ mod tests {
         assert!(y.contains("name: test-model-7b-fit"));
         assert!(y.contains("deviceClassName: llmfit.ai"));
         assert!(y.contains("quantity('6Gi')"));
-        assert!(y.contains("memoryBandwidthGBs >= 148"));
+        assert!(y.contains("memoryBandwidthGBs >= 154"));
         assert!(y.contains(".healthy"));
     }
 
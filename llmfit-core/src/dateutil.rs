@@ -0,0 +1,223 @@
+//! Small calendar helpers for release-date math.
+//!
+//! Dates are proleptic Gregorian and counted in whole days since 1970-01-01
+//! (UTC), so the recency bonus and the TUI's NEW badge don't need a date crate
+//! and stay exact across leap years and month rollover.
+
+/// Whether `year` has a Feb 29: every 4th year, except centuries not divisible
+/// by 400 (2000 is a leap year, 1900 and 2100 aren't).
+pub fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Number of days in `month` (1-12) of `year`; 0 for an out-of-range month.
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Days since 1970-01-01 for a calendar date (Howard Hinnant's
+/// `days_from_civil`). Negative for dates before the epoch.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400; // [0, 399]
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of [`days_from_civil`]: `(year, month, day)` for a day count since
+/// 1970-01-01 (Howard Hinnant's `civil_from_days`).
+pub fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = doy - (153 * mp + 2) / 5 + 1; // [1, 31]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 }; // [1, 12]
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month as u32, day as u32)
+}
+
+/// Days since 1970-01-01 in UTC according to the system clock (0 if the clock
+/// is before the epoch).
+pub fn days_since_epoch_today() -> i64 {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    (secs / 86_400) as i64
+}
+
+/// Current `(year, month)` in UTC, derived from the system clock.
+pub fn current_year_month() -> (i32, u32) {
+    let (year, month, _) = civil_from_days(days_since_epoch_today());
+    (year as i32, month)
+}
+
+/// Parse a `YYYY-MM-DD` date, or a `YYYY-MM` one read as the 1st. Rejects
+/// days that don't exist in that month (e.g. `2023-02-29`).
+pub fn parse_date(date: &str) -> Option<(i64, u32, u32)> {
+    let mut parts = date.trim().split('-');
+    let year: i64 = parts.next()?.trim().parse().ok()?;
+    let month: u32 = parts.next()?.trim().parse().ok()?;
+    let day: u32 = match parts.next() {
+        Some(d) => d.trim().parse().ok()?,
+        None => 1,
+    };
+    if parts.next().is_some() || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+/// Days from `date` (see [`parse_date`]) to `today` (days since the epoch).
+/// Negative for future dates; `None` if the date can't be parsed.
+pub fn days_since(date: &str, today: i64) -> Option<i64> {
+    let (year, month, day) = parse_date(date)?;
+    Some(today - days_from_civil(year, month, day))
+}
+
+/// Whole calendar months between `date` (only the year and month are read)
+/// and `now` as a `(year, month)` pair. Future dates clamp to 0; `None` if the
+/// date can't be parsed.
+pub fn months_since(date: &str, now: (i32, u32)) -> Option<u32> {
+    let mut parts = date.trim().split('-');
+    let year: i32 = parts.next()?.trim().parse().ok()?;
+    let month: i32 = parts.next()?.trim().parse().ok()?;
+    let (now_year, now_month) = now;
+    let diff = (now_year - year) * 12 + (now_month as i32 - month);
+    Some(diff.max(0) as u32)
+}
+
+/// The month `months` before `now`, formatted as `YYYY-MM`. Rolls back across
+/// year boundaries, so 3 months before 2025-02 is `2024-11`.
+pub fn months_ago_yyyy_mm(now: (i32, u32), months: u32) -> String {
+    let (year, month) = now;
+    let index = year as i64 * 12 + (month as i64 - 1) - months as i64;
+    format!(
+        "{:04}-{:02}",
+        index.div_euclid(12),
+        index.rem_euclid(12) + 1
+    )
+}
+
+/// Format a day count since the epoch as `YYYY-MM-DD`.
+pub fn format_days(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_year_follows_the_century_rule() {
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2100));
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2023));
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2024, 4), 30);
+        assert_eq!(days_in_month(2024, 13), 0);
+    }
+
+    #[test]
+    fn civil_conversion_known_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(59), (1970, 3, 1));
+        assert_eq!(civil_from_days(20_454), (2026, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(
+            days_from_civil(2000, 3, 1) - days_from_civil(2000, 2, 28),
+            2
+        );
+        assert_eq!(
+            days_from_civil(1900, 3, 1) - days_from_civil(1900, 2, 28),
+            1
+        );
+        assert_eq!(
+            days_from_civil(2100, 3, 1) - days_from_civil(2100, 2, 28),
+            1
+        );
+    }
+
+    #[test]
+    fn civil_conversion_round_trips() {
+        // 1899-01-01 through 2101-01-01 covers 1900, 2000 and 2100.
+        let start = days_from_civil(1899, 1, 1);
+        let end = days_from_civil(2101, 1, 1);
+        for z in start..=end {
+            let (y, m, d) = civil_from_days(z);
+            assert!(d >= 1 && d <= days_in_month(y, m), "{y}-{m}-{d}");
+            assert_eq!(days_from_civil(y, m, d), z);
+        }
+    }
+
+    #[test]
+    fn parse_date_checks_month_length() {
+        assert_eq!(parse_date("2024-02-29"), Some((2024, 2, 29)));
+        assert_eq!(parse_date("2000-02-29"), Some((2000, 2, 29)));
+        assert_eq!(parse_date("1900-02-29"), None);
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2025-04-31"), None);
+        assert_eq!(parse_date("2025-03"), Some((2025, 3, 1)));
+        assert_eq!(parse_date("2025-13-01"), None);
+        assert_eq!(parse_date("2025-01-01-01"), None);
+        assert_eq!(parse_date("unknown"), None);
+    }
+
+    #[test]
+    fn days_since_spans_leap_days_and_year_ends() {
+        let today = days_from_civil(2024, 3, 1);
+        assert_eq!(days_since("2024-02-28", today), Some(2));
+        assert_eq!(days_since("2024-02-29", today), Some(1));
+        let today = days_from_civil(2025, 1, 1);
+        assert_eq!(days_since("2024-12-31", today), Some(1));
+        assert_eq!(days_since("2024-01-01", today), Some(366));
+        assert_eq!(days_since("2025-01-02", today), Some(-1));
+        assert_eq!(days_since("nope", today), None);
+    }
+
+    #[test]
+    fn months_since_is_deterministic() {
+        assert_eq!(months_since("2026-06-01", (2026, 6)), Some(0));
+        assert_eq!(months_since("2026-04-01", (2026, 6)), Some(2));
+        assert_eq!(months_since("2025-12-01", (2026, 6)), Some(6));
+        assert_eq!(months_since("2025-12", (2026, 1)), Some(1));
+        assert_eq!(months_since("2024-06-01", (2026, 6)), Some(24));
+        assert_eq!(months_since("2099-01-01", (2026, 6)), Some(0));
+        assert_eq!(months_since("not-a-date", (2026, 6)), None);
+    }
+
+    #[test]
+    fn months_ago_rolls_over_year_boundaries() {
+        assert_eq!(months_ago_yyyy_mm((2025, 6), 0), "2025-06");
+        assert_eq!(months_ago_yyyy_mm((2025, 6), 5), "2025-01");
+        assert_eq!(months_ago_yyyy_mm((2025, 6), 6), "2024-12");
+        assert_eq!(months_ago_yyyy_mm((2025, 2), 3), "2024-11");
+        assert_eq!(months_ago_yyyy_mm((2025, 1), 12), "2024-01");
+        assert_eq!(months_ago_yyyy_mm((2025, 1), 25), "2022-12");
+    }
+
+    #[test]
+    fn format_days_pads_fields() {
+        assert_eq!(format_days(0), "1970-01-01");
+        assert_eq!(format_days(days_from_civil(2000, 2, 29)), "2000-02-29");
+        assert_eq!(format_days(days_from_civil(2026, 10, 5)), "2026-10-05");
+    }
+}
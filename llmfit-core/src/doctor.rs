@@ -6,7 +6,7 @@
 //! then doubles as a parser regression fixture (the verbatim tool output can
 //! be dropped straight into `hardware.rs` tests).
 
-use crate::hardware::SystemSpecs;
+use crate::hardware::{SystemSpecs, nvidia_smi_program, wsl_info};
 use std::fmt::Write as _;
 
 /// Cap each captured section so a pathological tool can't flood the report.
@@ -15,7 +15,7 @@ const MAX_SECTION_BYTES: usize = 16 * 1024;
 /// Run `cmd args…` and return its combined stdout/stderr, or a note that the
 /// tool is unavailable. Never fails: missing tools are part of the diagnosis.
 fn capture(cmd: &str, args: &[&str]) -> String {
-    match std::process::Command::new(cmd).args(args).output() {
+    match crate::subprocess::output(std::process::Command::new(cmd).args(args)) {
         Ok(out) => {
             let mut text = String::new();
             let stdout = String::from_utf8_lossy(&out.stdout);
@@ -126,12 +126,17 @@ pub fn collect_diagnostics(version: &str) -> String {
     let specs = SystemSpecs::detect();
     section(&mut report, "Detected by llmfit", &format!("{specs:#?}"));
 
+    // WSL2: `.wslconfig` memory cap and /dev/dxg GPU passthrough.
+    if let Some(wsl) = wsl_info() {
+        section(&mut report, "WSL", &format!("{wsl:#?}"));
+    }
+
     // NVIDIA
     section(
         &mut report,
         "nvidia-smi (extended query)",
         &capture(
-            "nvidia-smi",
+            nvidia_smi_program(),
             &[
                 "--query-gpu=addressing_mode,memory.total,name",
                 "--format=csv,noheader,nounits",
@@ -142,7 +147,7 @@ pub fn collect_diagnostics(version: &str) -> String {
         &mut report,
         "nvidia-smi (standard query)",
         &capture(
-            "nvidia-smi",
+            nvidia_smi_program(),
             &[
                 "--query-gpu=memory.total,name",
                 "--format=csv,noheader,nounits",
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 use sysinfo::System;
 
 /// The acceleration backend for inference speed estimation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum GpuBackend {
     Cuda,
     Metal,
@@ -12,6 +12,7 @@ pub enum GpuBackend {
     CpuArm,
     CpuX86,
     Ascend,
+    OpenVino, // Intel Core Ultra NPU via OpenVINO
 }
 
 impl GpuBackend {
@@ -25,21 +26,30 @@ impl GpuBackend {
             GpuBackend::CpuArm => "CPU (ARM)",
             GpuBackend::CpuX86 => "CPU (x86)",
             GpuBackend::Ascend => "NPU (Ascend)",
+            GpuBackend::OpenVino => "NPU (OpenVINO)",
         }
     }
 }
 
 /// Information about a single detected GPU.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct GpuInfo {
     pub name: String,
     pub vram_gb: Option<f64>,
     pub backend: GpuBackend,
     pub count: u32, // >1 for same-model multi-GPU (e.g. 2x RTX 4090)
+    #[serde(default)]
     pub unified_memory: bool,
+    /// Driver version as reported by the vendor (`550.54.14` from nvidia-smi,
+    /// the amdgpu module/kernel DRM version on Linux). `None` when unknown.
+    pub driver_version: Option<String>,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+/// Set to a JSON-serialized [`SystemSpecs`] to skip hardware detection
+/// entirely, e.g. to pin the hardware llmfit sees in CI.
+pub const SPECS_ENV: &str = "LLMFIT_SPECS";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SystemSpecs {
     pub total_ram_gb: f64,
     pub available_ram_gb: f64,
@@ -58,26 +68,86 @@ pub struct SystemSpecs {
     /// memory reports the *total* pool.
     pub gpu_available_gb: Option<f64>,
     pub gpu_name: Option<String>,
+    #[serde(default)]
     pub gpu_count: u32,
+    #[serde(default)]
     pub unified_memory: bool,
     pub backend: GpuBackend,
     /// All detected GPUs (may span different vendors/backends).
+    #[serde(default)]
     pub gpus: Vec<GpuInfo>,
     /// True when running in multi-node cluster mode (e.g. DGX Spark cluster).
+    #[serde(default)]
     pub cluster_mode: bool,
     /// Number of nodes in the cluster (0 or 1 = single machine).
+    #[serde(default)]
     pub cluster_node_count: u32,
+    /// GPU core count, when the platform reports one (Apple Silicon via
+    /// `system_profiler`). Binned chips share a name with the full part, so
+    /// this is what tells a 30-core M3 Max from a 40-core one.
+    #[serde(default)]
+    pub gpu_cores: Option<u32>,
+    /// System RAM generation, speed and channel count, when the firmware
+    /// tables are readable. Bounds CPU-only and MoE-offload speed.
+    #[serde(default)]
+    pub ram_config: Option<RamConfig>,
+}
+
+/// Share of a RAM configuration's spec-sheet peak that streaming reads
+/// actually achieve — the midpoint of the 60–80% seen in practice (see
+/// [`measured_ram_bandwidth_gbps`]).
+pub const RAM_ACHIEVABLE_FRACTION: f64 = 0.7;
+
+/// Installed system RAM as reported by SMBIOS (memory device, type 17):
+/// `dmidecode` on Linux, `Win32_PhysicalMemory` on Windows.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RamConfig {
+    /// Memory generation, e.g. "DDR4", "DDR5", "LPDDR5".
+    pub kind: String,
+    /// Configured transfer rate in MT/s.
+    pub speed_mts: u32,
+    /// Populated 64-bit memory channels.
+    pub channels: u32,
+}
+
+impl RamConfig {
+    /// Spec-sheet peak: MT/s × 8 bytes × channels.
+    pub fn peak_bandwidth_gbps(&self) -> f64 {
+        self.speed_mts as f64 * 8.0 * self.channels as f64 / 1000.0
+    }
+
+    /// What streaming reads get in practice ([`RAM_ACHIEVABLE_FRACTION`]).
+    pub fn achievable_bandwidth_gbps(&self) -> f64 {
+        self.peak_bandwidth_gbps() * RAM_ACHIEVABLE_FRACTION
+    }
+
+    /// Short label, e.g. "DDR5-5600 x2 channels".
+    pub fn label(&self) -> String {
+        format!(
+            "{}-{} x{} channel{}",
+            self.kind,
+            self.speed_mts,
+            self.channels,
+            if self.channels == 1 { "" } else { "s" }
+        )
+    }
 }
 
 impl SystemSpecs {
+    /// Detect the host's hardware, unless [`SPECS_ENV`] holds a complete
+    /// specs JSON object, which is returned as-is.
     pub fn detect() -> Self {
+        if let Some(specs) = Self::from_specs_env() {
+            return specs;
+        }
+
         let mut sys = System::new_all();
         sys.refresh_all();
 
         let total_ram_bytes = sys.total_memory();
         let available_ram_bytes = sys.available_memory();
-        let total_ram_gb = total_ram_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
-        let available_ram_gb = if available_ram_bytes == 0 && total_ram_bytes > 0 {
+        let mut total_ram_gb = total_ram_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+        let mut available_ram_gb = if available_ram_bytes == 0 && total_ram_bytes > 0 {
             // sysinfo may fail to report available memory on some platforms
             // (e.g. macOS Tahoe / newer macOS versions). Try fallbacks.
             Self::available_ram_fallback(&sys, total_ram_bytes, total_ram_gb)
@@ -85,96 +155,240 @@ impl SystemSpecs {
             available_ram_bytes as f64 / (1024.0 * 1024.0 * 1024.0)
         };
 
+        // The WSL2 VM normally sees its own size in /proc/meminfo, but an
+        // explicit `.wslconfig` cap is authoritative.
+        if let Some(wsl) = wsl_info() {
+            (total_ram_gb, available_ram_gb) = wsl.clamp_ram(total_ram_gb, available_ram_gb);
+        }
+
         let total_cpu_cores = sys.cpus().len();
-        let cpu_name = Self::detect_cpu_name(&sys);
+        let mut cpu_name = Self::detect_cpu_name(&sys);
+        // An x86_64 build under Rosetta sees a "VirtualApple" CPU; take the
+        // real chip name from the GPU so Apple Silicon lookups still work.
+        if is_rosetta_translated()
+            && let Some(chip) = Self::detect_apple_chipset_name()
+        {
+            cpu_name = chip;
+        }
 
         let gpus = Self::detect_all_gpus(total_ram_gb, &cpu_name);
 
-        // Primary GPU = the one with the most VRAM (best for inference).
-        // Per-card display values come from the primary; the fit-scoring pool
-        // and GPU count are aggregated across every detected GPU so that
-        // multi-GPU systems (including mixed models, e.g. RX 7600 + R9700)
-        // contribute their full combined VRAM, not just the primary's.
-        let primary = gpus.first();
-        let has_gpu = !gpus.is_empty();
-        let gpu_vram_gb = primary.and_then(|g| g.vram_gb);
-        let gpu_name = primary.map(|g| g.name.clone());
-        let unified_memory = primary.map(|g| g.unified_memory).unwrap_or(false);
-        // Total VRAM = sum of per-card VRAM * count across all GPUs (for
-        // multi-GPU tensor splitting). Unified-memory GPUs report the shared
-        // system pool as their VRAM; with a single such GPU this is correct.
-        let total_gpu_vram_gb = {
-            let sum: f64 = gpus
-                .iter()
-                .filter_map(|g| g.vram_gb.map(|vram| vram * g.count as f64))
-                .sum();
-            if sum > 0.0 { Some(sum) } else { None }
-        };
-        let gpu_count: u32 = gpus.iter().map(|g| g.count).sum();
-
         let cpu_backend =
             if cfg!(target_arch = "aarch64") || cpu_name.to_lowercase().contains("apple") {
                 GpuBackend::CpuArm
             } else {
                 GpuBackend::CpuX86
             };
-        let backend = primary.map(|g| g.backend).unwrap_or(cpu_backend);
+        let mut specs = SystemSpecs {
+            total_ram_gb,
+            available_ram_gb,
+            total_cpu_cores,
+            cpu_name,
+            has_gpu: false,
+            gpu_vram_gb: None,
+            total_gpu_vram_gb: None,
+            gpu_available_gb: None,
+            gpu_name: None,
+            gpu_count: 0,
+            unified_memory: false,
+            backend: cpu_backend,
+            gpus,
+            cluster_mode: false,
+            cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: detect_ram_config(),
+        };
+        specs.refresh_gpu_summary();
 
         // Only Apple Silicon reports unified memory *and* runs Metal, so the
         // GPU-available query is meaningful only there. Other unified-memory
         // paths (AMD APUs, NVIDIA Grace) fall through to `None` because the
         // query is macOS-only.
-        let gpu_available_gb = if unified_memory {
-            detect_gpu_available_gb()
-        } else {
-            None
-        };
+        if specs.unified_memory {
+            specs.gpu_available_gb = detect_gpu_available_gb();
+            if specs.backend == GpuBackend::Metal {
+                specs.gpu_cores = Self::detect_apple_gpu_cores();
+            }
+        }
+        specs
+    }
 
+    /// Specs for a machine described by hand rather than detected: `cpu_cores`
+    /// cores and `total_ram_gb` of RAM, `available_ram_gb` of it free, and no
+    /// GPU. Add GPUs with [`with_gpu`](Self::with_gpu). Useful for "what if"
+    /// analysis and tests:
+    ///
+    /// ```
+    /// use llmfit_core::hardware::{GpuBackend, GpuInfo, SystemSpecs};
+    ///
+    /// let specs = SystemSpecs::new(64.0, 56.0, 16).with_gpu(GpuInfo {
+    ///     name: "NVIDIA GeForce RTX 4090".to_string(),
+    ///     vram_gb: Some(24.0),
+    ///     backend: GpuBackend::Cuda,
+    ///     count: 1,
+    ///     unified_memory: false,
+    ///     driver_version: None,
+    /// });
+    /// assert_eq!(specs.total_gpu_vram_gb, Some(24.0));
+    /// ```
+    pub fn new(total_ram_gb: f64, available_ram_gb: f64, cpu_cores: usize) -> Self {
         SystemSpecs {
             total_ram_gb,
             available_ram_gb,
-            total_cpu_cores,
-            cpu_name,
-            has_gpu,
-            gpu_vram_gb,
-            total_gpu_vram_gb,
-            gpu_available_gb,
-            gpu_name,
-            gpu_count,
-            unified_memory,
-            backend,
-            gpus,
+            total_cpu_cores: cpu_cores,
+            cpu_name: "Custom CPU".to_string(),
+            has_gpu: false,
+            gpu_vram_gb: None,
+            total_gpu_vram_gb: None,
+            gpu_available_gb: None,
+            gpu_name: None,
+            gpu_count: 0,
+            unified_memory: false,
+            backend: if cfg!(target_arch = "aarch64") {
+                GpuBackend::CpuArm
+            } else {
+                GpuBackend::CpuX86
+            },
+            gpus: Vec::new(),
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
+        }
+    }
+
+    /// Add a GPU (or `count` identical ones). The GPU with the most VRAM
+    /// becomes the primary, as in detection, and the summary fields
+    /// (`has_gpu`, `gpu_vram_gb`, `total_gpu_vram_gb`, `backend`, …) follow.
+    pub fn with_gpu(mut self, gpu: GpuInfo) -> Self {
+        self.gpus.push(gpu);
+        self.gpus.sort_by(|a, b| {
+            b.vram_gb
+                .unwrap_or(0.0)
+                .total_cmp(&a.vram_gb.unwrap_or(0.0))
+        });
+        self.refresh_gpu_summary();
+        self
+    }
+
+    /// Derive the GPU summary fields from `gpus`, whose first entry is the
+    /// primary GPU. Per-card display values come from the primary; the
+    /// fit-scoring pool and GPU count are aggregated across every GPU so that
+    /// multi-GPU systems (including mixed models, e.g. RX 7600 + R9700)
+    /// contribute their full combined VRAM, not just the primary's. Without
+    /// a GPU the CPU backend already in `backend` is kept.
+    fn refresh_gpu_summary(&mut self) {
+        let primary = self.gpus.first();
+        self.has_gpu = primary.is_some();
+        self.gpu_vram_gb = primary.and_then(|g| g.vram_gb);
+        self.gpu_name = primary.map(|g| g.name.clone());
+        self.unified_memory = primary.is_some_and(|g| g.unified_memory);
+        if let Some(gpu) = primary {
+            self.backend = gpu.backend;
+        }
+        // Total VRAM = sum of per-card VRAM * count across all GPUs (for
+        // multi-GPU tensor splitting). Unified-memory GPUs report the shared
+        // system pool as their VRAM; with a single such GPU this is correct.
+        let sum: f64 = self
+            .gpus
+            .iter()
+            .filter_map(|g| g.vram_gb.map(|vram| vram * g.count as f64))
+            .sum();
+        self.total_gpu_vram_gb = if sum > 0.0 { Some(sum) } else { None };
+        self.gpu_count = self.gpus.iter().map(|g| g.count).sum();
+    }
+
+    /// Specs from [`SPECS_ENV`]. An unparsable value is reported and ignored
+    /// so a typo falls back to real detection instead of aborting.
+    fn from_specs_env() -> Option<Self> {
+        let raw = std::env::var(SPECS_ENV).ok()?;
+        if raw.trim().is_empty() {
+            return None;
+        }
+        match serde_json::from_str(&raw) {
+            Ok(specs) => Some(specs),
+            Err(e) => {
+                eprintln!("Warning: ignoring {SPECS_ENV}: {e}");
+                None
+            }
         }
     }
 
     /// Detect all GPUs across all vendors. Returns a Vec sorted by VRAM descending
     /// (best GPU first). Unlike the old cascade, this does NOT short-circuit:
     /// a system with both NVIDIA and AMD GPUs will report both.
+    ///
+    /// The vendor probes shell out to tools that can each take seconds
+    /// (nvidia-smi, rocm-smi, vulkaninfo, PowerShell), so they all run in
+    /// parallel; the results are then merged in the fixed order below, which
+    /// keeps the de-duplication between overlapping probes deterministic.
     fn detect_all_gpus(total_ram_gb: f64, cpu_name: &str) -> Vec<GpuInfo> {
-        let mut gpus = Vec::new();
-
-        // NVIDIA GPUs via nvidia-smi, with sysfs fallback for Linux/toolbox setups
-        let nvidia = Self::detect_nvidia_gpus();
-        if nvidia.is_empty() {
-            if let Some(nvidia_sysfs) = Self::detect_nvidia_gpu_sysfs_info() {
-                gpus.push(nvidia_sysfs);
-            }
+        let apple_name = if cpu_name.to_lowercase().contains("apple") {
+            cpu_name.to_string()
         } else {
-            gpus.extend(nvidia);
-        }
+            "Apple Silicon".to_string()
+        };
+        let probes: Vec<GpuProbe> = vec![
+            // NVIDIA GPUs via nvidia-smi, with sysfs fallback for Linux/toolbox setups
+            Box::new(|| {
+                let nvidia = Self::detect_nvidia_gpus();
+                let nvidia = if nvidia.is_empty() {
+                    Self::detect_nvidia_gpu_sysfs_info().into_iter().collect()
+                } else {
+                    nvidia
+                };
+                with_driver_version(nvidia, nvidia_driver_version)
+            }),
+            // AMD GPUs via rocm-smi or sysfs
+            Box::new(move || {
+                let amd_rocm = Self::detect_amd_gpu_rocm_info();
+                let amd = if amd_rocm.is_empty() {
+                    Self::detect_amd_gpu_sysfs_info(total_ram_gb)
+                } else {
+                    amd_rocm
+                };
+                with_driver_version(amd, amdgpu_dkms_version)
+            }),
+            Box::new(Self::detect_gpu_windows_info),
+            Box::new(move || Self::detect_intel_gpus(total_ram_gb)),
+            Box::new(Self::detect_macos_metal_gpus),
+            Box::new(move || {
+                Self::detect_apple_gpu(total_ram_gb)
+                    .map(|vram| GpuInfo {
+                        name: apple_name,
+                        vram_gb: Some(vram),
+                        backend: GpuBackend::Metal,
+                        count: 1,
+                        unified_memory: true,
+                        driver_version: None,
+                    })
+                    .into_iter()
+                    .collect()
+            }),
+            Box::new(Self::detect_ascend_npus),
+            Box::new(Self::detect_vulkan_gpu_info),
+            Box::new(move || Self::detect_intel_npus(total_ram_gb)),
+        ];
+        let [
+            nvidia,
+            amd,
+            windows,
+            intel_gpus,
+            macos_metal,
+            apple,
+            ascend,
+            vulkan,
+            intel_npus,
+        ]: [Vec<GpuInfo>; 9] = run_gpu_probes(probes, GPU_PROBE_TIMEOUT)
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("one result per probe"));
 
-        // AMD GPUs via rocm-smi or sysfs
-        let amd_rocm = Self::detect_amd_gpu_rocm_info();
-        if amd_rocm.is_empty() {
-            gpus.extend(Self::detect_amd_gpu_sysfs_info());
-        } else {
-            gpus.extend(amd_rocm);
-        }
+        let mut gpus = Vec::new();
+        gpus.extend(nvidia);
+        gpus.extend(amd);
 
         // Windows WMI (catches GPUs not found by vendor-specific tools)
-        for wmi_gpu in Self::detect_gpu_windows_info() {
+        for wmi_gpu in windows {
             // Skip if we already found a GPU with the same name from a vendor tool
             let dominated = gpus.iter().any(|existing| {
                 let existing_lower = existing.name.to_lowercase();
@@ -220,6 +434,7 @@ impl SystemSpecs {
                     backend: GpuBackend::Vulkan,
                     count: 1,
                     unified_memory: true,
+                    driver_version: None,
                 });
             }
         }
@@ -241,7 +456,6 @@ impl SystemSpecs {
         }
 
         // Intel GPUs (integrated or discrete Arc) via lspci/sysfs
-        let intel_gpus = Self::detect_intel_gpus(total_ram_gb);
         if !intel_gpus.is_empty() {
             let already_found = gpus.iter().any(|g| g.name.to_lowercase().contains("intel"));
             if !already_found {
@@ -252,7 +466,7 @@ impl SystemSpecs {
         // Intel macOS machines expose Intel and AMD GPUs through Metal, but
         // not through Linux ROCm/sysfs or NVIDIA-specific tools. Read
         // system_profiler so older MacBook Pros report their discrete Radeon.
-        for mac_gpu in Self::detect_macos_metal_gpus() {
+        for mac_gpu in macos_metal {
             let dominated = gpus
                 .iter()
                 .any(|existing| Self::is_same_gpu_name(&existing.name, &mac_gpu.name));
@@ -262,30 +476,14 @@ impl SystemSpecs {
         }
 
         // Apple Silicon (unified memory)
-        if let Some(vram) = Self::detect_apple_gpu(total_ram_gb) {
-            let name = if cpu_name.to_lowercase().contains("apple") {
-                cpu_name.to_string()
-            } else {
-                "Apple Silicon".to_string()
-            };
-            gpus.push(GpuInfo {
-                name,
-                vram_gb: Some(vram),
-                backend: GpuBackend::Metal,
-                count: 1,
-                unified_memory: true,
-            });
-        }
+        gpus.extend(apple);
 
         // Ascend NPUs via npu-smi
-        let ascend = Self::detect_ascend_npus();
-        if !ascend.is_empty() {
-            gpus.extend(ascend);
-        }
+        gpus.extend(ascend);
 
         // Vulkan fallback (e.g. Android/Termux with Turnip)
         let has_rocm_gpu = gpus.iter().any(|g| g.backend == GpuBackend::Rocm);
-        for vulkan_gpu in Self::detect_vulkan_gpu_info() {
+        for vulkan_gpu in vulkan {
             // When a ROCm AMD GPU is already detected, skip any Vulkan AMD/RADV
             // devices — they represent the same physical GPU and ROCm is the
             // higher-quality detection path (provides real VRAM and product name).
@@ -335,7 +533,36 @@ impl SystemSpecs {
             vb.partial_cmp(&va).unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        gpus
+        with_npu_fallback(gpus, intel_npus)
+    }
+
+    /// Detect Intel Core Ultra NPUs ("AI Boost"): the `intel_vpu` accel
+    /// devices under `/sys/class/accel` on Linux, PnP devices on Windows.
+    /// The NPU has no memory of its own and works out of system RAM.
+    fn detect_intel_npus(total_ram_gb: f64) -> Vec<GpuInfo> {
+        let mut pci_ids = Vec::new();
+        if cfg!(target_os = "linux") {
+            if let Ok(entries) = std::fs::read_dir("/sys/class/accel") {
+                for entry in entries.flatten() {
+                    pci_ids.extend(pci_id_from_sysfs(&entry.path().join("device")));
+                }
+            }
+        } else if cfg!(target_os = "windows")
+            && let Ok(output) = crate::subprocess::output(
+                std::process::Command::new("powershell")
+                    .arg("-NoProfile")
+                    .arg("-Command")
+                    .arg("Get-PnpDevice -PresentOnly | Where-Object { $_.InstanceId -like 'PCI\\VEN_8086*' } | ForEach-Object { $_.InstanceId }"),
+            )
+            && output.status.success()
+        {
+            pci_ids.extend(
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .filter_map(pci_id_from_pnp_device_id),
+            );
+        }
+        intel_npus_from_pci_ids(&pci_ids, total_ram_gb)
     }
 
     /// Detect NVIDIA GPUs via nvidia-smi. Returns one GpuInfo per unique model,
@@ -345,6 +572,25 @@ impl SystemSpecs {
     /// Blackwell platforms). Falls back to the standard 2-column query if the field
     /// is unavailable on older nvidia-smi versions.
     fn detect_nvidia_gpus() -> Vec<GpuInfo> {
+        let gpus = Self::detect_nvidia_gpus_by_query();
+        if gpus.is_empty() {
+            return gpus;
+        }
+
+        // With MIG enabled, `--query-gpu` still reports the full card's memory
+        // even though workloads only ever see one slice. `nvidia-smi -L` lists
+        // the slices, so swap partitioned cards for their instances.
+        match crate::subprocess::output(std::process::Command::new(nvidia_smi_program()).arg("-L"))
+        {
+            Ok(o) if o.status.success() => {
+                let listing = String::from_utf8_lossy(&o.stdout);
+                Self::apply_nvidia_mig_slices(gpus, &listing)
+            }
+            _ => gpus,
+        }
+    }
+
+    fn detect_nvidia_gpus_by_query() -> Vec<GpuInfo> {
         // Try the extended query first (addressing_mode,memory.total,name).
         // On NVIDIA Tegra / Grace Blackwell, addressing_mode returns "ATS"
         // (Address Translation Services) which signals unified CPU+GPU memory.
@@ -353,11 +599,11 @@ impl SystemSpecs {
         }
 
         // Fallback: standard 2-column query for older nvidia-smi versions
-        let output = match std::process::Command::new("nvidia-smi")
-            .arg("--query-gpu=memory.total,name")
-            .arg("--format=csv,noheader,nounits")
-            .output()
-        {
+        let output = match crate::subprocess::output(
+            std::process::Command::new(nvidia_smi_program())
+                .arg("--query-gpu=memory.total,name")
+                .arg("--format=csv,noheader,nounits"),
+        ) {
             Ok(o) if o.status.success() => o,
             _ => return Vec::new(),
         };
@@ -374,11 +620,12 @@ impl SystemSpecs {
     /// query fails (e.g. older driver that doesn't support the field), so the
     /// caller can fall back to the standard query.
     fn try_nvidia_smi_with_addressing_mode() -> Option<Vec<GpuInfo>> {
-        let output = std::process::Command::new("nvidia-smi")
-            .arg("--query-gpu=addressing_mode,memory.total,name")
-            .arg("--format=csv,noheader,nounits")
-            .output()
-            .ok()?;
+        let output = crate::subprocess::output(
+            std::process::Command::new(nvidia_smi_program())
+                .arg("--query-gpu=addressing_mode,memory.total,name")
+                .arg("--format=csv,noheader,nounits"),
+        )
+        .ok()?;
 
         if !output.status.success() {
             return None;
@@ -425,7 +672,7 @@ impl SystemSpecs {
                 // Unified memory: use total system RAM as the shared pool
                 total_ram_gb.unwrap_or(0.0) * 1024.0
             } else {
-                estimate_vram_from_name(&name) * 1024.0
+                fallback_vram_gb(&name, sysfs_pci_vram_gb("10de")).unwrap_or(0.0) * 1024.0
             };
 
             let entry = grouped.entry(name).or_insert((0, 0.0, false));
@@ -454,6 +701,7 @@ impl SystemSpecs {
                 backend: GpuBackend::Cuda,
                 count,
                 unified_memory: is_unified,
+                driver_version: None,
             })
             .collect()
     }
@@ -484,7 +732,7 @@ impl SystemSpecs {
             let vram_mb = if parsed_vram_mb > 0.0 {
                 parsed_vram_mb
             } else {
-                estimate_vram_from_name(&name) * 1024.0
+                fallback_vram_gb(&name, sysfs_pci_vram_gb("10de")).unwrap_or(0.0) * 1024.0
             };
 
             let entry = grouped.entry(name).or_insert((0, 0.0));
@@ -510,10 +758,80 @@ impl SystemSpecs {
                 backend: GpuBackend::Cuda,
                 count,
                 unified_memory: false,
+                driver_version: None,
             })
             .collect()
     }
 
+    /// Replace MIG-partitioned cards with their instances, using the output of
+    /// `nvidia-smi -L`:
+    ///
+    /// ```text
+    /// GPU 0: NVIDIA A100-SXM4-80GB (UUID: GPU-...)
+    ///   MIG 3g.40gb     Device  0: (UUID: MIG-...)
+    ///   MIG 2g.20gb     Device  1: (UUID: MIG-...)
+    /// ```
+    ///
+    /// Each slice becomes a device named after its parent and profile, with
+    /// the VRAM from the profile's memory size. Cards without MIG instances
+    /// are left as reported by the memory query.
+    fn apply_nvidia_mig_slices(mut gpus: Vec<GpuInfo>, listing: &str) -> Vec<GpuInfo> {
+        // Per parent card: its name and the MIG profiles carved out of it.
+        let mut cards: Vec<(String, Vec<String>)> = Vec::new();
+        for line in listing.lines() {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("GPU ") {
+                let name = rest
+                    .split_once(':')
+                    .map(|(_, n)| n)
+                    .unwrap_or("")
+                    .split(" (UUID")
+                    .next()
+                    .unwrap_or("")
+                    .trim();
+                cards.push((name.to_string(), Vec::new()));
+            } else if let Some(rest) = line.strip_prefix("MIG ")
+                && let Some(profile) = rest.split_whitespace().next()
+                && let Some((_, parent_profiles)) = cards.last_mut()
+            {
+                parent_profiles.push(profile.to_string());
+            }
+        }
+
+        let mut slices: BTreeMap<String, (u32, Option<f64>)> = BTreeMap::new();
+        for (parent, profiles) in cards.iter().filter(|(_, p)| !p.is_empty()) {
+            // The partitioned card is no longer usable as a whole device.
+            if let Some(pos) = gpus.iter().position(|g| g.name == *parent) {
+                if gpus[pos].count > 1 {
+                    gpus[pos].count -= 1;
+                } else {
+                    gpus.remove(pos);
+                }
+            }
+            for profile in profiles {
+                let entry = slices
+                    .entry(format!("{parent} MIG {profile}"))
+                    .or_insert((0, mig_profile_memory_gb(profile)));
+                entry.0 += 1;
+            }
+        }
+
+        gpus.extend(slices.into_iter().map(|(name, (count, vram_gb))| GpuInfo {
+            name,
+            vram_gb,
+            backend: GpuBackend::Cuda,
+            count,
+            unified_memory: false,
+            driver_version: None,
+        }));
+        gpus.sort_by(|a, b| {
+            let va = a.vram_gb.unwrap_or(0.0) * a.count as f64;
+            let vb = b.vram_gb.unwrap_or(0.0) * b.count as f64;
+            vb.partial_cmp(&va).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        gpus
+    }
+
     /// Detect NVIDIA GPUs via Linux sysfs when nvidia-smi is unavailable.
     /// This is common in containerized environments (e.g. Toolbx) and
     /// Nouveau-based systems.
@@ -526,6 +844,7 @@ impl SystemSpecs {
         let mut gpu_count: u32 = 0;
         let mut total_vram_bytes: u64 = 0;
         let mut slot_hints: Vec<String> = Vec::new();
+        let mut pci_vram: Option<f64> = None;
         let mut backend = GpuBackend::Vulkan;
 
         for entry in entries.flatten() {
@@ -546,6 +865,8 @@ impl SystemSpecs {
             }
 
             gpu_count += 1;
+            pci_vram = pci_vram
+                .or_else(|| pci_id_from_sysfs(&device_path).and_then(|id| pci_vram_gb(&id)));
 
             if let Ok(vram_str) = std::fs::read_to_string(device_path.join("mem_info_vram_total"))
                 && let Ok(vram_bytes) = vram_str.trim().parse::<u64>()
@@ -575,19 +896,12 @@ impl SystemSpecs {
         let name = Self::get_nvidia_gpu_name_lspci(&slot_hints)
             .unwrap_or_else(|| "NVIDIA GPU".to_string());
 
-        let mut vram_gb = if total_vram_bytes > 0 {
+        let vram_gb = if total_vram_bytes > 0 {
             Some(total_vram_bytes as f64 / (1024.0 * 1024.0 * 1024.0))
         } else {
-            None
+            fallback_vram_gb(&name, pci_vram)
         };
 
-        if vram_gb.is_none() {
-            let est = estimate_vram_from_name(&name);
-            if est > 0.0 {
-                vram_gb = Some(est);
-            }
-        }
-
         let unified_memory = is_nvidia_unified_memory_gpu(&name);
 
         Some(GpuInfo {
@@ -596,6 +910,7 @@ impl SystemSpecs {
             backend,
             count: gpu_count,
             unified_memory,
+            driver_version: None,
         })
     }
 
@@ -603,11 +918,11 @@ impl SystemSpecs {
     /// Parses per-card VRAM and GPU name from rocm-smi output, returning one
     /// `GpuInfo` per distinct GPU model (like `detect_nvidia_gpus`).
     fn detect_amd_gpu_rocm_info() -> Vec<GpuInfo> {
-        let vram_output = match std::process::Command::new("rocm-smi")
-            .arg("--showmeminfo")
-            .arg("vram")
-            .output()
-        {
+        let vram_output = match crate::subprocess::output(
+            std::process::Command::new("rocm-smi")
+                .arg("--showmeminfo")
+                .arg("vram"),
+        ) {
             Ok(o) if o.status.success() => o,
             _ => return Vec::new(),
         };
@@ -616,14 +931,24 @@ impl SystemSpecs {
             Err(_) => return Vec::new(),
         };
 
-        let product_text = std::process::Command::new("rocm-smi")
-            .arg("--showproductname")
-            .output()
-            .ok()
-            .filter(|o| o.status.success())
-            .and_then(|o| String::from_utf8(o.stdout).ok());
-
-        Self::parse_rocm_smi_output(&vram_text, product_text.as_deref())
+        // The driver version rides along on the product-name call rather than
+        // costing another rocm-smi spawn.
+        let product_text = crate::subprocess::output(
+            std::process::Command::new("rocm-smi")
+                .args(["--showproductname", "--showdriverversion"]),
+        )
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok());
+
+        let driver_version = product_text
+            .as_deref()
+            .and_then(parse_rocm_smi_driver_version);
+        let mut gpus = Self::parse_rocm_smi_output(&vram_text, product_text.as_deref());
+        for gpu in &mut gpus {
+            gpu.driver_version = driver_version.clone();
+        }
+        gpus
     }
 
     /// Parse per-GPU VRAM totals (bytes) from `rocm-smi --showmeminfo vram`.
@@ -824,8 +1149,7 @@ impl SystemSpecs {
                 let vram_gb = if vram_bytes > 0 {
                     Some(vram_bytes as f64 / (1024.0 * 1024.0 * 1024.0))
                 } else {
-                    let est = estimate_vram_from_name(&name);
-                    if est > 0.0 { Some(est) } else { None }
+                    fallback_vram_gb(&name, sysfs_pci_vram_gb("1002"))
                 };
                 GpuInfo {
                     name,
@@ -833,6 +1157,7 @@ impl SystemSpecs {
                     backend: GpuBackend::Rocm,
                     count,
                     unified_memory: false,
+                    driver_version: None,
                 }
             })
             .collect()
@@ -843,7 +1168,11 @@ impl SystemSpecs {
     /// `/sys/class/drm`, groups identical models with a `count` (like the
     /// ROCm and NVIDIA paths), and returns one `GpuInfo` per distinct model
     /// so multi-GPU setups are reported in full.
-    fn detect_amd_gpu_sysfs_info() -> Vec<GpuInfo> {
+    ///
+    /// APU iGPUs are reported as unified-memory devices sized by their GTT
+    /// budget (see [`amd_apu_shared_budget_gb`]) rather than the tiny BIOS
+    /// carve-out in `mem_info_vram_total`.
+    fn detect_amd_gpu_sysfs_info(total_ram_gb: f64) -> Vec<GpuInfo> {
         if !cfg!(target_os = "linux") {
             return Vec::new();
         }
@@ -853,8 +1182,10 @@ impl SystemSpecs {
             Err(_) => return Vec::new(),
         };
 
-        // Collect per-card (name, vram) pairs.
+        // Collect per-card (name, vram) pairs, plus the shared-memory budget
+        // of any APU iGPU keyed by name.
         let mut cards: Vec<(String, Option<f64>)> = Vec::new();
+        let mut apu_budgets: BTreeMap<String, f64> = BTreeMap::new();
 
         for entry in entries.flatten() {
             let card_path = entry.path();
@@ -898,18 +1229,36 @@ impl SystemSpecs {
             let gpu_name = Self::get_amd_gpu_name_lspci(&slot_hints);
             let name = gpu_name.unwrap_or_else(|| "AMD GPU".to_string());
 
-            // If we still don't have VRAM, try to estimate from name
+            // GTT: system RAM the GPU can map for compute. On APUs this,
+            // not the carve-out, bounds what a model can use.
+            let gtt_gb = std::fs::read_to_string(device_path.join("mem_info_gtt_total"))
+                .ok()
+                .and_then(|s| s.trim().parse::<u64>().ok())
+                .filter(|&b| b > 0)
+                .map(|b| b as f64 / (1024.0 * 1024.0 * 1024.0));
+            if let Some(budget) = amd_apu_shared_budget_gb(&name, vram_gb, gtt_gb, total_ram_gb) {
+                apu_budgets.insert(name.clone(), budget);
+            }
+
+            // If we still don't have VRAM, look up the PCI ID, then the name
             if vram_gb.is_none() {
-                let estimated = estimate_vram_from_name(&name);
-                if estimated > 0.0 {
-                    vram_gb = Some(estimated);
-                }
+                let pci_vram = pci_id_from_sysfs(&device_path).and_then(|id| pci_vram_gb(&id));
+                vram_gb = fallback_vram_gb(&name, pci_vram);
             }
 
             cards.push((name, vram_gb));
         }
 
-        Self::group_and_filter_amd_sysfs_cards(cards)
+        let mut gpus = Self::group_and_filter_amd_sysfs_cards(cards);
+        // An APU that survived the discrete-GPU filter runs models from its
+        // shared budget, like Apple Silicon runs from the unified pool.
+        for gpu in &mut gpus {
+            if let Some(&budget) = apu_budgets.get(&gpu.name) {
+                gpu.vram_gb = Some(budget);
+                gpu.unified_memory = true;
+            }
+        }
+        gpus
     }
 
     /// Group sysfs AMD cards by model name and drop integrated GPUs when a
@@ -952,6 +1301,7 @@ impl SystemSpecs {
                 backend: GpuBackend::Vulkan,
                 count,
                 unified_memory: false,
+                driver_version: None,
             })
             .collect()
     }
@@ -1025,9 +1375,7 @@ impl SystemSpecs {
 
     /// Read lspci output, with host fallback for containerized environments.
     fn lspci_output() -> Option<String> {
-        let local = std::process::Command::new("lspci")
-            .arg("-nnD")
-            .output()
+        let local = crate::subprocess::output(std::process::Command::new("lspci").arg("-nnD"))
             .ok()
             .filter(|o| o.status.success())
             .and_then(|o| String::from_utf8(o.stdout).ok());
@@ -1036,12 +1384,12 @@ impl SystemSpecs {
             return local;
         }
 
-        std::process::Command::new("flatpak-spawn")
-            .args(["--host", "lspci", "-nnD"])
-            .output()
-            .ok()
-            .filter(|o| o.status.success())
-            .and_then(|o| String::from_utf8(o.stdout).ok())
+        crate::subprocess::output(
+            std::process::Command::new("flatpak-spawn").args(["--host", "lspci", "-nnD"]),
+        )
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
     }
 
     /// Extract a likely model name from an lspci line.
@@ -1090,11 +1438,12 @@ impl SystemSpecs {
         }
 
         // Use PowerShell to query WMI — more reliable than wmic (deprecated)
-        if let Ok(output) = std::process::Command::new("powershell")
-            .arg("-NoProfile")
-            .arg("-Command")
-            .arg("Get-CimInstance Win32_VideoController | Select-Object Name,AdapterRAM | ForEach-Object { $_.Name + '|' + $_.AdapterRAM }")
-            .output()
+        if let Ok(output) = crate::subprocess::output(
+            std::process::Command::new("powershell")
+                .arg("-NoProfile")
+                .arg("-Command")
+                .arg("Get-CimInstance Win32_VideoController | Select-Object Name,AdapterRAM,PNPDeviceID | ForEach-Object { $_.Name + '|' + $_.AdapterRAM + '|' + $_.PNPDeviceID }"),
+        )
             && output.status.success()
                 && let Ok(text) = String::from_utf8(output.stdout) {
                     let gpus = Self::parse_windows_gpu_list(&text);
@@ -1110,14 +1459,14 @@ impl SystemSpecs {
 
     /// Fallback Windows GPU detection via wmic (works on older systems).
     fn detect_gpu_windows_wmic_list() -> Vec<GpuInfo> {
-        let output = match std::process::Command::new("wmic")
-            .arg("path")
-            .arg("win32_VideoController")
-            .arg("get")
-            .arg("Name,AdapterRAM")
-            .arg("/format:csv")
-            .output()
-        {
+        let output = match crate::subprocess::output(
+            std::process::Command::new("wmic")
+                .arg("path")
+                .arg("win32_VideoController")
+                .arg("get")
+                .arg("Name,AdapterRAM,PNPDeviceID")
+                .arg("/format:csv"),
+        ) {
             Ok(o) if o.status.success() => o,
             _ => return Vec::new(),
         };
@@ -1128,16 +1477,17 @@ impl SystemSpecs {
         };
 
         let mut gpus = Vec::new();
-        // CSV format: Node,AdapterRAM,Name
+        // CSV format: Node,AdapterRAM,Name,PNPDeviceID (columns are sorted)
         for line in text.lines().skip(1) {
             let line = line.trim();
             if line.is_empty() {
                 continue;
             }
             let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() >= 3 {
+            if parts.len() >= 4 {
                 let raw_vram: u64 = parts[1].trim().parse().unwrap_or(0);
-                let name = parts[2..].join(",").trim().to_string();
+                let name = parts[2..parts.len() - 1].join(",").trim().to_string();
+                let pci_id = pci_id_from_pnp_device_id(parts[parts.len() - 1]);
                 let lower = name.to_lowercase();
                 if lower.contains("microsoft")
                     || lower.contains("basic")
@@ -1146,20 +1496,22 @@ impl SystemSpecs {
                     continue;
                 }
                 let backend = Self::infer_gpu_backend(&name);
-                let vram_gb = Self::resolve_wmi_vram(raw_vram, &name);
+                let vram_gb = Self::resolve_wmi_vram(raw_vram, &name, pci_id.as_deref());
                 gpus.push(GpuInfo {
                     name,
                     vram_gb,
                     backend,
                     count: 1,
                     unified_memory: false,
+                    driver_version: None,
                 });
             }
         }
         gpus
     }
 
-    /// Parse all GPU entries from PowerShell output (Name|AdapterRAM per line).
+    /// Parse all GPU entries from PowerShell output
+    /// (Name|AdapterRAM|PNPDeviceID per line; the device ID is optional).
     fn parse_windows_gpu_list(text: &str) -> Vec<GpuInfo> {
         let mut gpus = Vec::new();
         for line in text.lines() {
@@ -1167,12 +1519,13 @@ impl SystemSpecs {
             if line.is_empty() {
                 continue;
             }
-            let parts: Vec<&str> = line.splitn(2, '|').collect();
+            let parts: Vec<&str> = line.splitn(3, '|').collect();
             let name = parts[0].trim().to_string();
             let raw_vram: u64 = parts
                 .get(1)
                 .and_then(|v| v.trim().parse().ok())
                 .unwrap_or(0);
+            let pci_id = parts.get(2).and_then(|p| pci_id_from_pnp_device_id(p));
 
             let lower = name.to_lowercase();
             if lower.contains("microsoft")
@@ -1184,13 +1537,14 @@ impl SystemSpecs {
             }
 
             let backend = Self::infer_gpu_backend(&name);
-            let vram_gb = Self::resolve_wmi_vram(raw_vram, &name);
+            let vram_gb = Self::resolve_wmi_vram(raw_vram, &name, pci_id.as_deref());
             gpus.push(GpuInfo {
                 name,
                 vram_gb,
                 backend,
                 count: 1,
                 unified_memory: false,
+                driver_version: None,
             });
         }
         gpus
@@ -1265,9 +1619,15 @@ impl SystemSpecs {
     }
 
     /// WMI AdapterRAM is a 32-bit field, capped at ~4 GB.
-    /// If reported value is suspiciously low, estimate from GPU name.
-    fn resolve_wmi_vram(raw_bytes: u64, name: &str) -> Option<f64> {
+    /// If reported value is suspiciously low, look the card up by its PCI ID,
+    /// then estimate from GPU name.
+    fn resolve_wmi_vram(raw_bytes: u64, name: &str, pci_id: Option<&str>) -> Option<f64> {
         let mut vram_gb = raw_bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+        if let Some(table_gb) = pci_id.and_then(pci_vram_gb)
+            && (vram_gb < 0.1 || (vram_gb <= 4.1 && table_gb > 4.1))
+        {
+            return Some(table_gb);
+        }
         if vram_gb < 0.1 || (vram_gb <= 4.1 && estimate_vram_from_name(name) > 4.1) {
             let estimated = estimate_vram_from_name(name);
             if estimated > 0.0 {
@@ -1343,6 +1703,7 @@ impl SystemSpecs {
                         backend: GpuBackend::Sycl,
                         count: 1,
                         unified_memory: false,
+                        driver_version: None,
                     }];
                 }
             }
@@ -1382,6 +1743,7 @@ impl SystemSpecs {
                     backend: GpuBackend::Sycl,
                     count: 1,
                     unified_memory: true,
+                    driver_version: None,
                 });
             } else {
                 gpus.push(GpuInfo {
@@ -1390,6 +1752,7 @@ impl SystemSpecs {
                     backend: GpuBackend::Sycl,
                     count: 1,
                     unified_memory: false,
+                    driver_version: None,
                 });
             }
         }
@@ -1474,17 +1837,7 @@ impl SystemSpecs {
     /// The unified memory pool capacity is the total RAM -- it doesn't
     /// fluctuate with current usage the way available RAM does.
     fn detect_apple_gpu(total_ram_gb: f64) -> Option<f64> {
-        // system_profiler only exists on macOS
-        let output = std::process::Command::new("system_profiler")
-            .arg("SPDisplaysDataType")
-            .output()
-            .ok()?;
-
-        if !output.status.success() {
-            return None;
-        }
-
-        let text = String::from_utf8(output.stdout).ok()?;
+        let text = Self::apple_displays_report()?;
 
         // Apple Silicon GPUs show "Apple M1/M2/M3/M4" in the chipset line.
         // Discrete AMD/Intel GPUs on older Macs won't match.
@@ -1502,6 +1855,38 @@ impl SystemSpecs {
         }
     }
 
+    /// Text output of `system_profiler SPDisplaysDataType`, run once per
+    /// process and shared by the Apple Silicon probes below. `None` off
+    /// macOS or when the command fails.
+    fn apple_displays_report() -> Option<&'static str> {
+        static REPORT: std::sync::OnceLock<Option<String>> = std::sync::OnceLock::new();
+        REPORT
+            .get_or_init(|| {
+                // system_profiler only exists on macOS
+                let output = crate::subprocess::output(
+                    std::process::Command::new("system_profiler").arg("SPDisplaysDataType"),
+                )
+                .ok()?;
+                if !output.status.success() {
+                    return None;
+                }
+                String::from_utf8(output.stdout).ok()
+            })
+            .as_deref()
+    }
+
+    /// Apple Silicon chip name ("Apple M2 Max") from system_profiler's
+    /// "Chipset Model" line.
+    fn detect_apple_chipset_name() -> Option<String> {
+        parse_apple_chipset_name(Self::apple_displays_report()?)
+    }
+
+    /// Apple Silicon GPU core count from system_profiler's
+    /// "Total Number of Cores" line.
+    fn detect_apple_gpu_cores() -> Option<u32> {
+        parse_apple_gpu_cores(Self::apple_displays_report()?)
+    }
+
     /// Detect macOS Metal GPUs from system_profiler.
     ///
     /// This covers Intel Macs with built-in Intel graphics and discrete AMD
@@ -1512,9 +1897,9 @@ impl SystemSpecs {
             return Vec::new();
         }
 
-        let output = std::process::Command::new("system_profiler")
-            .args(["SPDisplaysDataType", "-json"])
-            .output();
+        let output = crate::subprocess::output(
+            std::process::Command::new("system_profiler").args(["SPDisplaysDataType", "-json"]),
+        );
         let Ok(output) = output else {
             return Vec::new();
         };
@@ -1568,6 +1953,7 @@ impl SystemSpecs {
                     backend: GpuBackend::Metal,
                     count: 1,
                     unified_memory: false,
+                    driver_version: None,
                 })
             })
             .collect()
@@ -1603,12 +1989,11 @@ impl SystemSpecs {
             return Vec::new();
         }
 
-        let output = match std::process::Command::new("vulkaninfo")
-            .arg("--summary")
-            .output()
-        {
+        let output = match crate::subprocess::output(
+            std::process::Command::new("vulkaninfo").arg("--summary"),
+        ) {
             Ok(o) if o.status.success() => o,
-            _ => match std::process::Command::new("vulkaninfo").output() {
+            _ => match crate::subprocess::output(&mut std::process::Command::new("vulkaninfo")) {
                 Ok(o) if o.status.success() => o,
                 _ => return Vec::new(),
             },
@@ -1632,6 +2017,7 @@ impl SystemSpecs {
                 name,
                 unified_memory: false,
                 vram_gb: None,
+                driver_version: None,
             })
             .collect()
     }
@@ -1806,10 +2192,9 @@ impl SystemSpecs {
     /// Detect Ascend NPUs via npu-smi. Returns a vector of NPU info.
     fn detect_ascend_npus() -> Vec<GpuInfo> {
         // 1. Get the list of IDs
-        let list_output = match std::process::Command::new("npu-smi")
-            .args(["info", "-l"])
-            .output()
-        {
+        let list_output = match crate::subprocess::output(
+            std::process::Command::new("npu-smi").args(["info", "-l"]),
+        ) {
             Ok(o) if o.status.success() => o,
             _ => return Vec::new(),
         };
@@ -1833,9 +2218,9 @@ impl SystemSpecs {
 
         // 2. Loop through NPUs
         for id in &ids {
-            let mem_output = std::process::Command::new("npu-smi")
-                .args(["info", "-t", "memory", "-i", id])
-                .output();
+            let mem_output = crate::subprocess::output(
+                std::process::Command::new("npu-smi").args(["info", "-t", "memory", "-i", id]),
+            );
 
             if let Ok(o) = mem_output {
                 let s = String::from_utf8_lossy(&o.stdout);
@@ -1855,6 +2240,7 @@ impl SystemSpecs {
                     backend: GpuBackend::Ascend,
                     count: 1,
                     unified_memory: false,
+                    driver_version: None,
                 };
                 npu_infos.push(npu_info);
             }
@@ -1884,7 +2270,7 @@ impl SystemSpecs {
     /// Parse macOS `vm_stat` to compute available memory.
     /// Available ≈ (free + inactive + purgeable) * page_size
     fn available_ram_from_vm_stat() -> Option<f64> {
-        let output = std::process::Command::new("vm_stat").output().ok()?;
+        let output = crate::subprocess::output(&mut std::process::Command::new("vm_stat")).ok()?;
         if !output.status.success() {
             return None;
         }
@@ -1994,10 +2380,10 @@ impl SystemSpecs {
     fn read_android_soc_name() -> Option<String> {
         #[cfg(target_os = "linux")]
         {
-            let output = std::process::Command::new("getprop")
-                .arg("ro.soc.model")
-                .output()
-                .ok()?;
+            let output = crate::subprocess::output(
+                std::process::Command::new("getprop").arg("ro.soc.model"),
+            )
+            .ok()?;
             if !output.status.success() {
                 return None;
             }
@@ -2036,6 +2422,7 @@ impl SystemSpecs {
                 backend,
                 count: 1,
                 unified_memory: false,
+                driver_version: None,
             });
             self.has_gpu = true;
             self.gpu_vram_gb = Some(vram_gb);
@@ -2086,15 +2473,158 @@ impl SystemSpecs {
         self
     }
 
+    /// Pin the acceleration backend used for the whole fit computation, for
+    /// "what if" comparisons (e.g. CPU-only rankings on a GPU machine).
+    /// Forcing a CPU backend drops every GPU so models run from system RAM;
+    /// forcing a GPU backend relabels the detected GPUs and is a no-op on
+    /// machines without one.
+    pub fn with_backend_override(mut self, backend: GpuBackend) -> Self {
+        if matches!(backend, GpuBackend::CpuArm | GpuBackend::CpuX86) {
+            self.has_gpu = false;
+            self.gpu_vram_gb = None;
+            self.total_gpu_vram_gb = None;
+            self.gpu_available_gb = None;
+            self.gpu_name = None;
+            self.gpu_count = 0;
+            self.unified_memory = false;
+            self.gpus.clear();
+            self.backend = backend;
+        } else if self.has_gpu {
+            for gpu in &mut self.gpus {
+                gpu.backend = backend;
+            }
+            self.backend = backend;
+        }
+        self
+    }
+
+    /// True when any detected GPU is an AMD/Radeon card, regardless of
+    /// whether it is driven via ROCm or Vulkan.
+    pub fn has_amd_gpu(&self) -> bool {
+        self.backend == GpuBackend::Rocm
+            || self.gpus.iter().any(|gpu| {
+                let lower = gpu.name.to_lowercase();
+                lower.contains("amd") || lower.contains("radeon")
+            })
+    }
+
+    /// Note for AMD systems with ZLUDA installed: CUDA builds of runtimes
+    /// can run on the AMD card, so CUDA does not imply NVIDIA here.
+    pub fn zluda_note(&self, zluda_available: bool) -> Option<String> {
+        (zluda_available && self.has_amd_gpu()).then(|| {
+            "ZLUDA detected: CUDA builds of llama.cpp/vLLM may run on this AMD GPU".to_string()
+        })
+    }
+
+    /// Whether this machine's AMD GPU should run llama.cpp on ROCm or Vulkan,
+    /// for the host OS. `None` without a recognizable AMD GPU.
+    pub fn amd_backend_advice(&self) -> Option<AmdBackendAdvice> {
+        self.amd_backend_advice_for(std::env::consts::OS)
+    }
+
+    /// [`SystemSpecs::amd_backend_advice`] for an explicit OS
+    /// (`std::env::consts::OS` values: "linux", "windows", ...).
+    pub fn amd_backend_advice_for(&self, os: &str) -> Option<AmdBackendAdvice> {
+        let name = self
+            .gpus
+            .iter()
+            .map(|gpu| gpu.name.as_str())
+            .chain(self.gpu_name.as_deref())
+            .find(|name| amd_generation(name).is_some())?;
+        let generation = amd_generation(name)?;
+        let (backend, reason) = match (generation, os) {
+            (AmdGeneration::Cdna, _) => (GpuBackend::Rocm, "Instinct accelerators are ROCm-only"),
+            (AmdGeneration::Rdna2 | AmdGeneration::Rdna3 | AmdGeneration::Rdna4, "linux") => (
+                GpuBackend::Rocm,
+                "this card is supported by ROCm, whose HIP kernels are faster here",
+            ),
+            (AmdGeneration::Rdna3 | AmdGeneration::Rdna4, "windows") => (
+                GpuBackend::Rocm,
+                "the HIP SDK supports this card on Windows",
+            ),
+            (AmdGeneration::Rdna2, _) => (
+                GpuBackend::Vulkan,
+                "ROCm on this OS doesn't cover RDNA2 cards",
+            ),
+            (AmdGeneration::Gcn | AmdGeneration::Rdna1, _) => (
+                GpuBackend::Vulkan,
+                "current ROCm releases no longer support this card",
+            ),
+            _ => return None,
+        };
+        Some(AmdBackendAdvice {
+            generation,
+            backend,
+            reason: reason.to_string(),
+        })
+    }
+
+    /// Decode-speed multiplier for a binned Apple Silicon GPU relative to the
+    /// full chip the bandwidth table describes. Token generation is mostly
+    /// bandwidth-bound, so it scales sublinearly with cores: llama.cpp's
+    /// Apple Silicon table has the 24-core M1 Max at ~0.87x the 32-core
+    /// (sqrt(24/32) = 0.87). 1.0 when the core count or chip is unknown.
+    pub fn apple_gpu_core_factor(&self) -> f64 {
+        if self.backend != GpuBackend::Metal || !self.unified_memory {
+            return 1.0;
+        }
+        let (Some(cores), Some(full)) = (
+            self.gpu_cores,
+            self.gpu_name.as_deref().and_then(apple_full_gpu_cores),
+        ) else {
+            return 1.0;
+        };
+        (f64::from(cores) / f64::from(full)).sqrt().clamp(0.5, 1.0)
+    }
+
+    /// Driver version of the first NVIDIA (CUDA) GPU, when detected.
+    pub fn cuda_driver_version(&self) -> Option<&str> {
+        self.driver_version_for(GpuBackend::Cuda)
+    }
+
+    /// Driver version of the first GPU on `backend`, when detected.
+    pub fn driver_version_for(&self, backend: GpuBackend) -> Option<&str> {
+        self.gpus
+            .iter()
+            .find(|gpu| gpu.backend == backend)
+            .and_then(|gpu| gpu.driver_version.as_deref())
+    }
+
+    /// True when the CUDA driver is older than [`MIN_CUDA_DRIVER_MAJOR`].
+    /// Unknown or unparseable versions are not flagged.
+    pub fn cuda_driver_outdated(&self) -> bool {
+        self.backend == GpuBackend::Cuda
+            && self
+                .cuda_driver_version()
+                .and_then(driver_major_version)
+                .is_some_and(|major| major < MIN_CUDA_DRIVER_MAJOR)
+    }
+
     pub fn display(&self) {
         println!("\n=== System Specifications ===");
         println!("CPU: {} ({} cores)", self.cpu_name, self.total_cpu_cores);
         println!("Total RAM: {:.2} GB", self.total_ram_gb);
         println!("Available RAM: {:.2} GB", self.available_ram_gb);
+        if let Some(ram) = &self.ram_config {
+            println!(
+                "RAM Type: {} ({:.0} GB/s peak)",
+                ram.label(),
+                ram.peak_bandwidth_gbps()
+            );
+        }
         if let Some(bw) = measured_ram_bandwidth_gbps() {
             println!("RAM Bandwidth: ~{bw:.0} GB/s (measured)");
         }
         println!("Backend: {}", self.backend.label());
+        if let Some(cores) = self.gpu_cores {
+            println!("GPU Cores: {cores}");
+        }
+        if self.zluda_note(is_zluda_available()).is_some() {
+            println!("ZLUDA: detected (CUDA-on-AMD)");
+        }
+        if let Some(warning) = rosetta_warning(is_rosetta_translated()) {
+            println!("Rosetta: {warning}");
+        }
 
         if self.gpus.is_empty() {
             println!("GPU: Not detected");
@@ -2232,36 +2762,504 @@ pub fn parse_memory_size(s: &str) -> Option<f64> {
     }
 }
 
-pub fn is_running_in_wsl() -> bool {
-    static IS_WSL: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
-    *IS_WSL.get_or_init(detect_running_in_wsl)
+/// Parse a `--backend` name into a [`GpuBackend`]. Case-insensitive; plain
+/// `cpu` resolves to the CPU flavour of the host architecture.
+pub fn parse_backend(s: &str) -> Option<GpuBackend> {
+    match s.trim().to_lowercase().as_str() {
+        "cpu" if cfg!(target_arch = "aarch64") => Some(GpuBackend::CpuArm),
+        "cpu" => Some(GpuBackend::CpuX86),
+        "cpu-arm" => Some(GpuBackend::CpuArm),
+        "cpu-x86" => Some(GpuBackend::CpuX86),
+        "cuda" => Some(GpuBackend::Cuda),
+        "metal" => Some(GpuBackend::Metal),
+        "rocm" => Some(GpuBackend::Rocm),
+        "vulkan" => Some(GpuBackend::Vulkan),
+        "sycl" => Some(GpuBackend::Sycl),
+        "ascend" => Some(GpuBackend::Ascend),
+        "openvino" | "npu" => Some(GpuBackend::OpenVino),
+        _ => None,
+    }
 }
 
-fn detect_running_in_wsl() -> bool {
-    if !cfg!(target_os = "linux") {
-        return false;
-    }
+/// Where the Windows GPU driver shim puts `nvidia-smi` inside WSL2. WSL adds
+/// the directory to PATH, but distros with a custom PATH (or systemd login
+/// shells) often drop it.
+const WSL_NVIDIA_SMI: &str = "/usr/lib/wsl/lib/nvidia-smi";
+
+/// WSL2 quirks that change what the Linux-side probes see.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WslInfo {
+    /// `memory=` cap from the Windows-side `.wslconfig`, in GB. The VM never
+    /// gets more than this, however much RAM the host has.
+    pub memory_limit_gb: Option<f64>,
+    /// `/dev/dxg` exists: the Windows GPU driver is passed through to the VM
+    /// (CUDA via the WSL driver shim, DirectML otherwise). There is no
+    /// `/sys/class/drm` entry for the card, so only nvidia-smi can size it.
+    pub gpu_passthrough: bool,
+}
 
-    if std::env::var_os("WSL_INTEROP").is_some() || std::env::var_os("WSL_DISTRO_NAME").is_some() {
-        return true;
+impl WslInfo {
+    /// Clamp total and available RAM to the `.wslconfig` memory cap.
+    pub fn clamp_ram(&self, total_gb: f64, available_gb: f64) -> (f64, f64) {
+        match self.memory_limit_gb {
+            Some(limit) if limit > 0.0 => (total_gb.min(limit), available_gb.min(limit)),
+            _ => (total_gb, available_gb),
+        }
     }
+}
 
-    ["/proc/sys/kernel/osrelease", "/proc/version"]
-        .iter()
-        .any(|path| {
-            std::fs::read_to_string(path)
-                .map(|text| text.to_ascii_lowercase().contains("microsoft"))
-                .unwrap_or(false)
-        })
+pub fn is_running_in_wsl() -> bool {
+    wsl_info().is_some()
 }
 
-/// Check if the CPU name indicates an AMD APU with unified memory architecture.
-/// These APUs share the full system RAM between CPU and GPU (like Apple Silicon).
-/// Currently covers:
-///  - Ryzen AI MAX / MAX+ (Strix Halo): up to 128 GB unified.
-///  - Ryzen AI 9 / 7 / 5 (Strix Point, Krackan Point): configurable shared
-///    memory, users can allocate most of system RAM to GPU via BIOS.
-/// All Ryzen AI APUs have integrated Radeon GPUs that share system memory.
+/// WSL2 details when running inside WSL, `None` otherwise. Cached, since
+/// neither the kernel nor `.wslconfig` changes during a run.
+pub fn wsl_info() -> Option<&'static WslInfo> {
+    static WSL: std::sync::OnceLock<Option<WslInfo>> = std::sync::OnceLock::new();
+    WSL.get_or_init(|| {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+        detect_wsl(
+            |var| std::env::var_os(var).map(|v| v.to_string_lossy().into_owned()),
+            |path| std::fs::read_to_string(path).ok(),
+            |path| std::path::Path::new(path).exists(),
+        )
+    })
+    .as_ref()
+}
+
+/// WSL is detected from the interop env vars or a "microsoft" kernel
+/// banner in `/proc/version` / `osrelease`.
+fn detect_wsl(
+    env: impl Fn(&str) -> Option<String>,
+    read_file: impl Fn(&str) -> Option<String>,
+    path_exists: impl Fn(&str) -> bool,
+) -> Option<WslInfo> {
+    let is_wsl = env("WSL_INTEROP").is_some()
+        || env("WSL_DISTRO_NAME").is_some()
+        || ["/proc/sys/kernel/osrelease", "/proc/version"]
+            .iter()
+            .any(|path| read_file(path).is_some_and(|text| kernel_banner_is_wsl(&text)));
+    if !is_wsl {
+        return None;
+    }
+
+    let memory_limit_gb = wslconfig_path(&env)
+        .and_then(|path| read_file(&path))
+        .and_then(|text| parse_wslconfig_memory_gb(&text));
+    Some(WslInfo {
+        memory_limit_gb,
+        gpu_passthrough: path_exists("/dev/dxg"),
+    })
+}
+
+fn kernel_banner_is_wsl(text: &str) -> bool {
+    text.to_ascii_lowercase().contains("microsoft")
+}
+
+/// `.wslconfig` lives in the Windows user profile. `USERPROFILE` is only
+/// visible when forwarded through `WSLENV` (as a Linux path with `/p`, a
+/// Windows path otherwise); without it, guess the Windows user matches the
+/// Linux one.
+fn wslconfig_path(env: &impl Fn(&str) -> Option<String>) -> Option<String> {
+    if let Some(profile) = env("USERPROFILE").filter(|p| !p.trim().is_empty()) {
+        let profile = profile.trim().trim_end_matches(['\\', '/']);
+        if profile.starts_with('/') {
+            return Some(format!("{profile}/.wslconfig"));
+        }
+        let mut chars = profile.chars();
+        if let (Some(drive), Some(':')) = (chars.next(), chars.next()) {
+            let rest = chars.as_str().replace('\\', "/");
+            return Some(format!(
+                "/mnt/{}{rest}/.wslconfig",
+                drive.to_ascii_lowercase()
+            ));
+        }
+    }
+    let user = env("USER").filter(|u| !u.trim().is_empty())?;
+    Some(format!("/mnt/c/Users/{user}/.wslconfig"))
+}
+
+/// `memory=` from the `[wsl2]` section of a `.wslconfig`, in GB. Accepts
+/// the units WSL does (`8GB`, `4096MB`, `1TB`, bare bytes).
+fn parse_wslconfig_memory_gb(text: &str) -> Option<f64> {
+    let mut in_wsl2 = false;
+    for line in text.lines() {
+        let line = line.split(['#', ';']).next().unwrap_or("").trim();
+        if line.starts_with('[') {
+            in_wsl2 = line.eq_ignore_ascii_case("[wsl2]");
+            continue;
+        }
+        if !in_wsl2 {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if !key.trim().eq_ignore_ascii_case("memory") {
+            continue;
+        }
+        let value = value.trim().to_ascii_uppercase();
+        let digits_end = value
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(value.len());
+        let amount: f64 = value[..digits_end].parse().ok()?;
+        let gb = match value[digits_end..].trim() {
+            "" | "B" => amount / (1024.0 * 1024.0 * 1024.0),
+            "K" | "KB" => amount / (1024.0 * 1024.0),
+            "M" | "MB" => amount / 1024.0,
+            "G" | "GB" => amount,
+            "T" | "TB" => amount * 1024.0,
+            _ => return None,
+        };
+        return (gb > 0.0).then_some(gb);
+    }
+    None
+}
+
+/// The nvidia-smi to run: the WSL driver shim when the GPU is passed through
+/// and the shim exists, plain `nvidia-smi` from PATH otherwise.
+pub(crate) fn nvidia_smi_program() -> &'static str {
+    if wsl_info().is_some_and(|wsl| wsl.gpu_passthrough)
+        && std::path::Path::new(WSL_NVIDIA_SMI).exists()
+    {
+        WSL_NVIDIA_SMI
+    } else {
+        "nvidia-smi"
+    }
+}
+
+/// Whether this process is an x86_64 binary translated by Rosetta 2 on
+/// Apple Silicon. Cached, like WSL detection.
+pub fn is_rosetta_translated() -> bool {
+    static ROSETTA: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *ROSETTA.get_or_init(|| {
+        cfg!(all(target_os = "macos", target_arch = "x86_64"))
+            && crate::subprocess::output(
+                std::process::Command::new("sysctl").args(["-n", "sysctl.proc_translated"]),
+            )
+            .is_ok_and(|out| parse_proc_translated(&String::from_utf8_lossy(&out.stdout)))
+    })
+}
+
+/// `sysctl -n sysctl.proc_translated` prints 1 under Rosetta, 0 for native
+/// processes, and nothing on Intel Macs where the key doesn't exist.
+fn parse_proc_translated(output: &str) -> bool {
+    output.trim() == "1"
+}
+
+/// Warning shown when running under Rosetta: detection is corrected for the
+/// real chip, but the native build is faster and sees the hardware directly.
+pub fn rosetta_warning(translated: bool) -> Option<&'static str> {
+    translated.then_some(
+        "llmfit is an x86_64 build running under Rosetta on Apple Silicon; \
+         install the arm64 build for accurate Metal/MLX detection",
+    )
+}
+
+/// Chip name from the "Chipset Model: Apple M2 Max" line of
+/// `system_profiler SPDisplaysDataType` output.
+fn parse_apple_chipset_name(text: &str) -> Option<String> {
+    text.lines().find_map(|line| {
+        let name = line.trim().strip_prefix("Chipset Model:")?.trim();
+        name.to_lowercase()
+            .starts_with("apple m")
+            .then(|| name.to_string())
+    })
+}
+
+/// Intel NPUs by PCI `vendor:device` id.
+const INTEL_NPU_PCI_IDS: &[(&str, &str)] = &[
+    ("8086:7d1d", "Intel AI Boost NPU (Meteor Lake)"),
+    ("8086:ad1d", "Intel AI Boost NPU (Arrow Lake)"),
+    ("8086:643e", "Intel AI Boost NPU (Lunar Lake)"),
+    ("8086:b03e", "Intel AI Boost NPU (Panther Lake)"),
+];
+
+/// Product name of the Intel NPU with PCI id `pci_id` (`vendor:device`).
+pub fn intel_npu_name(pci_id: &str) -> Option<&'static str> {
+    INTEL_NPU_PCI_IDS
+        .iter()
+        .find(|(id, _)| id.eq_ignore_ascii_case(pci_id))
+        .map(|(_, name)| *name)
+}
+
+/// One OpenVINO NPU entry for the first known Intel NPU among `pci_ids`,
+/// sharing the `total_ram_gb` system pool.
+fn intel_npus_from_pci_ids(pci_ids: &[String], total_ram_gb: f64) -> Vec<GpuInfo> {
+    pci_ids
+        .iter()
+        .find_map(|id| intel_npu_name(id))
+        .map(|name| GpuInfo {
+            name: name.to_string(),
+            vram_gb: Some(total_ram_gb),
+            backend: GpuBackend::OpenVino,
+            count: 1,
+            unified_memory: true,
+            driver_version: None,
+        })
+        .into_iter()
+        .collect()
+}
+
+/// NPUs only become the accelerator when no GPU was found: for LLM decode an
+/// Intel iGPU on the same memory bus is at least as fast, and listing both
+/// would count the shared RAM pool twice.
+fn with_npu_fallback(gpus: Vec<GpuInfo>, npus: Vec<GpuInfo>) -> Vec<GpuInfo> {
+    if gpus.is_empty() { npus } else { gpus }
+}
+
+/// Parse the GPU core count from `system_profiler SPDisplaysDataType` text
+/// output ("Total Number of Cores: 40").
+fn parse_apple_gpu_cores(text: &str) -> Option<u32> {
+    text.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix("Total Number of Cores:")?
+            .trim()
+            .parse()
+            .ok()
+    })
+}
+
+/// GPU core count of the full (unbinned) configuration of an Apple Silicon
+/// chip, or `None` when the chip isn't known.
+pub fn apple_full_gpu_cores(gpu_name: &str) -> Option<u32> {
+    let lower = gpu_name.to_lowercase();
+    let generation = ["m1", "m2", "m3", "m4", "m5"]
+        .into_iter()
+        .position(|g| lower.contains(g))?;
+    // Columns: base, Pro, Max, Ultra. `None` = not shipped / not known.
+    const CORES: [[Option<u32>; 4]; 5] = [
+        [Some(8), Some(16), Some(32), Some(64)],  // M1
+        [Some(10), Some(19), Some(38), Some(76)], // M2
+        [Some(10), Some(18), Some(40), Some(80)], // M3
+        [Some(10), Some(20), Some(40), None],     // M4
+        [Some(10), None, None, None],             // M5
+    ];
+    let tier = if lower.contains("ultra") {
+        3
+    } else if lower.contains("max") {
+        2
+    } else if lower.contains("pro") {
+        1
+    } else {
+        0
+    };
+    CORES[generation][tier]
+}
+
+/// Whether llmfit is running on an interactive desktop session rather than a
+/// headless server. macOS and Windows always count; on Linux a display server
+/// (`DISPLAY` / `WAYLAND_DISPLAY`) must be present. Cached for the run.
+pub fn is_interactive_desktop() -> bool {
+    static DESKTOP: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *DESKTOP.get_or_init(|| {
+        detect_interactive_desktop(std::env::consts::OS, |var| {
+            std::env::var_os(var).is_some_and(|v| !v.is_empty())
+        })
+    })
+}
+
+fn detect_interactive_desktop(os: &str, env_set: impl Fn(&str) -> bool) -> bool {
+    match os {
+        "macos" | "windows" => true,
+        _ => env_set("DISPLAY") || env_set("WAYLAND_DISPLAY"),
+    }
+}
+
+/// Environment variables that point at a ZLUDA (CUDA-on-AMD) install.
+const ZLUDA_ENV_VARS: &[&str] = &["ZLUDA_PATH", "ZLUDA_HOME"];
+
+/// Well-known ZLUDA install locations, checked when no env var is set.
+const ZLUDA_INSTALL_PATHS: &[&str] = &[
+    "/opt/zluda",
+    "/usr/local/zluda",
+    "C:\\zluda",
+    "C:\\Program Files\\ZLUDA",
+];
+
+/// AMD GPU architecture family, which decides ROCm support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmdGeneration {
+    /// Polaris and Vega (gfx8xx, gfx900-gfx90c).
+    Gcn,
+    /// RX 5000 series (gfx101x).
+    Rdna1,
+    /// RX 6000 series and Radeon 6x0M iGPUs (gfx103x).
+    Rdna2,
+    /// RX 7000 series and Radeon 7x0M/8x0M/8060S iGPUs (gfx11xx).
+    Rdna3,
+    /// RX 9000 series (gfx12xx).
+    Rdna4,
+    /// Instinct MI100 and later (gfx908, gfx90a, gfx94x, gfx95x).
+    Cdna,
+}
+
+impl AmdGeneration {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AmdGeneration::Gcn => "GCN",
+            AmdGeneration::Rdna1 => "RDNA1",
+            AmdGeneration::Rdna2 => "RDNA2",
+            AmdGeneration::Rdna3 => "RDNA3",
+            AmdGeneration::Rdna4 => "RDNA4",
+            AmdGeneration::Cdna => "CDNA",
+        }
+    }
+}
+
+/// llama.cpp with Vulkan on cards ROCm has dropped runs somewhat below the
+/// bandwidth roofline, which is calibrated on ROCm (RX 6900 XT).
+pub const VULKAN_TPS_FACTOR: f64 = 0.85;
+
+/// Recommended llama.cpp backend for an AMD GPU.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmdBackendAdvice {
+    pub generation: AmdGeneration,
+    /// [`GpuBackend::Rocm`] or [`GpuBackend::Vulkan`].
+    pub backend: GpuBackend,
+    pub reason: String,
+}
+
+impl AmdBackendAdvice {
+    /// Throughput on the recommended path relative to the bandwidth estimate.
+    pub fn tps_factor(&self) -> f64 {
+        if self.backend == GpuBackend::Vulkan {
+            VULKAN_TPS_FACTOR
+        } else {
+            1.0
+        }
+    }
+
+    /// e.g. "Use ROCm (RDNA3): this card is supported by ROCm, ..."
+    pub fn note(&self) -> String {
+        format!(
+            "Use {} ({}): {}",
+            self.backend.label(),
+            self.generation.label(),
+            self.reason
+        )
+    }
+}
+
+/// Classify an AMD GPU from its gfx target (`"AMD Radeon Graphics (gfx1151)"`,
+/// as rocm-smi names it) or its marketing name. `None` for non-AMD GPUs and
+/// names that don't identify a generation.
+pub fn amd_generation(gpu_name: &str) -> Option<AmdGeneration> {
+    let lower = gpu_name.to_lowercase();
+    if let Some(idx) = lower.find("gfx") {
+        let target: String = lower[idx + 3..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect();
+        return match target.as_str() {
+            "908" | "90a" => Some(AmdGeneration::Cdna),
+            t if t.starts_with("94") || t.starts_with("95") => Some(AmdGeneration::Cdna),
+            t if t.starts_with('8') || (t.starts_with("90") && t.len() == 3) => {
+                Some(AmdGeneration::Gcn)
+            }
+            t if t.starts_with("101") => Some(AmdGeneration::Rdna1),
+            t if t.starts_with("103") => Some(AmdGeneration::Rdna2),
+            t if t.starts_with("11") && t.len() == 4 => Some(AmdGeneration::Rdna3),
+            t if t.starts_with("12") && t.len() == 4 => Some(AmdGeneration::Rdna4),
+            _ => None,
+        };
+    }
+    if !(lower.contains("amd") || lower.contains("radeon") || lower.contains("instinct")) {
+        return None;
+    }
+    if lower.contains("instinct")
+        || lower
+            .split_whitespace()
+            .any(|w| w.starts_with("mi") && w[2..].starts_with(|c: char| c.is_ascii_digit()))
+    {
+        return Some(AmdGeneration::Cdna);
+    }
+    if lower.contains("vega") || lower.contains("radeon vii") {
+        return Some(AmdGeneration::Gcn);
+    }
+    // Model number: the first all-digit token, optionally with an M/S
+    // (mobile iGPU) suffix or a W (workstation) prefix.
+    let number = lower.split_whitespace().find_map(|w| {
+        let w = w.trim_start_matches('w');
+        let digits = w.trim_end_matches(['m', 's']);
+        (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+            .then(|| (digits.to_string(), digits.len() != w.len()))
+    })?;
+    let (digits, igpu) = number;
+    let first = digits.chars().next()?;
+    match (digits.len(), igpu, first) {
+        // Radeon 660M/680M (RDNA2), 740M-890M and 8060S (RDNA3/3.5).
+        (3, true, '6') => Some(AmdGeneration::Rdna2),
+        (3, true, '7' | '8') | (4, true, '8') => Some(AmdGeneration::Rdna3),
+        // RX 460-590 (Polaris).
+        (3, false, '4' | '5') => Some(AmdGeneration::Gcn),
+        (4, false, '5') => Some(AmdGeneration::Rdna1),
+        (4, false, '6') => Some(AmdGeneration::Rdna2),
+        (4, false, '7') => Some(AmdGeneration::Rdna3),
+        (4, false, '9') => Some(AmdGeneration::Rdna4),
+        _ => None,
+    }
+}
+
+/// Whether a ZLUDA install was found on this machine. Cached, like WSL
+/// detection, since neither the environment nor the install paths change
+/// during a run.
+pub fn is_zluda_available() -> bool {
+    static ZLUDA: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *ZLUDA.get_or_init(|| {
+        detect_zluda(
+            |var| std::env::var_os(var).map(|v| v.to_string_lossy().into_owned()),
+            |path| std::path::Path::new(path).exists(),
+        )
+    })
+}
+
+/// ZLUDA is considered present when one of its env vars is set to a
+/// non-empty value or a known install directory exists.
+fn detect_zluda(env: impl Fn(&str) -> Option<String>, path_exists: impl Fn(&str) -> bool) -> bool {
+    ZLUDA_ENV_VARS
+        .iter()
+        .any(|var| env(var).is_some_and(|v| !v.trim().is_empty()))
+        || ZLUDA_INSTALL_PATHS.iter().any(|path| path_exists(path))
+}
+
+/// Share of system RAM the amdgpu driver exposes as GTT when
+/// `amdgpu.gttsize` is not set (the kernel default is half of RAM).
+const AMD_APU_DEFAULT_GTT_FRACTION: f64 = 0.5;
+
+/// Memory an AMD APU iGPU can use for inference, or `None` for a discrete
+/// card. APUs report a small BIOS carve-out as `mem_info_vram_total`
+/// (512 MB-2 GB) but compute out of GTT, a slice of system RAM sized by the
+/// `amdgpu.gttsize` kernel parameter. The budget is the reported GTT size,
+/// capped at total RAM, or the driver's default slice when GTT is unknown.
+fn amd_apu_shared_budget_gb(
+    name: &str,
+    dedicated_vram_gb: Option<f64>,
+    gtt_gb: Option<f64>,
+    total_ram_gb: f64,
+) -> Option<f64> {
+    const APU_CARVEOUT_MAX_GB: f64 = 2.0;
+    let is_apu = SystemSpecs::is_integrated_gpu_name(name)
+        || dedicated_vram_gb.is_some_and(|v| v <= APU_CARVEOUT_MAX_GB);
+    if !is_apu || total_ram_gb <= 0.0 {
+        return None;
+    }
+    let budget = match gtt_gb {
+        Some(gtt) => gtt.min(total_ram_gb),
+        None => total_ram_gb * AMD_APU_DEFAULT_GTT_FRACTION,
+    };
+    (budget > dedicated_vram_gb.unwrap_or(0.0)).then_some(budget)
+}
+
+/// Check if the CPU name indicates an AMD APU with unified memory architecture.
+/// These APUs share the full system RAM between CPU and GPU (like Apple Silicon).
+/// Currently covers:
+///  - Ryzen AI MAX / MAX+ (Strix Halo): up to 128 GB unified.
+///  - Ryzen AI 9 / 7 / 5 (Strix Point, Krackan Point): configurable shared
+///    memory, users can allocate most of system RAM to GPU via BIOS.
+/// All Ryzen AI APUs have integrated Radeon GPUs that share system memory.
 /// Placeholder GPU-name tokens that mean detection failed to read a real
 /// marketing name (rocm-smi prints "N/A" when it can't load libdrm). These
 /// must never be used as an actual GPU identity.
@@ -2314,14 +3312,12 @@ fn detect_windows_physical_total_ram_gb() -> Option<f64> {
     if !cfg!(target_os = "windows") {
         return None;
     }
-    let output = std::process::Command::new("powershell")
-        .args([
-            "-NoProfile",
-            "-Command",
-            "(Get-CimInstance Win32_PhysicalMemory | Measure-Object -Property Capacity -Sum).Sum",
-        ])
-        .output()
-        .ok()?;
+    let output = crate::subprocess::output(std::process::Command::new("powershell").args([
+        "-NoProfile",
+        "-Command",
+        "(Get-CimInstance Win32_PhysicalMemory | Measure-Object -Property Capacity -Sum).Sum",
+    ]))
+    .ok()?;
     if !output.status.success() {
         return None;
     }
@@ -2347,6 +3343,139 @@ fn read_proc_meminfo_total_gb() -> Option<f64> {
     None
 }
 
+/// Detect the installed RAM's generation, speed and channel count. Linux needs
+/// `dmidecode` (and usually root to read the SMBIOS tables); Windows reads
+/// `Win32_PhysicalMemory`. `None` wherever the tables aren't available.
+fn detect_ram_config() -> Option<RamConfig> {
+    if cfg!(target_os = "linux") {
+        let output =
+            crate::subprocess::output(std::process::Command::new("dmidecode").args(["-t", "17"]))
+                .ok()
+                .filter(|o| o.status.success())?;
+        parse_dmidecode_memory(&String::from_utf8_lossy(&output.stdout))
+    } else if cfg!(target_os = "windows") {
+        let output = crate::subprocess::output(
+            std::process::Command::new("powershell")
+                .arg("-NoProfile")
+                .arg("-Command")
+                .arg("Get-CimInstance Win32_PhysicalMemory | ForEach-Object { \"$($_.SMBIOSMemoryType)|$($_.ConfiguredClockSpeed)|$($_.BankLabel)|$($_.DeviceLocator)\" }"),
+        )
+            .ok()
+            .filter(|o| o.status.success())?;
+        parse_win32_physical_memory(&String::from_utf8_lossy(&output.stdout))
+    } else {
+        None
+    }
+}
+
+/// One populated memory module: (type, MT/s, channel key if named).
+type MemoryModule = (String, u32, Option<String>);
+
+/// Channel name from a bank/device locator such as "P0 CHANNEL A",
+/// "ChannelB-DIMM0" or "Controller0-ChannelA-DIMM1".
+fn memory_channel_key(locators: &[&str]) -> Option<String> {
+    locators.iter().find_map(|locator| {
+        let lower = locator.to_ascii_lowercase();
+        let pos = lower.find("channel")?;
+        let rest = lower[pos + "channel".len()..].trim_start_matches([' ', '_', '-']);
+        let key: String = rest
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect();
+        // Keep the controller in the key so multi-controller layouts
+        // ("Controller0-ChannelA", "Controller1-ChannelA") stay distinct.
+        let controller = lower[..pos]
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .rfind(|part| !part.is_empty())
+            .unwrap_or("");
+        (!key.is_empty()).then(|| format!("{controller}/{key}"))
+    })
+}
+
+/// Combine populated modules into a [`RamConfig`]: the most common type, the
+/// slowest speed (the bus runs at it), and one channel per distinct named
+/// channel — or per module when the locators don't name channels.
+fn ram_config_from_modules(modules: &[MemoryModule]) -> Option<RamConfig> {
+    let (kind, _, _) = modules.first()?;
+    let speed_mts = modules.iter().map(|(_, speed, _)| *speed).min()?;
+    let channels = if modules.iter().all(|(_, _, key)| key.is_some()) {
+        modules
+            .iter()
+            .filter_map(|(_, _, key)| key.as_deref())
+            .collect::<std::collections::HashSet<_>>()
+            .len()
+    } else {
+        modules.len()
+    };
+    Some(RamConfig {
+        kind: kind.clone(),
+        speed_mts,
+        channels: channels as u32,
+    })
+}
+
+/// Parse `dmidecode -t 17`: one "Memory Device" block per slot; empty slots
+/// report "No Module Installed".
+fn parse_dmidecode_memory(text: &str) -> Option<RamConfig> {
+    let mut modules = Vec::new();
+    for block in text.split("Memory Device").skip(1) {
+        let field = |name: &str| {
+            block.lines().find_map(|line| {
+                let (key, value) = line.trim().split_once(':')?;
+                (key.trim() == name).then(|| value.trim())
+            })
+        };
+        let populated = field("Size").is_some_and(|size| {
+            !size.is_empty() && !size.eq_ignore_ascii_case("No Module Installed")
+        });
+        let kind = field("Type").filter(|t| t.contains("DDR"));
+        let speed = field("Configured Memory Speed")
+            .or_else(|| field("Configured Clock Speed"))
+            .filter(|v| !v.eq_ignore_ascii_case("Unknown"))
+            .or_else(|| field("Speed"))
+            .and_then(|v| v.split_whitespace().next()?.parse::<u32>().ok())
+            .filter(|speed| *speed > 0);
+        if populated && let (Some(kind), Some(speed)) = (kind, speed) {
+            let channel = memory_channel_key(&[
+                field("Bank Locator").unwrap_or(""),
+                field("Locator").unwrap_or(""),
+            ]);
+            modules.push((kind.to_string(), speed, channel));
+        }
+    }
+    ram_config_from_modules(&modules)
+}
+
+/// Parse `Win32_PhysicalMemory` rows printed as
+/// `SMBIOSMemoryType|ConfiguredClockSpeed|BankLabel|DeviceLocator`.
+fn parse_win32_physical_memory(text: &str) -> Option<RamConfig> {
+    let modules: Vec<MemoryModule> = text
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.trim().split('|');
+            let kind = match parts.next()?.trim().parse::<u32>().ok()? {
+                24 => "DDR3",
+                26 => "DDR4",
+                29 => "LPDDR3",
+                30 => "LPDDR4",
+                34 => "DDR5",
+                35 => "LPDDR5",
+                _ => return None,
+            };
+            let speed = parts
+                .next()?
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|s| *s > 0)?;
+            let bank = parts.next().unwrap_or("");
+            let device = parts.next().unwrap_or("");
+            Some((kind.to_string(), speed, memory_channel_key(&[bank, device])))
+        })
+        .collect();
+    ram_config_from_modules(&modules)
+}
+
 /// Effective system RAM bandwidth in GB/s, measured once per process with a
 /// short multithreaded memcpy sweep (~100 ms total) and cached.
 ///
@@ -2740,6 +3869,119 @@ pub fn gpu_memory_bandwidth_gbps(name: &str) -> Option<f64> {
     None
 }
 
+/// One GPU detection path, run on its own thread by [`run_gpu_probes`].
+pub type GpuProbe = Box<dyn FnOnce() -> Vec<GpuInfo> + Send>;
+
+/// How long hardware detection waits for the slowest GPU probe. A probe still
+/// running after this (e.g. a hung driver tool) is treated as finding nothing.
+const GPU_PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// Run GPU probes in parallel and return their results in probe order.
+/// Probes that have not finished within `timeout` (or that panic) contribute
+/// an empty list; their threads are left to finish in the background.
+pub fn run_gpu_probes(probes: Vec<GpuProbe>, timeout: std::time::Duration) -> Vec<Vec<GpuInfo>> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let count = probes.len();
+    for (index, probe) in probes.into_iter().enumerate() {
+        let tx = tx.clone();
+        std::thread::spawn(move || {
+            let _ = tx.send((index, probe()));
+        });
+    }
+    drop(tx);
+
+    let deadline = std::time::Instant::now() + timeout;
+    let mut results: Vec<Vec<GpuInfo>> = (0..count).map(|_| Vec::new()).collect();
+    for _ in 0..count {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        match rx.recv_timeout(remaining) {
+            Ok((index, gpus)) => results[index] = gpus,
+            Err(_) => break,
+        }
+    }
+    results
+}
+
+/// Board power (TDP, watts) of common inference GPUs, matched in order as
+/// whole lowercase words of the GPU name, so "Tesla M40" is not an "m4".
+/// More specific names come before the names they start with ("4070 ti"
+/// before "4070", "m2 max" before "m2").
+/// Apple Silicon entries are whole-package power under GPU load.
+const GPU_TDP_WATTS: &[(&str, f64)] = &[
+    // NVIDIA GeForce
+    ("5090", 575.0),
+    ("5080", 360.0),
+    ("5070 ti", 300.0),
+    ("5070", 250.0),
+    ("4090", 450.0),
+    ("4080", 320.0),
+    ("4070 ti", 285.0),
+    ("4070 super", 220.0),
+    ("4070", 200.0),
+    ("4060 ti", 165.0),
+    ("4060", 115.0),
+    ("3090 ti", 450.0),
+    ("3090", 350.0),
+    ("3080 ti", 350.0),
+    ("3080", 320.0),
+    ("3070", 220.0),
+    ("3060", 170.0),
+    // NVIDIA data center / workstation
+    ("b200", 1000.0),
+    ("h200", 700.0),
+    ("h100 pcie", 350.0),
+    ("h100", 700.0),
+    ("a100", 400.0),
+    ("l40s", 350.0),
+    ("l40", 300.0),
+    ("l4", 72.0),
+    ("a10", 150.0),
+    ("t4", 70.0),
+    ("v100", 300.0),
+    ("rtx 6000 ada", 300.0),
+    ("a6000", 300.0),
+    // AMD
+    ("mi300x", 750.0),
+    ("mi250", 560.0),
+    ("7900 xtx", 355.0),
+    ("7900 xt", 315.0),
+    // Apple Silicon
+    ("m4 max", 120.0),
+    ("m4 pro", 60.0),
+    ("m4", 30.0),
+    ("m3 ultra", 210.0),
+    ("m3 max", 100.0),
+    ("m3 pro", 50.0),
+    ("m3", 25.0),
+    ("m2 ultra", 200.0),
+    ("m2 max", 90.0),
+    ("m2 pro", 45.0),
+    ("m2", 22.0),
+    ("m1 ultra", 180.0),
+    ("m1 max", 90.0),
+    ("m1 pro", 45.0),
+    ("m1", 20.0),
+];
+
+/// TDP in watts for a known GPU name, from [`GPU_TDP_WATTS`]. Used for the
+/// rough power-draw estimate; `None` for unrecognized GPUs.
+pub fn gpu_tdp_watts(name: &str) -> Option<f64> {
+    let lower = name.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    GPU_TDP_WATTS
+        .iter()
+        .find(|(pattern, _)| {
+            let pattern: Vec<&str> = pattern.split(' ').collect();
+            words
+                .windows(pattern.len())
+                .any(|w| w == pattern.as_slice())
+        })
+        .map(|&(_, watts)| watts)
+}
+
 /// Returns the NVIDIA compute capability (major, minor) for a known GPU name.
 /// Used to determine compatibility with quantization formats that require
 /// specific hardware features (e.g. AWQ requires Turing+ / cc >= 7.5).
@@ -2861,6 +4103,192 @@ fn is_nvidia_unified_memory_gpu(name: &str) -> bool {
     false
 }
 
+/// Memory of a MIG profile such as `3g.40gb` or `1g.10gb+me`, in GB.
+fn mig_profile_memory_gb(profile: &str) -> Option<f64> {
+    let (_, memory) = profile.split_once('.')?;
+    let memory = memory.split('+').next()?.to_ascii_lowercase();
+    memory
+        .strip_suffix("gb")?
+        .parse::<f64>()
+        .ok()
+        .filter(|gb| *gb > 0.0)
+}
+
+/// Embedded PCI device ID → VRAM table for cards whose VRAM can't be read
+/// from the driver. IDs shared by SKUs with different memory sizes (e.g.
+/// 1002:744c for the RX 7900 XTX/XT/GRE) are deliberately left out.
+const PCI_VRAM_DB_JSON: &str = include_str!("../data/pci_vram_db.json");
+
+#[derive(serde::Deserialize)]
+struct PciVramEntry {
+    id: String,
+    vram_gb: f64,
+}
+
+/// Lazily parsed PCI VRAM table, keyed by lowercase `vendor:device`.
+fn pci_vram_db() -> &'static std::collections::HashMap<String, f64> {
+    static DB: std::sync::OnceLock<std::collections::HashMap<String, f64>> =
+        std::sync::OnceLock::new();
+    DB.get_or_init(|| {
+        serde_json::from_str::<Vec<PciVramEntry>>(PCI_VRAM_DB_JSON)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|e| (e.id.to_lowercase(), e.vram_gb))
+            .collect()
+    })
+}
+
+/// VRAM in GB for a PCI ID such as `10de:2684`, from the embedded table.
+pub fn pci_vram_gb(pci_id: &str) -> Option<f64> {
+    pci_vram_db().get(&pci_id.trim().to_lowercase()).copied()
+}
+
+/// `vendor:device` for a PCI device directory in sysfs (e.g.
+/// `/sys/class/drm/card0/device`), from its `vendor`/`device` files.
+fn pci_id_from_sysfs(device_path: &std::path::Path) -> Option<String> {
+    let read = |file: &str| {
+        let raw = std::fs::read_to_string(device_path.join(file)).ok()?;
+        let hex = raw.trim().trim_start_matches("0x").to_lowercase();
+        (hex.len() == 4).then_some(hex)
+    };
+    Some(format!("{}:{}", read("vendor")?, read("device")?))
+}
+
+/// `vendor:device` from a Windows PnP device ID such as
+/// `PCI\VEN_10DE&DEV_2684&SUBSYS_16F010DE&REV_A1\4&...`.
+fn pci_id_from_pnp_device_id(pnp: &str) -> Option<String> {
+    let upper = pnp.to_uppercase();
+    let field = |key: &str| {
+        let start = upper.find(key)? + key.len();
+        upper.get(start..start + 4).map(str::to_lowercase)
+    };
+    Some(format!("{}:{}", field("VEN_")?, field("DEV_")?))
+}
+
+/// VRAM of the display devices from `vendor` (e.g. `10de`) on the Linux PCI
+/// bus, when every such device with a table entry agrees on its size — used
+/// where a tool named the GPU but reported no memory. `None` on other
+/// platforms, with no known device, or with differently-sized cards.
+fn sysfs_pci_vram_gb(vendor: &str) -> Option<f64> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let mut sizes = std::fs::read_dir("/sys/bus/pci/devices")
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            // PCI class 0x03xxxx = display controller
+            std::fs::read_to_string(path.join("class"))
+                .is_ok_and(|class| class.trim().starts_with("0x03"))
+        })
+        .filter_map(|path| pci_id_from_sysfs(&path))
+        .filter(|id| id.starts_with(vendor))
+        .filter_map(|id| pci_vram_gb(&id));
+    let first = sizes.next()?;
+    sizes.all(|gb| gb == first).then_some(first)
+}
+
+/// VRAM fallback for a GPU the driver couldn't size: the PCI table first,
+/// then the name heuristics. `None` when neither knows the card.
+fn fallback_vram_gb(name: &str, pci_vram: Option<f64>) -> Option<f64> {
+    pci_vram.or_else(|| {
+        let est = estimate_vram_from_name(name);
+        (est > 0.0).then_some(est)
+    })
+}
+
+/// Oldest NVIDIA driver branch that ships CUDA 12, which current PyTorch and
+/// llama.cpp CUDA builds require.
+pub const MIN_CUDA_DRIVER_MAJOR: u32 = 525;
+
+/// Major component of a dotted driver version (`"550.54.14"` → 550).
+pub fn driver_major_version(version: &str) -> Option<u32> {
+    version.trim().split('.').next()?.parse().ok()
+}
+
+/// Leading `(major, minor)` of a driver version: `"550.54.14"` → (550, 54),
+/// `"6.8.0-45-generic"` → (6, 8). A missing minor reads as 0.
+pub fn parse_driver_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |minor| {
+        let digits: String = minor.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse().ok()
+    })?;
+    Some((major, minor))
+}
+
+/// Stamp `version()` onto every GPU a probe found. The version is only looked
+/// up when there is something to attach it to.
+fn with_driver_version(mut gpus: Vec<GpuInfo>, version: fn() -> Option<String>) -> Vec<GpuInfo> {
+    if gpus.iter().all(|gpu| gpu.driver_version.is_some()) {
+        return gpus;
+    }
+    let version = version();
+    for gpu in gpus.iter_mut().filter(|gpu| gpu.driver_version.is_none()) {
+        gpu.driver_version = version.clone();
+    }
+    gpus
+}
+
+/// NVIDIA driver version from nvidia-smi, falling back to the kernel module's
+/// `/proc/driver/nvidia/version` banner when nvidia-smi isn't on the PATH.
+fn nvidia_driver_version() -> Option<String> {
+    crate::subprocess::output(
+        std::process::Command::new(nvidia_smi_program())
+            .args(["--query-gpu=driver_version", "--format=csv,noheader"]),
+    )
+    .ok()
+    .filter(|o| o.status.success())
+    .and_then(|o| parse_nvidia_smi_driver_version(&String::from_utf8_lossy(&o.stdout)))
+    .or_else(|| {
+        let banner = std::fs::read_to_string("/proc/driver/nvidia/version").ok()?;
+        parse_nvrm_driver_version(&banner)
+    })
+}
+
+/// First non-empty line of `nvidia-smi --query-gpu=driver_version` (one line
+/// per GPU, all on the same driver).
+fn parse_nvidia_smi_driver_version(text: &str) -> Option<String> {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+/// Version from the `NVRM version: NVIDIA UNIX x86_64 Kernel Module  550.54.14  ...`
+/// banner in `/proc/driver/nvidia/version`.
+fn parse_nvrm_driver_version(banner: &str) -> Option<String> {
+    let line = banner.lines().find(|l| l.starts_with("NVRM version:"))?;
+    line.split_whitespace()
+        .find(|word| word.contains('.') && driver_major_version(word).is_some())
+        .map(str::to_string)
+}
+
+/// amdgpu DKMS module version on Linux, for GPUs found without rocm-smi.
+/// Only the ROCm packaged driver sets it; the in-tree driver reports no
+/// version of its own, and the kernel release says nothing about which
+/// amdgpu features it carries, so that case stays unknown.
+fn amdgpu_dkms_version() -> Option<String> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let text = std::fs::read_to_string("/sys/module/amdgpu/version").ok()?;
+    let text = text.trim();
+    parse_driver_version(text).map(|_| text.to_string())
+}
+
+/// Version from the `Driver version: 6.8.5` line of
+/// `rocm-smi --showdriverversion`.
+fn parse_rocm_smi_driver_version(text: &str) -> Option<String> {
+    text.lines()
+        .find_map(|line| line.trim().strip_prefix("Driver version:"))
+        .map(str::trim)
+        .filter(|version| parse_driver_version(version).is_some())
+        .map(str::to_string)
+}
+
 /// Fallback VRAM estimation from GPU model name.
 /// Used when nvidia-smi or other tools report 0 VRAM.
 fn estimate_vram_from_name(name: &str) -> f64 {
@@ -3190,6 +4618,257 @@ mod tests {
         assert!(vram > 100.0, "GB10 VRAM should be ~128GB, got {vram}");
     }
 
+    #[test]
+    fn test_mig_slices_replace_partitioned_card() {
+        let gpus = SystemSpecs::parse_nvidia_smi_list(
+            "81920, NVIDIA A100-SXM4-80GB\n81920, NVIDIA A100-SXM4-80GB\n",
+        );
+        let listing = "\
+GPU 0: NVIDIA A100-SXM4-80GB (UUID: GPU-5d5ba0d6-d33d-2b2c-524d-9e3d8d2b8a77)
+  MIG 3g.40gb     Device  0: (UUID: MIG-1d9a8e6f-0b5e-5b7a-9f0e-2c0f5e0d1a11)
+  MIG 2g.20gb     Device  1: (UUID: MIG-2e8b7f5e-1c4d-4a69-8e1d-3b1e4d1c2b22)
+  MIG 1g.10gb+me  Device  2: (UUID: MIG-3f7c6e4d-2b3c-3958-7d2c-4a2d3c2b3c33)
+GPU 1: NVIDIA A100-SXM4-80GB (UUID: GPU-6e6cb1e7-e44e-3c3d-635e-af4e9e3c9b88)
+";
+        let gpus = SystemSpecs::apply_nvidia_mig_slices(gpus, listing);
+
+        // GPU 1 is not partitioned and stays a full 80 GB card.
+        let full = gpus
+            .iter()
+            .find(|g| g.name == "NVIDIA A100-SXM4-80GB")
+            .expect("unpartitioned card should remain");
+        assert_eq!(full.count, 1);
+        assert!(full.vram_gb.unwrap() > 79.0);
+
+        let slice = |profile: &str| {
+            gpus.iter()
+                .find(|g| g.name == format!("NVIDIA A100-SXM4-80GB MIG {profile}"))
+                .unwrap_or_else(|| panic!("missing MIG {profile} slice"))
+        };
+        assert_eq!(slice("3g.40gb").vram_gb, Some(40.0));
+        assert_eq!(slice("2g.20gb").vram_gb, Some(20.0));
+        assert_eq!(slice("1g.10gb+me").vram_gb, Some(10.0));
+        assert_eq!(gpus.len(), 4);
+        assert!(gpus.iter().all(|g| g.backend == super::GpuBackend::Cuda));
+    }
+
+    #[test]
+    fn test_mig_listing_without_slices_is_a_noop() {
+        let gpus = SystemSpecs::parse_nvidia_smi_list("24564, NVIDIA GeForce RTX 4090\n");
+        let listing = "GPU 0: NVIDIA GeForce RTX 4090 (UUID: GPU-0a1b2c3d)\n";
+        let gpus = SystemSpecs::apply_nvidia_mig_slices(gpus, listing);
+        assert_eq!(gpus.len(), 1);
+        assert_eq!(gpus[0].name, "NVIDIA GeForce RTX 4090");
+    }
+
+    #[test]
+    fn test_driver_version_parsing() {
+        assert_eq!(
+            super::parse_nvidia_smi_driver_version("\n550.54.14\n550.54.14\n").as_deref(),
+            Some("550.54.14")
+        );
+        let banner = "NVRM version: NVIDIA UNIX x86_64 Kernel Module  470.239.06  Wed Feb 28 2024\n\
+                      GCC version:  gcc version 11.4.0\n";
+        assert_eq!(
+            super::parse_nvrm_driver_version(banner).as_deref(),
+            Some("470.239.06")
+        );
+        assert_eq!(super::driver_major_version("535.183.01"), Some(535));
+        assert_eq!(super::driver_major_version("garbage"), None);
+
+        let rocm_smi = "\n============ ROCm System Management Interface ============\n\
+                        ========== Version of System Component ==========\n\
+                        Driver version: 6.8.5\n\
+                        ==================================================\n";
+        assert_eq!(
+            super::parse_rocm_smi_driver_version(rocm_smi).as_deref(),
+            Some("6.8.5")
+        );
+        assert_eq!(
+            super::parse_rocm_smi_driver_version("Driver version: N/A"),
+            None
+        );
+        assert_eq!(super::parse_driver_version("550.54.14"), Some((550, 54)));
+        assert_eq!(
+            super::parse_driver_version("6.8.0-45-generic"),
+            Some((6, 8))
+        );
+        assert_eq!(super::parse_driver_version("6.10.5"), Some((6, 10)));
+        assert_eq!(super::parse_driver_version("535"), Some((535, 0)));
+        assert_eq!(super::parse_driver_version("garbage"), None);
+    }
+
+    #[test]
+    fn test_cuda_driver_outdated() {
+        let mut gpus = SystemSpecs::parse_nvidia_smi_list("24564, NVIDIA GeForce RTX 3090\n");
+        gpus[0].driver_version = Some("470.239.06".to_string());
+        let mut specs = SystemSpecs {
+            total_ram_gb: 64.0,
+            available_ram_gb: 48.0,
+            total_cpu_cores: 16,
+            cpu_name: "Test CPU".to_string(),
+            has_gpu: true,
+            gpu_vram_gb: Some(24.0),
+            total_gpu_vram_gb: Some(24.0),
+            gpu_available_gb: None,
+            gpu_name: Some("NVIDIA GeForce RTX 3090".to_string()),
+            gpu_count: 1,
+            unified_memory: false,
+            backend: super::GpuBackend::Cuda,
+            gpus,
+            cluster_mode: false,
+            cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
+        };
+        assert_eq!(specs.cuda_driver_version(), Some("470.239.06"));
+        assert!(specs.cuda_driver_outdated());
+
+        specs.gpus[0].driver_version = Some("550.54.14".to_string());
+        assert!(!specs.cuda_driver_outdated());
+        specs.gpus[0].driver_version = None;
+        assert!(!specs.cuda_driver_outdated());
+    }
+
+    fn probe_gpu(name: &str) -> super::GpuInfo {
+        super::GpuInfo {
+            name: name.to_string(),
+            vram_gb: Some(8.0),
+            backend: super::GpuBackend::Vulkan,
+            count: 1,
+            unified_memory: false,
+            driver_version: None,
+        }
+    }
+
+    #[test]
+    fn test_gpu_probes_run_in_parallel_and_keep_order() {
+        use std::sync::{Arc, Condvar, Mutex};
+        use std::time::Duration;
+
+        // Each probe checks in and waits for all the others. Run one at a
+        // time, the first probe's wait would time out and it would report
+        // nothing.
+        let names = ["a", "b", "c", "d"];
+        let arrived = Arc::new((Mutex::new(0), Condvar::new()));
+        let probes: Vec<super::GpuProbe> = names
+            .into_iter()
+            .map(|name| {
+                let arrived = Arc::clone(&arrived);
+                Box::new(move || {
+                    let (count, all_here) = &*arrived;
+                    let mut count = count.lock().unwrap();
+                    *count += 1;
+                    all_here.notify_all();
+                    let (count, wait) = all_here
+                        .wait_timeout_while(count, Duration::from_secs(5), |n| *n < names.len())
+                        .unwrap();
+                    drop(count);
+                    if wait.timed_out() {
+                        Vec::new()
+                    } else {
+                        vec![probe_gpu(name)]
+                    }
+                }) as super::GpuProbe
+            })
+            .collect();
+        let results = super::run_gpu_probes(probes, Duration::from_secs(10));
+
+        let found: Vec<&str> = results
+            .iter()
+            .map(|r| r.first().map_or("", |g| g.name.as_str()))
+            .collect();
+        assert_eq!(found, names);
+    }
+
+    #[test]
+    fn test_gpu_probe_timeout_yields_empty_result() {
+        use std::time::Duration;
+
+        let probes: Vec<super::GpuProbe> = vec![
+            Box::new(|| vec![probe_gpu("fast")]),
+            Box::new(|| {
+                std::thread::sleep(Duration::from_secs(5));
+                vec![probe_gpu("hung")]
+            }),
+        ];
+        let results = super::run_gpu_probes(probes, Duration::from_millis(300));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0][0].name, "fast");
+        assert!(results[1].is_empty());
+    }
+
+    #[test]
+    fn test_gpu_tdp_prefers_specific_names() {
+        assert_eq!(super::gpu_tdp_watts("NVIDIA GeForce RTX 4090"), Some(450.0));
+        assert_eq!(super::gpu_tdp_watts("NVIDIA GeForce RTX 3090"), Some(350.0));
+        assert_eq!(
+            super::gpu_tdp_watts("NVIDIA GeForce RTX 4070 Ti SUPER"),
+            Some(285.0)
+        );
+        assert_eq!(super::gpu_tdp_watts("NVIDIA L40S"), Some(350.0));
+        assert_eq!(super::gpu_tdp_watts("NVIDIA L4"), Some(72.0));
+        assert_eq!(super::gpu_tdp_watts("NVIDIA H100 PCIe"), Some(350.0));
+        assert_eq!(super::gpu_tdp_watts("Apple M4 Max"), Some(120.0));
+        assert_eq!(super::gpu_tdp_watts("Apple M2"), Some(22.0));
+        assert_eq!(super::gpu_tdp_watts("Apple M1 Pro"), Some(45.0));
+        assert_eq!(super::gpu_tdp_watts("NVIDIA A100-SXM4-80GB"), Some(400.0));
+        assert_eq!(super::gpu_tdp_watts("Some Unknown GPU"), None);
+        // Whole words only: no "m4" in an M40, no "a10" in an A1000.
+        assert_eq!(super::gpu_tdp_watts("NVIDIA Tesla M40"), None);
+        assert_eq!(super::gpu_tdp_watts("NVIDIA RTX A1000"), None);
+    }
+
+    #[test]
+    fn test_pci_vram_db_lookup() {
+        assert_eq!(super::pci_vram_gb("10de:2684"), Some(24.0));
+        assert_eq!(super::pci_vram_gb("10DE:2684"), Some(24.0));
+        assert_eq!(super::pci_vram_gb("1002:74a1"), Some(192.0));
+        // Shared by the 7900 XTX / XT / GRE, which differ in VRAM.
+        assert_eq!(super::pci_vram_gb("1002:744c"), None);
+        assert_eq!(super::pci_vram_gb("ffff:0000"), None);
+    }
+
+    #[test]
+    fn test_pci_id_from_pnp_device_id() {
+        assert_eq!(
+            super::pci_id_from_pnp_device_id(
+                "PCI\\VEN_10DE&DEV_2684&SUBSYS_16F010DE&REV_A1\\4&2D78AB8F&0&0008"
+            )
+            .as_deref(),
+            Some("10de:2684")
+        );
+        assert_eq!(
+            super::pci_id_from_pnp_device_id("ROOT\\DISPLAY\\0000"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_windows_gpu_list_prefers_pci_table_over_capped_adapter_ram() {
+        // AdapterRAM saturates at 4 GB; the name is unknown to the heuristics,
+        // so only the PCI ID can recover the real size.
+        let text = "NVIDIA Graphics Device|4293918720|PCI\\VEN_10DE&DEV_2204&SUBSYS_00000000\n";
+        let gpus = SystemSpecs::parse_windows_gpu_list(text);
+        assert_eq!(gpus.len(), 1);
+        assert_eq!(gpus[0].vram_gb, Some(24.0));
+
+        // Without a PCI ID the old name-based path still applies.
+        let text = "NVIDIA GeForce RTX 3090|4293918720\n";
+        let gpus = SystemSpecs::parse_windows_gpu_list(text);
+        assert_eq!(gpus[0].vram_gb, Some(24.0));
+    }
+
+    #[test]
+    fn test_fallback_vram_prefers_pci_table() {
+        assert_eq!(
+            super::fallback_vram_gb("NVIDIA GPU", Some(48.0)),
+            Some(48.0)
+        );
+        assert_eq!(super::fallback_vram_gb("NVIDIA GB10", None), Some(128.0));
+        assert_eq!(super::fallback_vram_gb("Mystery GPU", None), None);
+    }
+
     #[test]
     fn test_estimate_vram_gb10() {
         assert_eq!(super::estimate_vram_from_name("NVIDIA GB10"), 128.0);
@@ -3532,9 +5211,334 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
             gpus: vec![],
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
         }
     }
 
+    fn make_specs_with_amd_gpu() -> SystemSpecs {
+        let mut specs = make_specs_with_gpu();
+        specs.backend = super::GpuBackend::Vulkan;
+        specs.gpu_name = Some("AMD Radeon RX 7900 XTX".to_string());
+        specs.gpus[0].name = "AMD Radeon RX 7900 XTX".to_string();
+        specs.gpus[0].backend = super::GpuBackend::Vulkan;
+        specs
+    }
+
+    #[test]
+    fn test_zluda_note_with_env_var_and_amd_gpu() {
+        let zluda = super::detect_zluda(
+            |var| (var == "ZLUDA_PATH").then(|| "/home/user/zluda".to_string()),
+            |_| false,
+        );
+        assert!(zluda);
+        let note = make_specs_with_amd_gpu().zluda_note(zluda);
+        assert!(note.is_some_and(|n| n.contains("ZLUDA")));
+    }
+
+    #[test]
+    fn test_wsl_detection_from_proc_version_and_wslconfig() {
+        let proc_version = "Linux version 5.15.153.1-microsoft-standard-WSL2 (root@65c757a075e2) (gcc (GCC) 11.2.0) #1 SMP";
+        let wslconfig = "[wsl2]\n# leave room for Windows\nmemory=12GB\nprocessors=8\n";
+        let wsl = super::detect_wsl(
+            |var| (var == "USERPROFILE").then(|| "C:\\Users\\alex".to_string()),
+            |path| match path {
+                "/proc/version" => Some(proc_version.to_string()),
+                "/mnt/c/Users/alex/.wslconfig" => Some(wslconfig.to_string()),
+                _ => None,
+            },
+            |path| path == "/dev/dxg",
+        )
+        .expect("microsoft kernel banner means WSL");
+        assert_eq!(wsl.memory_limit_gb, Some(12.0));
+        assert!(wsl.gpu_passthrough);
+        // meminfo can report a little more than the cap; the cap wins.
+        assert_eq!(wsl.clamp_ram(15.6, 13.0), (12.0, 12.0));
+        assert_eq!(wsl.clamp_ram(11.7, 9.0), (11.7, 9.0));
+
+        // A stock Linux kernel is not WSL.
+        let native = super::detect_wsl(
+            |_| None,
+            |path| (path == "/proc/version").then(|| "Linux version 6.8.0-45-generic".to_string()),
+            |_| true,
+        );
+        assert!(native.is_none());
+
+        // No .wslconfig: no cap, RAM passes through untouched.
+        let uncapped = super::detect_wsl(
+            |var| (var == "WSL_DISTRO_NAME").then(|| "Ubuntu".to_string()),
+            |_| None,
+            |_| false,
+        )
+        .unwrap();
+        assert_eq!(uncapped.memory_limit_gb, None);
+        assert!(!uncapped.gpu_passthrough);
+        assert_eq!(uncapped.clamp_ram(31.2, 20.0), (31.2, 20.0));
+    }
+
+    #[test]
+    fn test_parse_wslconfig_memory_units_and_sections() {
+        let parse = super::parse_wslconfig_memory_gb;
+        assert_eq!(parse("[wsl2]\nmemory=8GB"), Some(8.0));
+        assert_eq!(parse("[WSL2]\nMemory = 4096MB ; half"), Some(4.0));
+        assert_eq!(parse("[wsl2]\nmemory=1TB"), Some(1024.0));
+        // memory= outside [wsl2] is ignored.
+        assert_eq!(parse("[experimental]\nmemory=8GB"), None);
+        assert_eq!(parse("[wsl2]\nswap=8GB"), None);
+        assert_eq!(parse("[wsl2]\nmemory=lots"), None);
+    }
+
+    #[test]
+    fn test_apple_gpu_cores_parse_and_full_counts() {
+        let text = "Graphics/Displays:\n\n    Apple M3 Max:\n\n      Chipset Model: Apple M3 Max\n      Type: GPU\n      Bus: Built-In\n      Total Number of Cores: 30\n      Vendor: Apple (0x106b)\n";
+        assert_eq!(super::parse_apple_gpu_cores(text), Some(30));
+        assert_eq!(super::parse_apple_gpu_cores("Chipset Model: Radeon"), None);
+
+        assert_eq!(super::apple_full_gpu_cores("Apple M3 Max"), Some(40));
+        assert_eq!(super::apple_full_gpu_cores("Apple M1 Pro"), Some(16));
+        assert_eq!(super::apple_full_gpu_cores("Apple M2 Ultra"), Some(76));
+        assert_eq!(super::apple_full_gpu_cores("Apple M4"), Some(10));
+        assert_eq!(super::apple_full_gpu_cores("RTX 4090"), None);
+    }
+
+    #[test]
+    fn test_meteor_lake_npu_detected_as_openvino_backend() {
+        use super::{GpuBackend, GpuInfo};
+        let ids = vec!["8086:a7a0".to_string(), "8086:7D1D".to_string()];
+        let npus = super::intel_npus_from_pci_ids(&ids, 32.0);
+        assert_eq!(npus.len(), 1);
+        assert_eq!(npus[0].name, "Intel AI Boost NPU (Meteor Lake)");
+        assert_eq!(npus[0].backend, GpuBackend::OpenVino);
+        assert!(npus[0].unified_memory);
+        assert_eq!(npus[0].vram_gb, Some(32.0));
+        assert!(super::intel_npus_from_pci_ids(&["10de:2684".to_string()], 32.0).is_empty());
+
+        // Alone, the NPU becomes the primary accelerator...
+        let gpus = super::with_npu_fallback(Vec::new(), npus.clone());
+        assert_eq!(gpus[0].backend, GpuBackend::OpenVino);
+        // ...but an integrated Arc GPU on the same chip takes precedence.
+        let arc = GpuInfo {
+            name: "Intel Arc Graphics".to_string(),
+            vram_gb: Some(32.0),
+            backend: GpuBackend::Sycl,
+            count: 1,
+            unified_memory: true,
+            driver_version: None,
+        };
+        let gpus = super::with_npu_fallback(vec![arc], npus);
+        assert_eq!(gpus.len(), 1);
+        assert_eq!(gpus[0].backend, GpuBackend::Sycl);
+
+        assert_eq!(super::parse_backend("npu"), Some(GpuBackend::OpenVino));
+    }
+
+    #[test]
+    fn test_ram_config_parsed_from_smbios() {
+        use super::{RamConfig, parse_dmidecode_memory, parse_win32_physical_memory};
+
+        let dmidecode = "\
+# dmidecode 3.5
+Handle 0x0040, DMI type 17, 92 bytes
+Memory Device
+\tSize: 32 GB
+\tLocator: DIMM 0
+\tBank Locator: P0 CHANNEL A
+\tType: DDR5
+\tSpeed: 6000 MT/s
+\tConfigured Memory Speed: 5600 MT/s
+
+Handle 0x0041, DMI type 17, 92 bytes
+Memory Device
+\tSize: No Module Installed
+\tLocator: DIMM 1
+\tBank Locator: P0 CHANNEL A
+\tType: Unknown
+\tSpeed: Unknown
+
+Handle 0x0042, DMI type 17, 92 bytes
+Memory Device
+\tSize: 32 GB
+\tLocator: DIMM 0
+\tBank Locator: P0 CHANNEL B
+\tType: DDR5
+\tSpeed: 6000 MT/s
+\tConfigured Memory Speed: 5600 MT/s
+";
+        let ram = parse_dmidecode_memory(dmidecode).unwrap();
+        assert_eq!(
+            ram,
+            RamConfig {
+                kind: "DDR5".to_string(),
+                speed_mts: 5600,
+                channels: 2,
+            }
+        );
+        assert!((ram.peak_bandwidth_gbps() - 89.6).abs() < 1e-9);
+        assert_eq!(ram.label(), "DDR5-5600 x2 channels");
+
+        // Two DIMMs sharing channel A on a board with four slots.
+        let windows = "26|3200|BANK 0|ChannelA-DIMM0\n26|3200|BANK 1|ChannelA-DIMM1\n";
+        let ram = parse_win32_physical_memory(windows).unwrap();
+        assert_eq!((ram.kind.as_str(), ram.channels), ("DDR4", 1));
+
+        // Locators without channel names count one channel per module.
+        let windows = "34|4800|P0|DIMM_A1\n34|4800|P0|DIMM_B1\n34|4800|P1|DIMM_A1\n";
+        assert_eq!(parse_win32_physical_memory(windows).unwrap().channels, 3);
+
+        assert_eq!(
+            parse_dmidecode_memory("# No SMBIOS nor DMI entry point found"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rosetta_flag_is_read_and_surfaced() {
+        assert!(super::parse_proc_translated("1\n"));
+        assert!(!super::parse_proc_translated("0\n"));
+        // Intel Macs don't have the key at all.
+        assert!(!super::parse_proc_translated(""));
+
+        assert!(
+            super::rosetta_warning(true)
+                .unwrap()
+                .contains("arm64 build")
+        );
+        assert_eq!(super::rosetta_warning(false), None);
+
+        let text = "Graphics/Displays:\n\n    Apple M2 Max:\n\n      Chipset Model: Apple M2 Max\n      Type: GPU\n";
+        assert_eq!(
+            super::parse_apple_chipset_name(text).as_deref(),
+            Some("Apple M2 Max")
+        );
+        assert_eq!(
+            super::parse_apple_chipset_name("      Chipset Model: AMD Radeon Pro 5500M\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_zluda_note_absent_without_zluda_or_amd() {
+        assert!(!super::detect_zluda(|_| None, |_| false));
+        assert!(!super::detect_zluda(|_| Some(" ".to_string()), |_| false));
+        assert!(super::detect_zluda(|_| None, |path| path == "/opt/zluda"));
+        assert!(make_specs_with_amd_gpu().zluda_note(false).is_none());
+        assert!(make_specs_with_gpu().zluda_note(true).is_none());
+    }
+
+    #[test]
+    fn test_interactive_desktop_detection() {
+        assert!(super::detect_interactive_desktop("macos", |_| false));
+        assert!(super::detect_interactive_desktop("windows", |_| false));
+        assert!(!super::detect_interactive_desktop("linux", |_| false));
+        assert!(super::detect_interactive_desktop("linux", |var| {
+            var == "WAYLAND_DISPLAY"
+        }));
+    }
+
+    #[test]
+    fn test_amd_generation_from_gfx_target_and_name() {
+        use super::{AmdGeneration, amd_generation};
+        assert_eq!(
+            amd_generation("AMD Radeon Graphics (gfx1100)"),
+            Some(AmdGeneration::Rdna3)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon Graphics (gfx1151)"),
+            Some(AmdGeneration::Rdna3)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon Graphics (gfx1201)"),
+            Some(AmdGeneration::Rdna4)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon Graphics (gfx1030)"),
+            Some(AmdGeneration::Rdna2)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon Graphics (gfx906)"),
+            Some(AmdGeneration::Gcn)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon Graphics (gfx90a)"),
+            Some(AmdGeneration::Cdna)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon RX 7900 XTX"),
+            Some(AmdGeneration::Rdna3)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon PRO W7900"),
+            Some(AmdGeneration::Rdna3)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon RX 9070 XT"),
+            Some(AmdGeneration::Rdna4)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon RX 6800 XT"),
+            Some(AmdGeneration::Rdna2)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon RX 5700 XT"),
+            Some(AmdGeneration::Rdna1)
+        );
+        assert_eq!(
+            amd_generation("Radeon RX 580 Series"),
+            Some(AmdGeneration::Gcn)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon 8060S"),
+            Some(AmdGeneration::Rdna3)
+        );
+        assert_eq!(
+            amd_generation("AMD Radeon 680M"),
+            Some(AmdGeneration::Rdna2)
+        );
+        assert_eq!(
+            amd_generation("AMD Instinct MI300X"),
+            Some(AmdGeneration::Cdna)
+        );
+        assert_eq!(amd_generation("AMD Radeon Graphics"), None);
+        assert_eq!(amd_generation("NVIDIA GeForce RTX 4090"), None);
+    }
+
+    #[test]
+    fn test_amd_backend_advice_rdna3_recommends_rocm() {
+        let specs = make_specs_with_amd_gpu();
+        let advice = specs.amd_backend_advice_for("linux").unwrap();
+        assert_eq!(advice.backend, super::GpuBackend::Rocm);
+        assert_eq!(advice.tps_factor(), 1.0);
+        assert!(advice.note().starts_with("Use ROCm (RDNA3)"));
+        let advice = specs.amd_backend_advice_for("windows").unwrap();
+        assert_eq!(advice.backend, super::GpuBackend::Rocm);
+        // No AMD GPU, no advice.
+        assert!(
+            make_specs_with_gpu()
+                .amd_backend_advice_for("linux")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_amd_backend_advice_older_card_recommends_vulkan() {
+        let mut specs = make_specs_with_amd_gpu();
+        specs.gpu_name = Some("AMD Radeon RX 580".to_string());
+        specs.gpus[0].name = "AMD Radeon RX 580".to_string();
+        for os in ["linux", "windows"] {
+            let advice = specs.amd_backend_advice_for(os).unwrap();
+            assert_eq!(advice.backend, super::GpuBackend::Vulkan);
+            assert!(advice.tps_factor() < 1.0);
+            assert!(advice.note().starts_with("Use Vulkan (GCN)"));
+        }
+
+        // RDNA2 is ROCm-supported on Linux only.
+        specs.gpus[0].name = "AMD Radeon RX 6800 XT".to_string();
+        let linux = specs.amd_backend_advice_for("linux").unwrap();
+        assert_eq!(linux.backend, super::GpuBackend::Rocm);
+        let windows = specs.amd_backend_advice_for("windows").unwrap();
+        assert_eq!(windows.backend, super::GpuBackend::Vulkan);
+    }
+
     fn make_specs_with_gpu() -> SystemSpecs {
         SystemSpecs {
             total_ram_gb: 32.0,
@@ -3555,9 +5559,12 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
                 backend: super::GpuBackend::Cuda,
                 count: 1,
                 unified_memory: false,
+                driver_version: None,
             }],
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
         }
     }
 
@@ -3606,6 +5613,48 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
         assert_eq!(specs.gpu_available_gb, None);
     }
 
+    #[test]
+    fn test_backend_override_cpu_drops_gpus() {
+        let specs = make_specs_with_gpu().with_backend_override(super::GpuBackend::CpuX86);
+        assert!(!specs.has_gpu);
+        assert_eq!(specs.backend, super::GpuBackend::CpuX86);
+        assert_eq!(specs.gpu_vram_gb, None);
+        assert_eq!(specs.total_gpu_vram_gb, None);
+        assert_eq!(specs.gpu_count, 0);
+        assert!(specs.gpus.is_empty());
+    }
+
+    #[test]
+    fn test_backend_override_gpu_relabels_detected_gpus() {
+        let specs = make_specs_with_gpu().with_backend_override(super::GpuBackend::Vulkan);
+        assert!(specs.has_gpu);
+        assert_eq!(specs.backend, super::GpuBackend::Vulkan);
+        assert!(
+            specs
+                .gpus
+                .iter()
+                .all(|g| g.backend == super::GpuBackend::Vulkan)
+        );
+
+        // Without a GPU there is nothing to run a GPU backend on.
+        let specs = make_specs_no_gpu().with_backend_override(super::GpuBackend::Cuda);
+        assert!(!specs.has_gpu);
+        assert_ne!(specs.backend, super::GpuBackend::Cuda);
+    }
+
+    #[test]
+    fn test_parse_backend() {
+        use super::parse_backend;
+        assert_eq!(parse_backend("CUDA"), Some(super::GpuBackend::Cuda));
+        assert_eq!(parse_backend("rocm"), Some(super::GpuBackend::Rocm));
+        assert_eq!(parse_backend("cpu-arm"), Some(super::GpuBackend::CpuArm));
+        assert!(matches!(
+            parse_backend("cpu"),
+            Some(super::GpuBackend::CpuArm | super::GpuBackend::CpuX86)
+        ));
+        assert_eq!(parse_backend("tpu"), None);
+    }
+
     // ── format_unified_memory_line ───────────────────────────────────
 
     #[test]
@@ -3634,6 +5683,42 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
         assert_eq!(line, "Apple M3 (unified memory, 16.00 GB shared, Metal)");
     }
 
+    // ── amd_apu_shared_budget_gb ─────────────────────────────────────
+
+    #[test]
+    fn test_amd_apu_budget_uses_gtt_not_carveout() {
+        // Ryzen 7 7840U: 512 MB carve-out, 15.3 GB GTT on 32 GB of RAM.
+        let budget = super::amd_apu_shared_budget_gb(
+            "AMD Radeon 780M Graphics",
+            Some(0.5),
+            Some(15.3),
+            32.0,
+        );
+        assert_eq!(budget, Some(15.3));
+        // GTT raised past physical RAM via amdgpu.gttsize is capped.
+        let budget =
+            super::amd_apu_shared_budget_gb("Radeon Graphics", Some(0.5), Some(48.0), 32.0);
+        assert_eq!(budget, Some(32.0));
+    }
+
+    #[test]
+    fn test_amd_apu_budget_defaults_to_half_ram_without_gtt() {
+        let budget = super::amd_apu_shared_budget_gb("Radeon Graphics", None, None, 64.0);
+        assert_eq!(budget, Some(32.0));
+    }
+
+    #[test]
+    fn test_amd_apu_budget_ignores_discrete_cards() {
+        assert_eq!(
+            super::amd_apu_shared_budget_gb("Radeon RX 7900 XTX", Some(24.0), Some(31.0), 64.0),
+            None
+        );
+        assert_eq!(
+            super::amd_apu_shared_budget_gb("Radeon RX 7600", None, Some(31.0), 64.0),
+            None
+        );
+    }
+
     // ── is_amd_unified_memory_apu ────────────────────────────────────
 
     #[test]
@@ -3935,6 +6020,7 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
                 backend: GpuBackend::Vulkan,
                 count: 1,
                 unified_memory: false,
+                driver_version: None,
             },
             super::GpuInfo {
                 name: "NVIDIA GeForce RTX 4090".to_string(),
@@ -3942,6 +6028,7 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
                 backend: GpuBackend::Cuda,
                 count: 1,
                 unified_memory: false,
+                driver_version: None,
             },
         ];
         let result = SystemSpecs::prefer_discrete_gpus(gpus);
@@ -3958,6 +6045,7 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
             backend: GpuBackend::Vulkan,
             count: 1,
             unified_memory: false,
+            driver_version: None,
         }];
         let result = SystemSpecs::prefer_discrete_gpus(gpus);
         assert_eq!(result.len(), 1);
@@ -4053,9 +6141,12 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
                 backend: super::GpuBackend::Cuda,
                 count: 1,
                 unified_memory: false,
+                driver_version: None,
             }],
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
         };
 
         let overridden = specs.with_ram_override(128.0);
@@ -4087,9 +6178,12 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
                 backend: super::GpuBackend::Metal,
                 count: 1,
                 unified_memory: true,
+                driver_version: None,
             }],
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
         };
 
         let overridden = specs.with_ram_override(96.0);
@@ -4118,6 +6212,8 @@ GPU id = 1 (NVIDIA GeForce RTX 4090)
             gpus: vec![],
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
         };
 
         let overridden = specs.with_cpu_core_override(64);
@@ -4571,6 +6667,7 @@ GPU[2]\t\t: GFX Version: \t\tgfx90c
             backend: GpuBackend::Rocm,
             count: 1,
             unified_memory: false,
+            driver_version: None,
         };
         let gpus = vec![
             mk("AMD Radeon Graphics", 32.0), // mislabeled MI50-class accelerator
@@ -2,6 +2,7 @@ pub mod analysis;
 pub mod bench;
 pub mod benchmarks;
 pub mod claim;
+pub mod dateutil;
 pub mod doctor;
 pub mod fit;
 pub mod hardware;
@@ -10,11 +11,14 @@ pub mod plan;
 pub mod providers;
 pub mod quality;
 pub mod share;
+pub mod subprocess;
 pub mod task_bench;
 pub mod update;
 
 pub use analysis::{InstalledIndex, build_model_fits};
-pub use fit::{FitLevel, InferenceRuntime, ModelFit, RunMode, ScoreComponents, SortColumn};
+pub use fit::{
+    FitLevel, FitReport, InferenceRuntime, ModelFit, RunMode, ScoreComponents, SortColumn,
+};
 pub use hardware::{GpuBackend, SystemSpecs};
 pub use models::{Capability, LlmModel, ModelDatabase, ModelFormat, UseCase};
 pub use plan::{
@@ -4,36 +4,106 @@ use serde::{Deserialize, Serialize};
 /// Used for dynamic quantization selection: try the best that fits.
 pub const QUANT_HIERARCHY: &[&str] = &["Q8_0", "Q6_K", "Q5_K_M", "Q4_K_M", "Q3_K_M", "Q2_K"];
 
+/// Every GGUF quantization llmfit can size, best quality to most compressed.
+/// The names accepted in a `[quant] prefer = [...]` list.
+pub const GGUF_QUANTS: &[&str] = &[
+    "F16", "Q8_0", "Q6_K", "Q5_1", "Q5_K_M", "Q5_K_S", "Q5_0", "Q4_1", "Q4_K_M", "Q4_K_S", "Q4_0",
+    "IQ4_NL", "IQ4_XS", "Q3_K_L", "Q3_K_M", "IQ3_M", "IQ3_S", "Q3_K_S", "IQ3_XS", "IQ3_XXS",
+    "Q2_K", "IQ2_M", "IQ2_S", "IQ2_XS", "IQ2_XXS", "IQ1_M", "IQ1_S",
+];
+
 /// MLX-native quantization hierarchy (best quality to most compressed).
 pub const MLX_QUANT_HIERARCHY: &[&str] = &["mlx-8bit", "mlx-4bit"];
 
 /// ONNX catalog quantization hierarchy (best quality to most compressed).
 pub const ONNX_QUANT_HIERARCHY: &[&str] = &["Q8_0", "Q4_0"];
 
-/// Bytes per parameter for each quantization level.
-pub fn quant_bpp(quant: &str) -> f64 {
+/// Effective bits per weight of each quantization, as a whole GGUF file.
+///
+/// llama.cpp's K-quants are mixes — `Q4_K_M` keeps some tensors at Q6_K and
+/// every block carries scales — so files are larger than the nominal bit
+/// width suggests. These figures are derived from published file sizes of
+/// 8B-70B models (file bytes × 8 / parameters) and land within a few percent
+/// across that range. Unsloth dynamic (`UD-`) quants use their base K-quant.
+pub fn quant_bits_per_weight(quant: &str) -> f64 {
     match quant {
-        "F32" => 4.0,
-        "F16" | "BF16" => 2.0,
-        "Q8_0" => 1.05,
-        "Q6_K" => 0.80,
-        "Q5_K_M" => 0.68,
-        "Q4_K_M" | "Q4_0" => 0.58,
-        "Q3_K_M" => 0.48,
-        "Q2_K" => 0.37,
-        "UD-Q2_K_XL" | "UD-Q2_K_L" | "UD-Q2_K_M" | "UD-Q2_K_S" => 0.37,
-        "UD-Q3_K_XL" | "UD-Q3_K_L" | "UD-Q3_K_M" | "UD-Q3_K_S" => 0.48,
-        "UD-Q4_K_XL" | "UD-Q4_K_L" | "UD-Q4_K_M" | "UD-Q4_K_S" => 0.58,
-        "UD-Q5_K_XL" | "UD-Q5_K_L" | "UD-Q5_K_M" | "UD-Q5_K_S" => 0.68,
-        "UD-Q6_K_XL" | "UD-Q6_K_L" | "UD-Q6_K_M" | "UD-Q6_K_S" => 0.80,
-        "UD-Q8_K_XL" | "UD-Q8_K_L" | "UD-Q8_K_M" | "UD-Q8_K_S" => 1.05,
-        "mlx-4bit" => 0.55,
-        "mlx-8bit" => 1.0,
-        "AWQ-4bit" => 0.5,
-        "AWQ-8bit" => 1.0,
-        "GPTQ-Int4" => 0.5,
-        "GPTQ-Int8" => 1.0,
-        _ => 0.58,
+        "F32" => 32.0,
+        "F16" | "BF16" => 16.0,
+        "Q8_0" => 8.5,
+        "Q6_K" => 6.56,
+        "Q5_1" => 6.0,
+        "Q5_K_M" => 5.68,
+        "Q5_K_S" => 5.53,
+        "Q5_0" => 5.5,
+        "Q4_1" => 5.0,
+        "Q4_K_M" => 4.83,
+        "Q4_K_S" => 4.58,
+        "Q4_0" => 4.55,
+        "IQ4_NL" => 4.5,
+        "IQ4_XS" => 4.3,
+        "Q3_K_L" => 4.25,
+        "Q3_K_M" => 3.9,
+        "IQ3_M" => 3.65,
+        "IQ3_S" => 3.55,
+        "Q3_K_S" => 3.5,
+        "IQ3_XS" => 3.3,
+        "IQ3_XXS" => 3.1,
+        "Q2_K" => 2.99,
+        "IQ2_M" => 2.75,
+        "IQ2_S" => 2.6,
+        "IQ2_XS" => 2.4,
+        "IQ2_XXS" => 2.2,
+        "IQ1_M" => 1.9,
+        "IQ1_S" => 1.75,
+        "UD-Q2_K_XL" | "UD-Q2_K_L" | "UD-Q2_K_M" | "UD-Q2_K_S" => 2.99,
+        "UD-Q3_K_XL" | "UD-Q3_K_L" | "UD-Q3_K_M" | "UD-Q3_K_S" => 3.9,
+        "UD-Q4_K_XL" | "UD-Q4_K_L" | "UD-Q4_K_M" | "UD-Q4_K_S" => 4.83,
+        "UD-Q5_K_XL" | "UD-Q5_K_L" | "UD-Q5_K_M" | "UD-Q5_K_S" => 5.68,
+        "UD-Q6_K_XL" | "UD-Q6_K_L" | "UD-Q6_K_M" | "UD-Q6_K_S" => 6.56,
+        "UD-Q8_K_XL" | "UD-Q8_K_L" | "UD-Q8_K_M" | "UD-Q8_K_S" => 8.5,
+        "mlx-4bit" => 4.4,
+        "mlx-8bit" => 8.0,
+        "AWQ-4bit" | "GPTQ-Int4" | "AutoRound-4bit" => 4.0,
+        "AWQ-8bit" | "GPTQ-Int8" | "AutoRound-8bit" => 8.0,
+        _ => 4.83, // unknown: assume Q4_K_M
+    }
+}
+
+/// Bytes per parameter for each quantization level — the memory estimate's
+/// weight term. See [`quant_bits_per_weight`].
+pub fn quant_bpp(quant: &str) -> f64 {
+    quant_bits_per_weight(quant) / 8.0
+}
+
+/// Runtime overhead (CUDA/Metal context, buffers) added to every memory estimate.
+pub const RUNTIME_OVERHEAD_GB: f64 = 0.5;
+
+/// Layer/head geometry used by the precise KV cache formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct KvShape {
+    pub layers: u32,
+    pub kv_heads: u32,
+    pub head_dim: u32,
+}
+
+/// Breakdown of a memory estimate: weights + KV cache + runtime overhead.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MemoryTerms {
+    pub params_b: f64,
+    pub bytes_per_param: f64,
+    /// `params_b * bytes_per_param`.
+    pub weights_gb: f64,
+    pub context: u32,
+    pub kv_quant: KvQuant,
+    /// `None` when the coarse `params * ctx` KV approximation was used.
+    pub kv_shape: Option<KvShape>,
+    pub kv_cache_gb: f64,
+    pub overhead_gb: f64,
+}
+
+impl MemoryTerms {
+    pub fn total_gb(&self) -> f64 {
+        self.weights_gb + self.kv_cache_gb + self.overhead_gb
     }
 }
 
@@ -96,6 +166,8 @@ pub fn quant_quality_penalty(quant: &str) -> f64 {
         "Q4_K_M" | "Q4_0" => -5.0,
         "Q3_K_M" => -8.0,
         "Q2_K" => -12.0,
+        "IQ3_M" | "IQ3_S" | "IQ3_XS" | "IQ3_XXS" => -8.0,
+        "IQ2_M" | "IQ2_S" | "IQ2_XS" | "IQ2_XXS" => -12.0,
         "UD-Q2_K_XL" | "UD-Q2_K_L" | "UD-Q2_K_M" | "UD-Q2_K_S" => -12.0,
         "UD-Q3_K_XL" | "UD-Q3_K_L" | "UD-Q3_K_M" | "UD-Q3_K_S" => -8.0,
         "UD-Q4_K_XL" | "UD-Q4_K_L" | "UD-Q4_K_M" | "UD-Q4_K_S" => -5.0,
@@ -114,6 +186,29 @@ pub fn quant_quality_penalty(quant: &str) -> f64 {
     }
 }
 
+/// [`quant_quality_penalty`] adjusted for model size. 3-bit and lower quants
+/// degrade small models far more than large ones (a 3B at Q2_K is barely
+/// coherent, a 70B at Q2_K is still usable), so their penalty is scaled up
+/// below 14B and eased off from 30B. Milder quants are size-independent.
+pub fn quant_quality_penalty_for_size(quant: &str, params_b: f64) -> f64 {
+    let base = quant_quality_penalty(quant);
+    if base > -8.0 {
+        return base;
+    }
+    let scale = if params_b < 3.0 {
+        2.5
+    } else if params_b < 7.0 {
+        2.0
+    } else if params_b < 14.0 {
+        1.5
+    } else if params_b < 30.0 {
+        1.0
+    } else {
+        0.75
+    };
+    base * scale
+}
+
 /// Parse model generation from architecture string and model name.
 ///
 /// Returns a generation number (e.g. 2.0 for "qwen2", 3.5 for "qwen3_5_moe",
@@ -314,6 +409,8 @@ pub enum Capability {
     ToolUse,
     Audio,
     Tts,
+    /// Structured output: reliably emits JSON matching a schema.
+    JsonMode,
 }
 
 impl Capability {
@@ -323,6 +420,7 @@ impl Capability {
             Capability::ToolUse => "Tool Use",
             Capability::Audio => "Audio",
             Capability::Tts => "Text-to-Speech",
+            Capability::JsonMode => "JSON Mode",
         }
     }
 
@@ -332,6 +430,7 @@ impl Capability {
             Capability::ToolUse,
             Capability::Audio,
             Capability::Tts,
+            Capability::JsonMode,
         ]
     }
 
@@ -391,6 +490,16 @@ impl Capability {
             caps.push(Capability::Tts);
         }
 
+        // Structured output. Function calling is JSON emission under a schema,
+        // so tool-use models get it too.
+        if !caps.contains(&Capability::JsonMode)
+            && (caps.contains(&Capability::ToolUse)
+                || use_case.contains("json")
+                || use_case.contains("structured output"))
+        {
+            caps.push(Capability::JsonMode);
+        }
+
         caps
     }
 }
@@ -444,6 +553,43 @@ impl UseCase {
         }
     }
 
+    /// One or two sentences explaining the category, for detail views.
+    pub fn description(&self) -> &'static str {
+        match self {
+            UseCase::General => {
+                "All-round text generation: writing, summarizing, and answering questions."
+            }
+            UseCase::Coding => {
+                "Writing, explaining, and refactoring code. Best for IDE assistants and coding agents."
+            }
+            UseCase::Reasoning => {
+                "Step-by-step logical deduction, math, and complex problem solving. Best for chain-of-thought tasks."
+            }
+            UseCase::Chat => {
+                "Instruction-tuned for conversation. Best for assistants and chatbots."
+            }
+            UseCase::Multimodal => {
+                "Understands images alongside text. Best for describing, reading, or asking about pictures."
+            }
+            UseCase::Embedding => {
+                "Turns text into vectors rather than generating it. Best for search, clustering, and RAG retrieval."
+            }
+        }
+    }
+
+    /// A single-codepoint emoji for compact displays. Coding uses 💻 because
+    /// 🧑‍💻 is a multi-codepoint sequence.
+    pub fn emoji(&self) -> char {
+        match self {
+            UseCase::General => '🤖',
+            UseCase::Coding => '💻',
+            UseCase::Reasoning => '🔮',
+            UseCase::Chat => '💬',
+            UseCase::Multimodal => '👀',
+            UseCase::Embedding => '🧭',
+        }
+    }
+
     /// Infer use-case from the model's use_case field and name.
     pub fn from_model(model: &LlmModel) -> Self {
         let name = model.name.to_lowercase();
@@ -543,6 +689,21 @@ pub struct LlmModel {
     /// "deepseek_v3"). Used to infer model generation for quality scoring.
     #[serde(default)]
     pub architecture: Option<String>,
+    /// Model family derived from the name by [`parse_family`] (e.g.
+    /// "llama-3.1", "qwen2.5", "deepseek-r1"); filled in when the database
+    /// loads, so it is empty only on hand-built models.
+    #[serde(default)]
+    pub family: String,
+    /// Window the model was pre-trained on, when `context_length` is only
+    /// reached through rope scaling (YaRN, linear/dynamic NTK). Qwen2.5 is
+    /// trained at 32k and advertises 128k with YaRN. `None` means the whole
+    /// `context_length` is native.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trained_context: Option<u32>,
+    /// Longest window the runtime can stretch to with rope scaling beyond
+    /// `context_length`. `None` when the model is not known to extend further.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_context: Option<u32>,
 }
 
 /// Composition of attention layers in a hybrid model.
@@ -707,6 +868,41 @@ impl LlmModel {
         self.capabilities.contains(&Capability::Tts)
     }
 
+    /// Tool / function calling, for agentic use.
+    pub fn supports_tools(&self) -> bool {
+        self.capabilities.contains(&Capability::ToolUse)
+    }
+
+    /// Structured (JSON / schema-constrained) output.
+    pub fn supports_json_mode(&self) -> bool {
+        self.capabilities.contains(&Capability::JsonMode)
+    }
+
+    /// Image input.
+    pub fn supports_vision(&self) -> bool {
+        self.capabilities.contains(&Capability::Vision)
+    }
+
+    /// Context the model was trained on, before any rope scaling. Never
+    /// exceeds `context_length`.
+    pub fn native_context(&self) -> u32 {
+        self.trained_context
+            .map_or(self.context_length, |t| t.min(self.context_length))
+    }
+
+    /// Longest context the model can run at, rope scaling included. Never
+    /// below `context_length`.
+    pub fn extended_context(&self) -> u32 {
+        self.max_context
+            .map_or(self.context_length, |m| m.max(self.context_length))
+    }
+
+    /// True when running at `ctx` tokens goes past the trained window, so
+    /// the runtime relies on rope scaling and long-range quality degrades.
+    pub fn needs_rope_scaling(&self, ctx: u32) -> bool {
+        ctx > self.native_context()
+    }
+
     /// Returns true if the model's attention/KV heads are evenly divisible
     /// by `tp_size`, meaning it can be split across that many devices.
     /// TP=1 always returns true.
@@ -757,6 +953,16 @@ impl LlmModel {
         }
     }
 
+    /// Active parameter count per token in billions, for MoE models that
+    /// report it. Returns None for dense models.
+    pub fn active_params_b(&self) -> Option<f64> {
+        if !self.is_moe {
+            return None;
+        }
+        self.active_parameters
+            .map(|active| active as f64 / 1_000_000_000.0)
+    }
+
     /// Approximate on-disk size (GB) for a given quantization level.
     /// This is just the model weights: params_b * bytes_per_param.
     pub fn estimate_disk_gb(&self, quant: &str) -> f64 {
@@ -832,13 +1038,39 @@ impl LlmModel {
     /// Estimate memory required (GB) with an explicit KV cache quantization.
     /// Formula: model_weights + KV_cache + runtime_overhead
     pub fn estimate_memory_gb_with_kv(&self, quant: &str, ctx: u32, kv: KvQuant) -> f64 {
-        let bpp = quant_bpp(quant);
-        let params = self.params_b();
-        let model_mem = params * bpp;
-        let kv_cache = self.kv_cache_gb(ctx, kv);
-        // Runtime overhead (CUDA/Metal context, buffers)
-        let overhead = 0.5;
-        model_mem + kv_cache + overhead
+        self.memory_terms(quant, ctx, kv).total_gb()
+    }
+
+    /// The individual terms behind [`Self::estimate_memory_gb_with_kv`], so
+    /// the arithmetic can be shown to users and checked by hand.
+    pub fn memory_terms(&self, quant: &str, ctx: u32, kv: KvQuant) -> MemoryTerms {
+        let params_b = self.params_b();
+        let bytes_per_param = quant_bpp(quant);
+        MemoryTerms {
+            params_b,
+            bytes_per_param,
+            weights_gb: params_b * bytes_per_param,
+            context: ctx,
+            kv_quant: kv,
+            kv_shape: self.kv_shape(),
+            kv_cache_gb: self.kv_cache_gb(ctx, kv),
+            overhead_gb: RUNTIME_OVERHEAD_GB,
+        }
+    }
+
+    /// Layer/head geometry for the precise KV cache formula, or `None` when
+    /// the catalog entry lacks the metadata and the coarse fallback applies.
+    pub fn kv_shape(&self) -> Option<KvShape> {
+        let (layers, head_dim) = (self.num_hidden_layers?, self.head_dim?);
+        let kv_heads = self
+            .num_key_value_heads
+            .or(self.num_attention_heads)
+            .unwrap_or(8);
+        Some(KvShape {
+            layers,
+            kv_heads,
+            head_dim,
+        })
     }
 
     /// KV cache size in GB at the given context length and KV quant.
@@ -858,12 +1090,12 @@ impl LlmModel {
         let layout = self.effective_attention_layout();
 
         // Precise path: requires layer count, KV head count, head dim.
-        if let (Some(n_layers), Some(head_dim)) = (self.num_hidden_layers, self.head_dim) {
-            let n_kv_heads = self
-                .num_key_value_heads
-                .or(self.num_attention_heads)
-                .unwrap_or(8);
-
+        if let Some(KvShape {
+            layers: n_layers,
+            kv_heads: n_kv_heads,
+            head_dim,
+        }) = self.kv_shape()
+        {
             let bytes_per_layer =
                 |bpe: f64| -> f64 { 2.0 * n_kv_heads as f64 * head_dim as f64 * ctx as f64 * bpe };
 
@@ -1037,6 +1269,10 @@ struct HfModelEntry {
     license: Option<String>,
     #[serde(default)]
     architecture: Option<String>,
+    #[serde(default)]
+    trained_context: Option<u32>,
+    #[serde(default)]
+    max_context: Option<u32>,
 }
 
 const HF_MODELS_JSON: &str = include_str!("../data/hf_models.json");
@@ -1098,6 +1334,95 @@ pub(crate) fn canonical_slug(name: &str) -> String {
     slug.to_lowercase().replace(['-', '_', '.'], "")
 }
 
+/// Model family from a model name: the leading name tokens before the first
+/// size, variant or format marker, lowercased and joined with `-`.
+///
+/// `meta-llama/Llama-3.1-8B-Instruct` → `llama-3.1`,
+/// `Qwen/Qwen2.5-7B-Instruct` → `qwen2.5`,
+/// `deepseek-ai/DeepSeek-R1-Distill-Qwen-7B` → `deepseek-r1`.
+pub fn parse_family(name: &str) -> String {
+    const STOP_WORDS: &[&str] = &[
+        "instruct",
+        "chat",
+        "it",
+        "base",
+        "distill",
+        "preview",
+        "tiny",
+        "mini",
+        "small",
+        "medium",
+        "large",
+        "xl",
+        "gguf",
+        "awq",
+        "gptq",
+        "autoround",
+        "mlx",
+        "fp8",
+        "fp16",
+        "bf16",
+        "int4",
+        "int8",
+        "nvfp4",
+        "hf",
+        "onnx",
+    ];
+    let is_size = |token: &str| {
+        // 8b, 0.5b, 345m, 128k, a3b, e4b, 8x7b
+        let token = token
+            .strip_prefix(['a', 'e'])
+            .filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+            .unwrap_or(token);
+        let digits = token.trim_end_matches(['b', 'm', 'k']);
+        digits.len() < token.len()
+            && !digits.is_empty()
+            && digits
+                .split('x')
+                .all(|part| !part.is_empty() && part.parse::<f64>().is_ok())
+    };
+    let is_version = |token: &str| {
+        token
+            .strip_prefix('v')
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+    };
+    let is_quant_bits = |token: &str| {
+        token
+            .strip_suffix("bit")
+            .is_some_and(|bits| bits.parse::<f64>().is_ok())
+    };
+
+    let slug = name.split('/').next_back().unwrap_or(name).to_lowercase();
+    let mut tokens = slug
+        .split(['-', '_', ' '])
+        .filter(|t| !t.is_empty())
+        .peekable();
+    // "Meta-Llama-3.1" is the same family as "Llama-3.1".
+    if tokens.peek() == Some(&"meta") {
+        tokens.next();
+    }
+    let mut family: Vec<&str> = Vec::new();
+    for token in tokens {
+        let stop = STOP_WORDS.contains(&token)
+            || is_size(token)
+            || is_version(token)
+            || is_quant_bits(token);
+        if stop && !family.is_empty() {
+            break;
+        }
+        family.push(token);
+    }
+    family.join("-")
+}
+
+/// Fill in [`LlmModel::family`] where it is missing (e.g. models from an
+/// older update cache).
+fn fill_families(models: &mut [LlmModel]) {
+    for model in models.iter_mut().filter(|m| m.family.is_empty()) {
+        model.family = parse_family(&model.name);
+    }
+}
+
 /// Deduplicate a list of [`HfModelEntry`] records by canonical name slug, merging duplicates.
 ///
 /// Uses [`canonical_slug`] as the deduplication key so that entries differing
@@ -1137,6 +1462,12 @@ fn dedupe_hf_entries(entries: Vec<HfModelEntry>) -> Vec<HfModelEntry> {
                 if entry.context_length > existing.context_length {
                     existing.context_length = entry.context_length;
                 }
+                if entry.max_context > existing.max_context {
+                    existing.max_context = entry.max_context;
+                }
+                if existing.trained_context.is_none() {
+                    existing.trained_context = entry.trained_context;
+                }
                 // Merge MoE fields: if either is MoE, keep MoE info.
                 if entry.is_moe && !existing.is_moe {
                     existing.is_moe = true;
@@ -1232,6 +1563,9 @@ fn entry_to_model(e: HfModelEntry) -> LlmModel {
         shared_expert_intermediate_size: e.shared_expert_intermediate_size,
         license: e.license,
         architecture: e.architecture,
+        family: String::new(),
+        trained_context: e.trained_context,
+        max_context: e.max_context,
     };
     model.capabilities = Capability::infer(&model);
     // Auto-populate attention_layout from name heuristic for known
@@ -1379,6 +1713,9 @@ impl OnnxModelEntry {
             shared_expert_intermediate_size: None,
             license: self.license,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
         };
         model.capabilities = Capability::infer(&model);
         model
@@ -1389,6 +1726,12 @@ impl OnnxModelEntry {
 fn load_embedded() -> Vec<LlmModel> {
     let entries: Vec<HfModelEntry> =
         serde_json::from_str(HF_MODELS_JSON).expect("Failed to parse embedded hf_models.json");
+    with_embedded_onnx(entries)
+}
+
+/// Map catalog entries to models and add the embedded ONNX models, which
+/// replace any catalog entry with the same name.
+fn with_embedded_onnx(entries: Vec<HfModelEntry>) -> Vec<LlmModel> {
     // Deduplicate before mapping: ensures downstream code never sees two rows
     // for the same model slug with conflicting metadata.
     let mut models: Vec<LlmModel> = dedupe_hf_entries(entries)
@@ -1411,6 +1754,170 @@ fn load_embedded() -> Vec<LlmModel> {
     models
 }
 
+/// Environment variable naming a URL to load the model catalog from instead
+/// of the built-in list (see [`ModelDatabase::from_url`]).
+pub const MODELS_URL_ENV: &str = "LLMFIT_MODELS_URL";
+
+/// How long a catalog fetched by [`ModelDatabase::from_url`] is reused
+/// before it is fetched again.
+pub const REMOTE_CATALOG_TTL_SECS: u64 = 24 * 60 * 60;
+
+/// After a failed fetch, how long to keep using the cached (or built-in)
+/// catalog before trying the URL again.
+pub const REMOTE_CATALOG_RETRY_SECS: u64 = 60 * 60;
+
+/// Largest catalog body accepted from a remote URL. The bundled catalog is
+/// about 5 MB.
+const REMOTE_CATALOG_MAX_BYTES: u64 = 64 * 1024 * 1024;
+
+/// A remote catalog as last fetched, persisted at
+/// `<data dir>/llmfit/remote_models.json`.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RemoteCatalogCache {
+    version: u32,
+    url: String,
+    /// Unix seconds when the catalog was fetched.
+    fetched_at: u64,
+    models: Vec<LlmModel>,
+}
+
+/// The last failed fetch of a remote catalog, persisted next to the cache
+/// (`remote_models.failed.json`) so later runs back off instead of waiting
+/// on an unreachable URL every launch.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RemoteCatalogFailure {
+    url: String,
+    /// Unix seconds of the failed attempt.
+    failed_at: u64,
+}
+
+fn remote_catalog_cache_file() -> Option<std::path::PathBuf> {
+    Some(crate::update::cache_dir()?.join("remote_models.json"))
+}
+
+fn remote_catalog_failure_file(cache_path: &std::path::Path) -> std::path::PathBuf {
+    cache_path.with_extension("failed.json")
+}
+
+/// Catalogs loaded by [`ModelDatabase::from_url`] in this process, by URL.
+/// `ModelDatabase::new` runs from several places; this keeps it from
+/// re-reading the cache or re-fetching each time.
+static REMOTE_CATALOGS: std::sync::OnceLock<
+    std::sync::Mutex<std::collections::HashMap<String, Vec<LlmModel>>>,
+> = std::sync::OnceLock::new();
+
+/// Download a catalog body from `url`.
+fn fetch_catalog(url: &str) -> Result<String, String> {
+    let mut resp = ureq::get(url)
+        .config()
+        .timeout_global(Some(std::time::Duration::from_secs(10)))
+        .build()
+        .call()
+        .map_err(|e| e.to_string())?;
+    resp.body_mut()
+        .with_config()
+        .limit(REMOTE_CATALOG_MAX_BYTES)
+        .read_to_string()
+        .map_err(|e| e.to_string())
+}
+
+/// Parse a catalog in the `hf_models.json` schema.
+fn parse_catalog(json: &str) -> Result<Vec<LlmModel>, String> {
+    let entries: Vec<HfModelEntry> =
+        serde_json::from_str(json).map_err(|e| format!("invalid model catalog: {e}"))?;
+    if entries.is_empty() {
+        return Err("model catalog is empty".to_string());
+    }
+    Ok(with_embedded_onnx(entries))
+}
+
+/// Models from the catalog at `url`: the cached copy while it is younger
+/// than the TTL, otherwise a fresh download (which refreshes the cache),
+/// otherwise the stale cached copy. `Err` only when none of those exist.
+/// A failed download is recorded, and no new one is attempted for
+/// [`REMOTE_CATALOG_RETRY_SECS`]. `fetch` is injected so tests don't hit
+/// the network.
+fn load_remote_catalog(
+    url: &str,
+    cache_path: Option<&std::path::Path>,
+    now: u64,
+    fetch: &dyn Fn(&str) -> Result<String, String>,
+) -> Result<Vec<LlmModel>, String> {
+    let cached = cache_path
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|text| serde_json::from_str::<RemoteCatalogCache>(&text).ok())
+        .filter(|c| c.version == crate::update::CACHE_VERSION && c.url == url);
+    if let Some(cache) = &cached
+        && now.saturating_sub(cache.fetched_at) < REMOTE_CATALOG_TTL_SECS
+    {
+        return Ok(cache.models.clone());
+    }
+
+    let failure_path = cache_path.map(remote_catalog_failure_file);
+    let recent_failure = failure_path
+        .as_deref()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|text| serde_json::from_str::<RemoteCatalogFailure>(&text).ok())
+        .filter(|f| f.url == url && now.saturating_sub(f.failed_at) < REMOTE_CATALOG_RETRY_SECS);
+    if recent_failure.is_some() {
+        return cached
+            .map(|c| c.models)
+            .ok_or_else(|| "last download failed; retrying later".to_string());
+    }
+
+    match fetch(url).and_then(|body| parse_catalog(&body)) {
+        Ok(models) => {
+            if let Some(path) = &failure_path {
+                let _ = std::fs::remove_file(path);
+            }
+            if let Some(path) = cache_path {
+                let cache = RemoteCatalogCache {
+                    version: crate::update::CACHE_VERSION,
+                    url: url.to_string(),
+                    fetched_at: now,
+                    models,
+                };
+                if let Some(dir) = path.parent() {
+                    let _ = std::fs::create_dir_all(dir);
+                }
+                if let Ok(json) = serde_json::to_string(&cache) {
+                    let _ = std::fs::write(path, json);
+                }
+                return Ok(cache.models);
+            }
+            Ok(models)
+        }
+        Err(e) => {
+            if let Some(path) = &failure_path {
+                let failure = RemoteCatalogFailure {
+                    url: url.to_string(),
+                    failed_at: now,
+                };
+                if let Some(dir) = path.parent() {
+                    let _ = std::fs::create_dir_all(dir);
+                }
+                if let Ok(json) = serde_json::to_string(&failure) {
+                    let _ = std::fs::write(path, json);
+                }
+            }
+            cached.map(|c| c.models).ok_or(e)
+        }
+    }
+}
+
+/// [`load_remote_catalog`], falling back to the built-in list with a warning.
+fn remote_or_embedded(
+    url: &str,
+    cache_path: Option<&std::path::Path>,
+    now: u64,
+    fetch: &dyn Fn(&str) -> Result<String, String>,
+) -> Vec<LlmModel> {
+    load_remote_catalog(url, cache_path, now, fetch).unwrap_or_else(|e| {
+        eprintln!("Warning: could not load model list from {url} ({e}); using the built-in list");
+        load_embedded()
+    })
+}
+
 /// Full path to the user's custom model overlay file, alongside the update
 /// cache (e.g. `~/.local/share/llmfit/custom_models.json` on Linux).
 /// The `LLMFIT_CUSTOM_MODELS` env var overrides the location.
@@ -1439,13 +1946,41 @@ fn load_custom_models_from(path: &std::path::Path) -> Result<Vec<LlmModel>, Stri
         .collect())
 }
 
+/// Consume the database, yielding owned models.
+///
+/// ```
+/// use llmfit_core::models::{LlmModel, ModelDatabase};
+///
+/// let db = ModelDatabase::new();
+/// let count = db.len();
+/// let models: Vec<LlmModel> = db.into_iter().collect();
+/// assert_eq!(models.len(), count);
+/// ```
+impl IntoIterator for ModelDatabase {
+    type Item = LlmModel;
+    type IntoIter = std::vec::IntoIter<LlmModel>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.models.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ModelDatabase {
+    type Item = &'a LlmModel;
+    type IntoIter = std::slice::Iter<'a, LlmModel>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.models.iter()
+    }
+}
+
 impl ModelDatabase {
     /// Load only the compile-time embedded model list (no cache).
     /// Used internally by the updater to determine which models are already known.
     pub fn embedded() -> Self {
-        ModelDatabase {
-            models: load_embedded(),
-        }
+        let mut models = load_embedded();
+        fill_families(&mut models);
+        ModelDatabase { models }
     }
 
     /// Load the embedded model list **and** merge user custom models and any
@@ -1456,9 +1991,53 @@ impl ModelDatabase {
     /// `llmfit update`) are appended only for slugs not already present.
     /// A missing cache/custom file is ignored; a *corrupt* custom file prints
     /// a warning to stderr so hand-written entries don't vanish silently.
+    ///
+    /// When [`MODELS_URL_ENV`] is set, the catalog comes from that URL
+    /// instead, as with [`ModelDatabase::from_url`].
     pub fn new() -> Self {
-        let mut models = load_embedded();
+        match std::env::var(MODELS_URL_ENV) {
+            Ok(url) if !url.trim().is_empty() => Self::from_url(url.trim()),
+            _ => Self::with_overlays(load_embedded()),
+        }
+    }
 
+    /// Load the model catalog from `url` (same schema as the built-in
+    /// `hf_models.json`) so the list can update without a new binary.
+    ///
+    /// The download is cached in the data directory and reused for
+    /// [`REMOTE_CATALOG_TTL_SECS`]. When the URL can't be reached the last
+    /// cached copy is used, and without one the built-in list, with a
+    /// warning on stderr. The catalog is loaded once per process. Custom and
+    /// `llmfit update` models are merged as in [`ModelDatabase::new`].
+    pub fn from_url(url: &str) -> Self {
+        let catalogs = REMOTE_CATALOGS.get_or_init(Default::default);
+        let mut catalogs = catalogs.lock().unwrap_or_else(|e| e.into_inner());
+        let models = catalogs
+            .entry(url.to_string())
+            .or_insert_with(|| {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+                let cache_path = remote_catalog_cache_file();
+                remote_or_embedded(url, cache_path.as_deref(), now, &fetch_catalog)
+            })
+            .clone();
+        drop(catalogs);
+        Self::with_overlays(models)
+    }
+
+    #[cfg(test)]
+    fn from_url_with(
+        url: &str,
+        cache_path: Option<&std::path::Path>,
+        now: u64,
+        fetch: &dyn Fn(&str) -> Result<String, String>,
+    ) -> Self {
+        Self::with_overlays(remote_or_embedded(url, cache_path, now, fetch))
+    }
+
+    fn with_overlays(mut models: Vec<LlmModel>) -> Self {
         // Overlay user-defined models: same slug replaces the embedded entry,
         // new slugs are appended.
         if let Some(path) = custom_models_file() {
@@ -1487,6 +2066,7 @@ impl ModelDatabase {
             }
         }
 
+        fill_families(&mut models);
         ModelDatabase { models }
     }
 
@@ -1494,6 +2074,80 @@ impl ModelDatabase {
         &self.models
     }
 
+    /// Iterate over every model. `for model in &db` does the same.
+    ///
+    /// ```
+    /// use llmfit_core::models::ModelDatabase;
+    ///
+    /// let db = ModelDatabase::new();
+    /// assert_eq!(db.iter().count(), db.len());
+    /// for model in &db {
+    ///     assert!(!model.name.is_empty());
+    /// }
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, LlmModel> {
+        self.models.iter()
+    }
+
+    /// Number of models in the database.
+    ///
+    /// ```
+    /// use llmfit_core::models::ModelDatabase;
+    ///
+    /// let db = ModelDatabase::new();
+    /// assert_eq!(db.len(), db.get_all_models().len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.models.len()
+    }
+
+    /// Whether the database has no models.
+    ///
+    /// ```
+    /// use llmfit_core::models::ModelDatabase;
+    ///
+    /// assert!(!ModelDatabase::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty()
+    }
+
+    /// Lazily iterate over the models in `family`, with the same matching as
+    /// [`get_models_by_family`](Self::get_models_by_family).
+    ///
+    /// ```
+    /// use llmfit_core::models::ModelDatabase;
+    ///
+    /// let db = ModelDatabase::new();
+    /// let family = db.families().into_iter().next().unwrap();
+    /// assert!(db.iter_by_family(&family).all(|m| m.family == family));
+    /// assert_eq!(db.iter_by_family("no-such-family").count(), 0);
+    /// ```
+    pub fn iter_by_family(&self, family: &str) -> impl Iterator<Item = &LlmModel> + use<'_> {
+        let family = family.trim().to_string();
+        self.models
+            .iter()
+            .filter(move |m| m.family.eq_ignore_ascii_case(&family))
+    }
+
+    /// Every model in `family` (see [`parse_family`]), compared
+    /// case-insensitively: `"llama-3.1"` returns the 8B, 70B and 405B variants
+    /// and their fine-tunes that keep the name.
+    pub fn get_models_by_family(&self, family: &str) -> Vec<&LlmModel> {
+        self.iter_by_family(family).collect()
+    }
+
+    /// All family names in the database, sorted and deduplicated.
+    pub fn families(&self) -> Vec<String> {
+        self.models
+            .iter()
+            .map(|m| m.family.clone())
+            .filter(|f| !f.is_empty())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
     pub fn find_model(&self, query: &str) -> Vec<&LlmModel> {
         let query_lower = query.to_lowercase();
         self.models
@@ -1786,6 +2440,112 @@ mod tests {
         assert_eq!(replaced.use_case, "Testing");
     }
 
+    /// Serve `body` once over HTTP on a local port and return its URL.
+    fn serve_once(body: &'static str) -> String {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/models.json", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request);
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+        });
+        url
+    }
+
+    #[test]
+    fn test_remote_catalog_loads_from_url_and_caches_with_ttl() {
+        let url = serve_once(CUSTOM_ENTRY_JSON);
+        let cache = std::env::temp_dir().join(format!(
+            "llmfit-test-{}-remote_models.json",
+            std::process::id()
+        ));
+        std::fs::remove_file(&cache).ok();
+        let offline = |_: &str| -> Result<String, String> { Err("offline".to_string()) };
+
+        let models = load_remote_catalog(&url, Some(&cache), 1_000, &fetch_catalog).unwrap();
+        assert!(models.iter().any(|m| m.name == "acme/CustomNet-7B"));
+
+        // Within the TTL the cache answers without fetching.
+        let fresh = load_remote_catalog(&url, Some(&cache), 1_000 + 60, &offline).unwrap();
+        assert!(fresh.iter().any(|m| m.name == "acme/CustomNet-7B"));
+
+        // Past the TTL and offline: the stale copy still beats nothing.
+        let stale = load_remote_catalog(
+            &url,
+            Some(&cache),
+            1_000 + REMOTE_CATALOG_TTL_SECS,
+            &offline,
+        )
+        .unwrap();
+        assert_eq!(stale.len(), models.len());
+
+        // A cache written for another URL isn't reused.
+        assert!(
+            load_remote_catalog("http://other.invalid/", Some(&cache), 1_000, &offline).is_err()
+        );
+        std::fs::remove_file(&cache).ok();
+        std::fs::remove_file(remote_catalog_failure_file(&cache)).ok();
+    }
+
+    #[test]
+    fn test_remote_catalog_backs_off_after_failed_fetch() {
+        let cache = std::env::temp_dir().join(format!(
+            "llmfit-test-{}-backoff-remote_models.json",
+            std::process::id()
+        ));
+        std::fs::remove_file(remote_catalog_failure_file(&cache)).ok();
+        let url = "http://catalog.invalid/";
+        let attempts = std::cell::Cell::new(0);
+        let offline = |_: &str| -> Result<String, String> {
+            attempts.set(attempts.get() + 1);
+            Err("offline".to_string())
+        };
+
+        assert!(load_remote_catalog(url, Some(&cache), 1_000, &offline).is_err());
+        assert_eq!(attempts.get(), 1);
+
+        // Within the retry window the URL isn't tried again.
+        assert!(load_remote_catalog(url, Some(&cache), 1_000 + 60, &offline).is_err());
+        assert_eq!(attempts.get(), 1);
+
+        assert!(
+            load_remote_catalog(
+                url,
+                Some(&cache),
+                1_000 + REMOTE_CATALOG_RETRY_SECS,
+                &offline
+            )
+            .is_err()
+        );
+        assert_eq!(attempts.get(), 2);
+        std::fs::remove_file(remote_catalog_failure_file(&cache)).ok();
+    }
+
+    #[test]
+    fn test_remote_catalog_offline_falls_back_to_embedded() {
+        let offline = |_: &str| -> Result<String, String> { Err("offline".to_string()) };
+        let db = ModelDatabase::from_url_with("http://catalog.invalid/", None, 0, &offline);
+        let embedded = ModelDatabase::embedded();
+        assert!(
+            db.get_all_models()
+                .iter()
+                .any(|m| m.name == embedded.get_all_models()[0].name)
+        );
+
+        // A body that isn't a catalog is rejected rather than emptying the list.
+        let garbage = |_: &str| -> Result<String, String> { Ok("{\"error\": 1}".to_string()) };
+        assert!(load_remote_catalog("http://catalog.invalid/", None, 0, &garbage).is_err());
+        let empty = |_: &str| -> Result<String, String> { Ok("[]".to_string()) };
+        assert!(load_remote_catalog("http://catalog.invalid/", None, 0, &empty).is_err());
+    }
+
     #[test]
     fn test_matches_license_filter_handles_comma_separated_model_licenses() {
         let license = Some("apache-2.0,mit".to_string());
@@ -1845,7 +2605,7 @@ mod tests {
         // Verify no longer hitting defaults
         assert!(
             quant_bpp("UD-Q2_K_XL") < 0.5,
-            "UD-Q2_K_XL bpp should be 0.37, not default 0.58"
+            "UD-Q2_K_XL bpp should match Q2_K, not the Q4_K_M default"
         );
         assert!(
             quant_bytes_per_param("UD-Q2_K_XL") < 0.4,
@@ -1885,6 +2645,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
 
@@ -1905,11 +2668,47 @@ mod tests {
     fn test_quant_bpp() {
         assert_eq!(quant_bpp("F32"), 4.0);
         assert_eq!(quant_bpp("F16"), 2.0);
-        assert_eq!(quant_bpp("Q8_0"), 1.05);
-        assert_eq!(quant_bpp("Q4_K_M"), 0.58);
-        assert_eq!(quant_bpp("Q2_K"), 0.37);
+        assert_eq!(quant_bpp("Q8_0"), 8.5 / 8.0);
+        assert_eq!(quant_bpp("Q4_K_M"), 4.83 / 8.0);
+        assert_eq!(quant_bpp("IQ4_XS"), 4.3 / 8.0);
         // Unknown quant defaults to Q4_K_M
-        assert_eq!(quant_bpp("UNKNOWN"), 0.58);
+        assert_eq!(quant_bpp("UNKNOWN"), quant_bpp("Q4_K_M"));
+    }
+
+    #[test]
+    fn test_quant_sizes_match_real_gguf_files() {
+        // (params, quant, published file size in GB) for Llama-3.1-70B-Instruct
+        // (70.55B) and Qwen2.5-32B-Instruct (32.76B) GGUF builds.
+        let files = [
+            (70.55, "Q8_0", 74.98),
+            (70.55, "Q6_K", 57.89),
+            (70.55, "Q5_K_M", 49.95),
+            (70.55, "Q4_K_M", 42.52),
+            (70.55, "Q3_K_M", 34.27),
+            (70.55, "Q2_K", 26.38),
+            (70.55, "IQ4_XS", 37.90),
+            (70.55, "IQ2_M", 24.12),
+            (32.76, "Q8_0", 34.82),
+            (32.76, "Q6_K", 26.89),
+            (32.76, "Q4_K_M", 19.85),
+        ];
+        for (params_b, quant, real_gb) in files {
+            let estimate = params_b * quant_bpp(quant);
+            let error = (estimate - real_gb).abs() / real_gb;
+            assert!(
+                error < 0.03,
+                "{quant} @ {params_b}B: estimated {estimate:.2} GB, real {real_gb} GB"
+            );
+        }
+    }
+
+    #[test]
+    fn test_quant_bits_follow_compression_order() {
+        for pair in QUANT_HIERARCHY.windows(2) {
+            assert!(quant_bits_per_weight(pair[0]) > quant_bits_per_weight(pair[1]));
+        }
+        assert!(quant_bits_per_weight("IQ2_XXS") < quant_bits_per_weight("Q2_K"));
+        assert!(quant_bits_per_weight("IQ1_S") < quant_bits_per_weight("IQ2_XXS"));
     }
 
     #[test]
@@ -1968,6 +2767,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         assert_eq!(model.params_b(), 7.0);
@@ -2005,6 +2807,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         assert_eq!(model.params_b(), 13.0);
@@ -2042,6 +2847,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         assert_eq!(model.params_b(), 0.5);
@@ -2079,6 +2887,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
 
@@ -2124,6 +2935,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
 
@@ -2175,9 +2989,13 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         assert!(dense_model.moe_active_vram_gb().is_none());
+        assert!(dense_model.active_params_b().is_none());
 
         // MoE model should calculate active VRAM
         let moe_model = LlmModel {
@@ -2210,8 +3028,12 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
+        assert_eq!(moe_model.active_params_b(), Some(12.9));
         let vram = moe_model.moe_active_vram_gb();
         assert!(vram.is_some());
         let vram_val = vram.unwrap();
@@ -2253,6 +3075,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         assert!(dense_model.moe_offloaded_ram_gb().is_none());
@@ -2288,6 +3113,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         let offloaded = moe_model.moe_offloaded_ram_gb();
@@ -2333,6 +3161,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         assert_eq!(UseCase::from_model(&model), UseCase::Coding);
@@ -2370,6 +3201,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         assert_eq!(UseCase::from_model(&model), UseCase::Embedding);
@@ -2407,11 +3241,36 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         assert_eq!(UseCase::from_model(&model), UseCase::Reasoning);
     }
 
+    #[test]
+    fn test_use_case_description_and_emoji() {
+        assert_eq!(
+            UseCase::Reasoning.description(),
+            "Step-by-step logical deduction, math, and complex problem solving. Best for chain-of-thought tasks."
+        );
+        assert_eq!(UseCase::Coding.emoji(), '💻');
+        assert_eq!(UseCase::Reasoning.emoji(), '🔮');
+        assert_eq!(UseCase::Chat.emoji(), '💬');
+        let all = [
+            UseCase::General,
+            UseCase::Coding,
+            UseCase::Reasoning,
+            UseCase::Chat,
+            UseCase::Multimodal,
+            UseCase::Embedding,
+        ];
+        for uc in all {
+            assert!(uc.description().len() > uc.label().len());
+        }
+    }
+
     // ────────────────────────────────────────────────────────────────────
     // ModelDatabase tests
     // ────────────────────────────────────────────────────────────────────
@@ -2424,6 +3283,18 @@ mod tests {
         assert!(!models.is_empty());
     }
 
+    #[test]
+    fn test_embedded_catalog_carries_extended_context() {
+        let db = ModelDatabase::embedded();
+        let qwen = db
+            .get_all_models()
+            .iter()
+            .find(|m| m.name == "Qwen/Qwen2.5-7B-Instruct")
+            .expect("Qwen2.5-7B-Instruct in the embedded catalog");
+        assert_eq!(qwen.native_context(), 32_768);
+        assert_eq!(qwen.extended_context(), 131_072);
+    }
+
     #[test]
     fn test_dedupe_hf_entries_merges_duplicate_metadata() {
         let deduped = dedupe_hf_entries(vec![
@@ -2463,6 +3334,8 @@ mod tests {
                 shared_expert_intermediate_size: None,
                 architecture: None,
                 license: Some("apache-2.0".to_string()),
+                trained_context: None,
+                max_context: None,
             },
             // Entry 2: higher params, higher context, ToolUse capability, MoE
             HfModelEntry {
@@ -2500,6 +3373,8 @@ mod tests {
                 shared_expert_intermediate_size: None,
                 architecture: None,
                 license: None,
+                trained_context: None,
+                max_context: None,
             },
         ]);
 
@@ -2579,6 +3454,48 @@ mod tests {
         assert_eq!(results.len(), results_upper.len());
     }
 
+    #[test]
+    fn test_parse_family() {
+        let cases = [
+            ("meta-llama/Llama-3.1-8B-Instruct", "llama-3.1"),
+            ("meta-llama/Llama-3.1-405B", "llama-3.1"),
+            ("unsloth/Meta-Llama-3.1-8B-Instruct", "llama-3.1"),
+            ("Qwen/Qwen2.5-0.5B-Instruct-AWQ", "qwen2.5"),
+            ("Qwen/Qwen3-Coder-30B-A3B-Instruct", "qwen3-coder"),
+            ("deepseek-ai/DeepSeek-R1-Distill-Qwen-7B", "deepseek-r1"),
+            ("google/gemma-3-27b-it", "gemma-3"),
+            ("mistralai/Mixtral-8x7B-Instruct-v0.1", "mixtral"),
+            ("microsoft/Phi-3-mini-4k-instruct", "phi-3"),
+            ("mlx-community/MiniMax-M2.7-3.20bit", "minimax-m2.7"),
+            ("openai/gpt-oss-20b", "gpt-oss"),
+            ("nlpai-lab/KURE-v1", "kure"),
+        ];
+        for (name, family) in cases {
+            assert_eq!(parse_family(name), family, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_get_models_by_family() {
+        let db = ModelDatabase::new();
+        assert!(db.get_all_models().iter().all(|m| !m.family.is_empty()));
+
+        let llama = db.get_models_by_family("Llama-3.1");
+        for name in [
+            "meta-llama/Llama-3.1-8B-Instruct",
+            "meta-llama/Llama-3.1-70B-Instruct",
+        ] {
+            assert!(llama.iter().any(|m| m.name == name), "{name}");
+        }
+        assert!(llama.iter().all(|m| m.family == "llama-3.1"));
+        assert!(db.get_models_by_family("no-such-family").is_empty());
+
+        let families = db.families();
+        assert!(families.windows(2).all(|w| w[0] < w[1]));
+        assert!(families.iter().any(|f| f == "llama-3.1"));
+        assert!(families.iter().any(|f| f == "qwen2.5"));
+    }
+
     #[test]
     fn test_models_fitting_system() {
         let db = ModelDatabase::new();
@@ -2633,6 +3550,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         let caps = Capability::infer(&model);
@@ -2673,11 +3593,23 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         let caps = Capability::infer(&model);
         assert!(caps.contains(&Capability::ToolUse));
+        assert!(caps.contains(&Capability::JsonMode));
         assert!(!caps.contains(&Capability::Vision));
+
+        let model = LlmModel {
+            capabilities: caps,
+            ..model
+        };
+        assert!(model.supports_tools());
+        assert!(model.supports_json_mode());
+        assert!(!model.supports_vision());
     }
 
     #[test]
@@ -2712,6 +3644,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         let caps = Capability::infer(&model);
@@ -2750,6 +3685,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
         let caps = Capability::infer(&model);
@@ -2848,6 +3786,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         };
 
@@ -2966,6 +3907,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         }
     }
@@ -3075,6 +4019,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
             license: None,
         }
     }
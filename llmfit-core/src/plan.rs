@@ -31,8 +31,19 @@ pub struct PlanRequest {
     /// KV cache element representation. Defaults to fp16.
     #[serde(default)]
     pub kv_quant: Option<KvQuant>,
+    /// Model a chat of this many turns, each adding [`TOKENS_PER_TURN`]
+    /// tokens of history on top of `context`.
+    #[serde(default)]
+    pub turns: Option<u32>,
+    /// Concurrent requests, each holding its own KV cache at `context`
+    /// (`--batch-size`). `None` means one.
+    #[serde(default)]
+    pub batch_size: Option<u32>,
 }
 
+/// Tokens of history one chat turn adds: a user message plus the reply.
+pub const TOKENS_PER_TURN: u32 = 512;
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct HardwareEstimate {
     pub vram_gb: Option<f64>,
@@ -109,12 +120,38 @@ pub struct KvQuantAlternative {
     pub supported: bool,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConversationTurn {
+    pub turn: u32,
+    /// Context held after this turn, capped at the model's context window.
+    pub context: u32,
+    pub memory_required_gb: f64,
+}
+
+/// Memory growth over a multi-turn chat, for `PlanRequest::turns`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConversationEstimate {
+    pub turns: u32,
+    pub tokens_per_turn: u32,
+    /// Memory pool of the current run path (VRAM on GPU, RAM otherwise).
+    pub memory_budget_gb: f64,
+    /// Memory after turns 1, 2, 4, 8, ... and the last turn.
+    pub growth: Vec<ConversationTurn>,
+    /// First turn whose accumulated context no longer fits the budget.
+    pub exhausts_memory_at_turn: Option<u32>,
+    /// First turn that fills the model's context window. From there the
+    /// runtime drops old turns and memory stops growing.
+    pub context_full_at_turn: Option<u32>,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct PlanEstimate {
     pub estimate_notice: String,
     pub model_name: String,
     pub provider: String,
     pub context: u32,
+    /// Concurrent requests the memory figures reserve KV cache for.
+    pub batch_size: u32,
     pub quantization: String,
     pub kv_quant: KvQuant,
     pub target_tps: Option<f64>,
@@ -127,6 +164,8 @@ pub struct PlanEstimate {
     /// savings vs the fp16 baseline. Surfaced by phase 5.
     #[serde(default)]
     pub kv_alternatives: Vec<KvQuantAlternative>,
+    /// Set when the request asked for a multi-turn estimate.
+    pub conversation: Option<ConversationEstimate>,
 }
 
 pub fn normalize_quant(quant: &str) -> Option<String> {
@@ -172,6 +211,13 @@ pub fn normalize_quant(quant: &str) -> Option<String> {
     }
 }
 
+/// Canonical `'static` spelling of a quant name accepted by
+/// [`normalize_quant`], e.g. `"q4_k_m"` → `"Q4_K_M"`.
+pub fn supported_quant(quant: &str) -> Option<&'static str> {
+    let normalized = normalize_quant(quant)?;
+    SUPPORTED_QUANTS.iter().copied().find(|q| *q == normalized)
+}
+
 fn estimate_tps(
     model: &LlmModel,
     quant: &str,
@@ -228,6 +274,7 @@ fn estimate_tps_with_gpu(
         GpuBackend::CpuArm => 90.0,
         GpuBackend::CpuX86 => 70.0,
         GpuBackend::Ascend => 390.0,
+        GpuBackend::OpenVino => 60.0,
     };
 
     let mut base = (k / params) * quant_speed_multiplier(quant);
@@ -568,6 +615,9 @@ pub fn estimate_model_plan(
     {
         return Err("--target-tps must be greater than 0".to_string());
     }
+    if request.turns == Some(0) {
+        return Err("--turns must be greater than 0".to_string());
+    }
 
     let quant = if let Some(ref q) = request.quant {
         normalize_quant(q).ok_or_else(|| format!("Unsupported quantization '{}'.", q))?
@@ -591,11 +641,15 @@ pub fn estimate_model_plan(
     }
 
     let context = request.context;
+    // KV cache is linear in tokens: `batch_size` requests at `context` each
+    // cost the same as one request at `kv_context`.
+    let batch_size = request.batch_size.unwrap_or(1).max(1);
+    let kv_context = context.saturating_mul(batch_size);
     let run_paths = vec![
         build_path_estimate(
             model,
             &quant,
-            context,
+            kv_context,
             kv_quant,
             request.target_tps,
             PlanRunPath::Gpu,
@@ -604,7 +658,7 @@ pub fn estimate_model_plan(
         build_path_estimate(
             model,
             &quant,
-            context,
+            kv_context,
             kv_quant,
             request.target_tps,
             PlanRunPath::CpuOffload,
@@ -613,7 +667,7 @@ pub fn estimate_model_plan(
         build_path_estimate(
             model,
             &quant,
-            context,
+            kv_context,
             kv_quant,
             request.target_tps,
             PlanRunPath::CpuOnly,
@@ -621,8 +675,26 @@ pub fn estimate_model_plan(
         ),
     ];
 
-    let current = evaluate_current(model, &quant, context, kv_quant, request.target_tps, system);
-    let kv_alternatives = compute_kv_alternatives(model, &quant, context, system);
+    let current = evaluate_current(
+        model,
+        &quant,
+        kv_context,
+        kv_quant,
+        request.target_tps,
+        system,
+    );
+    let kv_alternatives = compute_kv_alternatives(model, &quant, kv_context, system);
+    let conversation = request.turns.map(|turns| {
+        let budget = if current.run_mode == RunMode::Gpu {
+            system
+                .total_gpu_vram_gb
+                .or(system.gpu_vram_gb)
+                .unwrap_or(0.0)
+        } else {
+            system.available_ram_gb
+        };
+        estimate_conversation(model, &quant, context, kv_quant, turns, budget)
+    });
 
     let preferred = run_paths
         .iter()
@@ -712,6 +784,7 @@ pub fn estimate_model_plan(
         model_name: model.name.clone(),
         provider: model.provider.clone(),
         context,
+        batch_size,
         quantization: quant,
         kv_quant,
         target_tps: request.target_tps,
@@ -721,9 +794,54 @@ pub fn estimate_model_plan(
         current,
         upgrade_deltas,
         kv_alternatives,
+        conversation,
     })
 }
 
+/// Memory needed as a chat accumulates history: turn `n` holds `context`
+/// plus `n - 1` turns of [`TOKENS_PER_TURN`], until the model's context
+/// window is full.
+pub fn estimate_conversation(
+    model: &LlmModel,
+    quant: &str,
+    context: u32,
+    kv_quant: KvQuant,
+    turns: u32,
+    budget_gb: f64,
+) -> ConversationEstimate {
+    let window = model.context_length.max(context);
+    let context_at = |turn: u32| {
+        let history = u64::from(turn - 1) * u64::from(TOKENS_PER_TURN);
+        (u64::from(context) + history).min(u64::from(window)) as u32
+    };
+    let memory_at = |turn: u32| model.estimate_memory_gb_with_kv(quant, context_at(turn), kv_quant);
+
+    let exhausts_memory_at_turn = (1..=turns).find(|&t| memory_at(t) > budget_gb);
+    let context_full_at_turn = (1..=turns).find(|&t| context_at(t) >= window);
+
+    let mut checkpoints: Vec<u32> = std::iter::successors(Some(1u32), |t| t.checked_mul(2))
+        .take_while(|&t| t < turns)
+        .collect();
+    checkpoints.push(turns);
+    let growth = checkpoints
+        .into_iter()
+        .map(|turn| ConversationTurn {
+            turn,
+            context: context_at(turn),
+            memory_required_gb: memory_at(turn),
+        })
+        .collect();
+
+    ConversationEstimate {
+        turns,
+        tokens_per_turn: TOKENS_PER_TURN,
+        memory_budget_gb: budget_gb,
+        growth,
+        exhausts_memory_at_turn,
+        context_full_at_turn,
+    }
+}
+
 /// Build the "what if" KV quant rows for the plan output. Includes every
 /// option, marking unsupported ones (TurboQuant on non CUDA backends) so the
 /// UI can render them with a caveat instead of hiding them.
@@ -868,6 +986,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
         }
     }
 
@@ -888,6 +1009,8 @@ mod tests {
             gpus: vec![],
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
         }
     }
 
@@ -931,6 +1054,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: Some(8.0),
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let plan =
             estimate_model_plan(&test_model(), &req, &test_specs()).expect("plan should build");
@@ -939,6 +1064,34 @@ mod tests {
         assert!(plan.minimum.ram_gb > 0.0);
     }
 
+    #[test]
+    fn test_estimate_model_plan_reserves_kv_per_batched_request() {
+        let req = |batch_size| PlanRequest {
+            context: 8192,
+            quant: Some("Q4_K_M".to_string()),
+            target_tps: None,
+            kv_quant: None,
+            turns: None,
+            batch_size,
+        };
+        let model = test_model();
+        let single = estimate_model_plan(&model, &req(None), &test_specs()).unwrap();
+        let batched = estimate_model_plan(&model, &req(Some(4)), &test_specs()).unwrap();
+        assert_eq!(batched.batch_size, 4);
+        assert_eq!(batched.context, 8192);
+        let kv = model.kv_cache_gb(8192, KvQuant::Fp16);
+        let gpu = |p: &PlanEstimate| {
+            p.run_paths
+                .iter()
+                .find(|r| r.path == PlanRunPath::Gpu)
+                .and_then(|r| r.minimum.as_ref())
+                .and_then(|m| m.vram_gb)
+                .unwrap()
+        };
+        // Three more requests' worth of KV cache.
+        assert!(gpu(&batched) >= gpu(&single) + 3.0 * kv - 0.1);
+    }
+
     #[test]
     fn test_estimate_model_plan_zero_context_errors() {
         let req = PlanRequest {
@@ -946,6 +1099,8 @@ mod tests {
             quant: None,
             target_tps: None,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let result = estimate_model_plan(&test_model(), &req, &test_specs());
         assert!(result.is_err());
@@ -963,6 +1118,8 @@ mod tests {
             quant: None,
             target_tps: Some(-5.0),
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let result = estimate_model_plan(&test_model(), &req, &test_specs());
         assert!(result.is_err());
@@ -980,6 +1137,8 @@ mod tests {
             quant: Some("INVALID_QUANT".to_string()),
             target_tps: None,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let result = estimate_model_plan(&test_model(), &req, &test_specs());
         assert!(result.is_err());
@@ -993,6 +1152,8 @@ mod tests {
             quant: None,
             target_tps: None,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let plan = estimate_model_plan(&test_model(), &req, &test_specs()).unwrap();
         assert_eq!(plan.quantization, "Q4_K_M"); // model default
@@ -1005,6 +1166,8 @@ mod tests {
             quant: None,
             target_tps: None,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let plan = estimate_model_plan(&test_model(), &req, &test_specs()).unwrap();
         assert_eq!(plan.run_paths.len(), 3);
@@ -1020,6 +1183,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let plan = estimate_model_plan(&test_model(), &req, &test_specs()).unwrap();
         let gpu_path = &plan.run_paths[0];
@@ -1354,6 +1519,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let plan = estimate_model_plan(&model, &req, &specs).unwrap();
         assert!(!plan.upgrade_deltas.is_empty());
@@ -1377,6 +1544,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let plan = estimate_model_plan(&test_model(), &req, &test_specs()).unwrap();
         // One row per KvQuant variant
@@ -1392,6 +1561,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
         let mut q4 = base.clone();
         q4.kv_quant = Some(KvQuant::Q4_0);
@@ -1409,6 +1580,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
             kv_quant: Some(KvQuant::TurboQuant),
+            turns: None,
+            batch_size: None,
         };
         let result = estimate_model_plan(&test_model(), &req, &specs);
         assert!(result.is_err());
@@ -1424,6 +1597,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
             kv_quant: Some(KvQuant::TurboQuant),
+            turns: None,
+            batch_size: None,
         };
         let plan = estimate_model_plan(&test_model(), &req, &test_specs())
             .expect("CUDA backend should allow TQ");
@@ -1439,6 +1614,8 @@ mod tests {
             quant: Some("Q4_K_M".to_string()),
             target_tps: None,
             kv_quant: None, // fp16 default, not TQ — so the request itself is fine
+            turns: None,
+            batch_size: None,
         };
         let plan = estimate_model_plan(&test_model(), &req, &specs).unwrap();
         let tq = plan
@@ -1452,4 +1629,51 @@ mod tests {
             "expected vLLM hint in note"
         );
     }
+
+    #[test]
+    fn test_conversation_memory_grows_until_budget_is_exhausted() {
+        let mut specs = test_specs();
+        specs.gpu_vram_gb = Some(6.0);
+        specs.total_gpu_vram_gb = Some(6.0);
+        let req = PlanRequest {
+            context: 4096,
+            quant: Some("Q4_K_M".to_string()),
+            target_tps: None,
+            kv_quant: None,
+            turns: Some(100),
+            batch_size: None,
+        };
+        let plan = estimate_model_plan(&test_model(), &req, &specs).unwrap();
+        assert_eq!(plan.current.run_mode, RunMode::Gpu);
+        let conv = plan.conversation.expect("turns were requested");
+        assert_eq!(conv.memory_budget_gb, 6.0);
+
+        let turns: Vec<u32> = conv.growth.iter().map(|g| g.turn).collect();
+        assert_eq!(turns, [1, 2, 4, 8, 16, 32, 64, 100]);
+        // The first turn is the single-turn estimate and fits...
+        assert_eq!(conv.growth[0].context, 4096);
+        assert!(conv.growth[0].memory_required_gb <= 6.0);
+        // Memory grows with every turn until the window is full, then levels off.
+        let mem: Vec<f64> = conv.growth.iter().map(|g| g.memory_required_gb).collect();
+        assert!(mem[..7].windows(2).all(|w| w[1] > w[0]), "{mem:?}");
+        assert_eq!(mem[6], mem[7]);
+        // ...but the chat outgrows the GPU before the 32k window is full.
+        let exhausted = conv
+            .exhausts_memory_at_turn
+            .expect("chat should run out of VRAM");
+        assert!(
+            (33..57).contains(&exhausted),
+            "exhausted at turn {exhausted}"
+        );
+        assert_eq!(conv.context_full_at_turn, Some(57));
+        assert_eq!(conv.growth.last().unwrap().context, 32768);
+
+        let req = PlanRequest {
+            turns: Some(0),
+            batch_size: None,
+            ..req
+        };
+        let err = estimate_model_plan(&test_model(), &req, &specs).unwrap_err();
+        assert!(err.contains("--turns"));
+    }
 }
@@ -24,6 +24,13 @@ pub trait ModelProvider {
     /// Start pulling a model. Returns immediately; progress is polled
     /// via `pull_progress()`.
     fn start_pull(&self, model_tag: &str) -> Result<PullHandle, String>;
+
+    /// Exact on-disk size (GB) of an installed model, for providers that
+    /// report one. Fits use it in place of the parameter-count estimate
+    /// via [`crate::fit::ModelFit::apply_actual_size`].
+    fn model_size_gb(&self, _model_tag: &str) -> Option<f64> {
+        None
+    }
 }
 
 /// Handle returned by `start_pull`. The TUI polls this in a background
@@ -31,6 +38,39 @@ pub trait ModelProvider {
 pub struct PullHandle {
     pub model_tag: String,
     pub receiver: std::sync::mpsc::Receiver<PullEvent>,
+    /// Bytes transferred so far, for providers that report byte counts
+    /// (currently Ollama). Stays at 0 for providers that only report
+    /// percentages.
+    pub bytes_downloaded: std::sync::Arc<std::sync::atomic::AtomicU64>,
+}
+
+/// How often [`PullHandle::recv_async`] checks the channel while a pull is
+/// quiet. Providers report progress a few times a second at most.
+#[cfg(feature = "tokio")]
+const PULL_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
+#[cfg(feature = "tokio")]
+impl PullHandle {
+    /// Await the next pull event; `None` once the pull worker has finished
+    /// and every event has been read.
+    ///
+    /// `receiver` stays a std channel: providers send on it from plain
+    /// threads, and the TUI and CLI read it with `try_recv`. Switching its
+    /// type with this feature would break those callers as soon as any
+    /// crate in the build enabled it. So this polls the std channel and
+    /// sleeps on the tokio timer in between, which needs no extra thread
+    /// but does need a runtime with the time driver enabled.
+    pub async fn recv_async(&self) -> Option<PullEvent> {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(event) => return Some(event),
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => return None,
+                Err(std::sync::mpsc::TryRecvError::Empty) => {
+                    tokio::time::sleep(PULL_POLL_INTERVAL).await;
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -53,6 +93,26 @@ pub struct OllamaProvider {
     /// Set when using the default `localhost` address so that systems where
     /// `localhost` resolves to `::1` (IPv6) can fall back to `127.0.0.1`.
     fallback_url: Option<String>,
+    /// Shared HTTP agent so repeated calls (the TUI polls `/api/tags` and
+    /// pull status) reuse keep-alive connections instead of reconnecting.
+    agent: ureq::Agent,
+    /// Local models from the last `/api/tags` listing, with size and details.
+    local_models: Vec<OllamaModel>,
+}
+
+/// Idle keep-alive connections kept open to the Ollama server.
+const OLLAMA_MAX_IDLE_CONNECTIONS: usize = 4;
+
+/// Default per-request timeout; individual probes set shorter ones.
+const OLLAMA_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+fn ollama_agent() -> ureq::Agent {
+    ureq::Agent::config_builder()
+        .max_idle_connections(OLLAMA_MAX_IDLE_CONNECTIONS)
+        .max_idle_connections_per_host(OLLAMA_MAX_IDLE_CONNECTIONS)
+        .timeout_global(Some(OLLAMA_TIMEOUT))
+        .build()
+        .into()
 }
 
 fn normalize_ollama_host(raw: &str) -> Option<String> {
@@ -125,6 +185,8 @@ impl Default for OllamaProvider {
             Self {
                 base_url,
                 fallback_url: None,
+                agent: ollama_agent(),
+                local_models: Vec::new(),
             }
         } else {
             // Default: try `localhost` first; fall back to `127.0.0.1` for
@@ -133,6 +195,8 @@ impl Default for OllamaProvider {
             Self {
                 base_url: "http://localhost:11434".to_string(),
                 fallback_url: Some("http://127.0.0.1:11434".to_string()),
+                agent: ollama_agent(),
+                local_models: Vec::new(),
             }
         }
     }
@@ -161,11 +225,13 @@ impl OllamaProvider {
             .header("content-type", "application/json")
             .body(body)
             .map_err(|e| format!("Failed to build request: {}", e))?;
-        let agent: ureq::Agent = ureq::Agent::config_builder()
+        let request = self
+            .agent
+            .configure_request(request)
             .timeout_global(Some(std::time::Duration::from_secs(10)))
-            .build()
-            .into();
-        let resp = agent
+            .build();
+        let resp = self
+            .agent
             .run(request)
             .map_err(|e| format!("Ollama delete request failed: {}", e))?;
         if resp.status() == 200 {
@@ -183,7 +249,9 @@ impl OllamaProvider {
     pub fn detect_with_installed(&mut self) -> (bool, HashSet<String>, usize) {
         let set = HashSet::new();
 
-        let primary_ok = ureq::get(&self.api_url("tags"))
+        let primary_ok = self
+            .agent
+            .get(&self.api_url("tags"))
             .config()
             .timeout_global(Some(std::time::Duration::from_millis(800)))
             .build()
@@ -197,7 +265,9 @@ impl OllamaProvider {
                     return (false, set, 0);
                 };
                 let fallback_url = format!("{}/api/tags", fallback.trim_end_matches('/'));
-                let Ok(r) = ureq::get(&fallback_url)
+                let Ok(r) = self
+                    .agent
+                    .get(&fallback_url)
                     .config()
                     .timeout_global(Some(std::time::Duration::from_millis(800)))
                     .build()
@@ -215,33 +285,56 @@ impl OllamaProvider {
         let Ok(tags): Result<TagsResponse, _> = resp.into_body().read_json() else {
             return (true, set, 0);
         };
-        let (set, count) = build_installed_set(tags.models);
+        let (set, count) = build_installed_set(&tags.models);
+        self.local_models = local_models(tags.models);
         (true, set, count)
     }
 
+    /// Locally installed models with the size and `details` Ollama reported
+    /// at the last [`OllamaProvider::detect_with_installed`] or
+    /// [`OllamaProvider::refresh_installed`] call.
+    pub fn local_models(&self) -> &[OllamaModel] {
+        &self.local_models
+    }
+
     /// Like `installed_models`, but also returns the true model count.
     /// The HashSet may have fewer entries than 2*count due to family-name deduplication,
     /// so `len() / 2` is unreliable for counting models.
     pub fn installed_models_counted(&self) -> (HashSet<String>, usize) {
-        let Ok(resp) = ureq::get(&self.api_url("tags"))
+        match self.fetch_tags() {
+            Some(models) => build_installed_set(&models),
+            None => (HashSet::new(), 0),
+        }
+    }
+
+    /// Like `installed_models_counted`, but also refreshes
+    /// [`OllamaProvider::local_models`].
+    pub fn refresh_installed(&mut self) -> (HashSet<String>, usize) {
+        let models = self.fetch_tags().unwrap_or_default();
+        let (set, count) = build_installed_set(&models);
+        self.local_models = local_models(models);
+        (set, count)
+    }
+
+    fn fetch_tags(&self) -> Option<Vec<OllamaModel>> {
+        let resp = self
+            .agent
+            .get(&self.api_url("tags"))
             .config()
             .timeout_global(Some(std::time::Duration::from_secs(5)))
             .build()
             .call()
-        else {
-            return (HashSet::new(), 0);
-        };
-        let Ok(tags): Result<TagsResponse, _> = resp.into_body().read_json() else {
-            return (HashSet::new(), 0);
-        };
-        build_installed_set(tags.models)
+            .ok()?;
+        let tags: TagsResponse = resp.into_body().read_json().ok()?;
+        Some(tags.models)
     }
 
     /// Best-effort check that a tag exists in Ollama's remote registry.
     /// Uses the local Ollama daemon's `/api/show` resolution path.
     pub fn has_remote_tag(&self, model_tag: &str) -> bool {
         let body = serde_json::json!({ "model": model_tag });
-        ureq::post(&self.api_url("show"))
+        self.agent
+            .post(&self.api_url("show"))
             .config()
             .timeout_global(Some(std::time::Duration::from_millis(1200)))
             .build()
@@ -257,14 +350,35 @@ struct TagsResponse {
     models: Vec<OllamaModel>,
 }
 
-#[derive(serde::Deserialize)]
-struct OllamaModel {
+/// One entry of Ollama's `/api/tags` listing.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+pub struct OllamaModel {
     /// e.g. "llama3.1:8b-instruct-q4_K_M"
-    name: String,
+    pub name: String,
     /// On-disk size in bytes. Cloud-hosted models are served remotely and
     /// report `0` because nothing is stored locally.
     #[serde(default)]
-    size: u64,
+    pub size: u64,
+    #[serde(default)]
+    pub details: OllamaModelDetails,
+}
+
+/// The `details` object Ollama attaches to each installed model. Every field
+/// is optional in practice: older daemons and imported models omit some.
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize)]
+pub struct OllamaModelDetails {
+    /// e.g. "gguf"
+    #[serde(default)]
+    pub format: String,
+    /// e.g. "llama"
+    #[serde(default)]
+    pub family: String,
+    /// e.g. "8.0B"
+    #[serde(default)]
+    pub parameter_size: String,
+    /// e.g. "Q4_K_M"
+    #[serde(default)]
+    pub quantization_level: String,
 }
 
 impl OllamaModel {
@@ -275,6 +389,24 @@ impl OllamaModel {
         let tag = self.name.rsplit(':').next().unwrap_or("");
         tag.ends_with("-cloud") || self.size == 0
     }
+
+    /// On-disk size in GB (10^9 bytes, matching `ollama list`).
+    pub fn size_gb(&self) -> f64 {
+        self.size as f64 / 1e9
+    }
+
+    /// Short "Q4_K_M, 4.9 GB" summary of the installed copy, skipping
+    /// whatever Ollama didn't report.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.details.quantization_level.is_empty() {
+            parts.push(self.details.quantization_level.clone());
+        }
+        if self.size > 0 {
+            parts.push(format!("{:.1} GB", self.size_gb()));
+        }
+        parts.join(", ")
+    }
 }
 
 /// Build the set of installed model name stems from Ollama's tag list, plus the
@@ -282,7 +414,7 @@ impl OllamaModel {
 /// they are not installed locally, and inserting their family stem (e.g.
 /// `qwen3-coder` from `qwen3-coder:480b-cloud`) would falsely mark unrelated
 /// models as installed (#619).
-fn build_installed_set(models: Vec<OllamaModel>) -> (HashSet<String>, usize) {
+fn build_installed_set(models: &[OllamaModel]) -> (HashSet<String>, usize) {
     let mut set = HashSet::new();
     let mut count = 0;
     for m in models {
@@ -299,6 +431,11 @@ fn build_installed_set(models: Vec<OllamaModel>) -> (HashSet<String>, usize) {
     (set, count)
 }
 
+/// Drop cloud-hosted entries, keeping only models stored on this machine.
+fn local_models(models: Vec<OllamaModel>) -> Vec<OllamaModel> {
+    models.into_iter().filter(|m| !m.is_cloud()).collect()
+}
+
 #[derive(serde::Deserialize)]
 struct PullStreamLine {
     #[serde(default)]
@@ -308,21 +445,94 @@ struct PullStreamLine {
     #[serde(default)]
     completed: Option<u64>,
     #[serde(default)]
+    digest: Option<String>,
+    #[serde(default)]
     error: Option<String>,
 }
 
+/// Times an Ollama pull is re-requested after the stream drops mid-download.
+const OLLAMA_PULL_RETRIES: u32 = 3;
+
+/// How an Ollama pull stream ended.
+#[derive(Debug, PartialEq)]
+enum PullStreamEnd {
+    Done,
+    /// Ollama reported an error; retrying won't help.
+    Failed(String),
+    /// The connection dropped after the download had started.
+    Interrupted(String),
+}
+
+/// Forward one Ollama `/api/pull` stream as `PullEvent::Progress` events.
+/// `layer_bytes` holds the bytes completed per layer digest and only grows,
+/// so a resumed pull continues the byte count rather than restarting it.
+fn read_ollama_pull_stream(
+    reader: impl std::io::BufRead,
+    layer_bytes: &mut std::collections::HashMap<String, u64>,
+    bytes_counter: &std::sync::atomic::AtomicU64,
+    tx: &std::sync::mpsc::Sender<PullEvent>,
+) -> PullStreamEnd {
+    let mut started = false;
+    for line in reader.lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return PullStreamEnd::Interrupted(e.to_string()),
+        };
+        if line.is_empty() {
+            continue;
+        }
+        let Ok(parsed) = serde_json::from_str::<PullStreamLine>(&line) else {
+            continue;
+        };
+        // Check for error responses from Ollama
+        if let Some(err) = parsed.error {
+            return PullStreamEnd::Failed(err);
+        }
+        let percent = match (parsed.completed, parsed.total) {
+            (Some(c), Some(t)) if t > 0 => Some(c as f64 / t as f64 * 100.0),
+            _ => None,
+        };
+        if let (Some(digest), Some(completed)) = (parsed.digest, parsed.completed) {
+            started = true;
+            let layer = layer_bytes.entry(digest).or_insert(0);
+            *layer = (*layer).max(completed);
+            bytes_counter.store(
+                layer_bytes.values().sum(),
+                std::sync::atomic::Ordering::Relaxed,
+            );
+        }
+        let _ = tx.send(PullEvent::Progress {
+            status: parsed.status.clone(),
+            percent,
+        });
+        if parsed.status == "success" {
+            return PullStreamEnd::Done;
+        }
+    }
+    if started {
+        PullStreamEnd::Interrupted("stream ended before the download finished".to_string())
+    } else {
+        // Stream ended without "success" — treat as error
+        PullStreamEnd::Failed(
+            "Pull ended without success (model may not exist in Ollama registry)".to_string(),
+        )
+    }
+}
+
 impl ModelProvider for OllamaProvider {
     fn name(&self) -> &str {
         "Ollama"
     }
 
     fn is_available(&self) -> bool {
-        ureq::get(&self.api_url("tags"))
+        // Drain the body: ureq only returns fully-read connections to the pool.
+        self.agent
+            .get(&self.api_url("tags"))
             .config()
             .timeout_global(Some(std::time::Duration::from_secs(2)))
             .build()
             .call()
-            .is_ok()
+            .is_ok_and(|mut resp| resp.body_mut().read_to_vec().is_ok())
     }
 
     fn installed_models(&self) -> HashSet<String> {
@@ -339,60 +549,94 @@ impl ModelProvider for OllamaProvider {
             "model": tag,
             "stream": true,
         });
+        let bytes_downloaded = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+        let bytes_counter = std::sync::Arc::clone(&bytes_downloaded);
+        let agent = self.agent.clone();
 
         std::thread::spawn(move || {
-            let resp = ureq::post(&url)
-                .config()
-                .timeout_global(Some(std::time::Duration::from_secs(3600)))
-                .build()
-                .send_json(&body);
+            // Ollama reports progress per layer; sum the latest count of each.
+            // Kept across reconnects so progress resumes where it stopped.
+            let mut layer_bytes: std::collections::HashMap<String, u64> =
+                std::collections::HashMap::new();
+            let mut attempt = 0;
+            loop {
+                let resp = agent
+                    .post(&url)
+                    .config()
+                    .timeout_global(Some(std::time::Duration::from_secs(3600)))
+                    .build()
+                    .send_json(&body);
 
-            match resp {
-                Ok(resp) => {
-                    let reader = std::io::BufReader::new(resp.into_body().into_reader());
-                    use std::io::BufRead;
-                    for line in reader.lines() {
-                        let Ok(line) = line else { break };
-                        if line.is_empty() {
-                            continue;
-                        }
-                        if let Ok(parsed) = serde_json::from_str::<PullStreamLine>(&line) {
-                            // Check for error responses from Ollama
-                            if let Some(ref err) = parsed.error {
-                                let _ = tx.send(PullEvent::Error(err.clone()));
+                let interrupted = match resp {
+                    Ok(resp) => {
+                        let reader = std::io::BufReader::new(resp.into_body().into_reader());
+                        match read_ollama_pull_stream(reader, &mut layer_bytes, &bytes_counter, &tx)
+                        {
+                            PullStreamEnd::Done => {
+                                let _ = tx.send(PullEvent::Done);
                                 return;
                             }
-                            let percent = match (parsed.completed, parsed.total) {
-                                (Some(c), Some(t)) if t > 0 => Some(c as f64 / t as f64 * 100.0),
-                                _ => None,
-                            };
-                            let _ = tx.send(PullEvent::Progress {
-                                status: parsed.status.clone(),
-                                percent,
-                            });
-                            if parsed.status == "success" {
-                                let _ = tx.send(PullEvent::Done);
+                            PullStreamEnd::Failed(e) => {
+                                let _ = tx.send(PullEvent::Error(e));
                                 return;
                             }
+                            PullStreamEnd::Interrupted(e) => e,
                         }
                     }
-                    // Stream ended without "success" — treat as error
-                    let _ = tx.send(PullEvent::Error(
-                        "Pull ended without success (model may not exist in Ollama registry)"
-                            .to_string(),
-                    ));
-                }
-                Err(e) => {
-                    let _ = tx.send(PullEvent::Error(format!("{e}")));
+                    // A refused reconnect is still part of the same outage.
+                    Err(e) if attempt > 0 => e.to_string(),
+                    Err(e) => {
+                        let _ = tx.send(PullEvent::Error(format!("{e}")));
+                        return;
+                    }
+                };
+
+                attempt += 1;
+                if attempt > OLLAMA_PULL_RETRIES {
+                    let _ = tx.send(PullEvent::Error(format!(
+                        "Pull interrupted after {} retries: {}",
+                        OLLAMA_PULL_RETRIES, interrupted
+                    )));
+                    return;
                 }
+                // Ollama keeps partially downloaded blobs and continues them
+                // when the same pull is requested again.
+                let _ = tx.send(PullEvent::Progress {
+                    status: format!(
+                        "Connection lost, resuming ({}/{})",
+                        attempt, OLLAMA_PULL_RETRIES
+                    ),
+                    percent: None,
+                });
+                std::thread::sleep(std::time::Duration::from_secs(2 * u64::from(attempt)));
             }
         });
 
         Ok(PullHandle {
             model_tag: model_tag.to_string(),
             receiver: rx,
+            bytes_downloaded,
         })
     }
+
+    /// Size from Ollama's `/api/tags` listing: the cached one from the last
+    /// detection or refresh, else a fresh fetch. (`/api/show` doesn't report
+    /// a size.) Tags without a `:` match `:latest`, as in the Ollama CLI.
+    fn model_size_gb(&self, model_tag: &str) -> Option<f64> {
+        let tag = model_tag.to_lowercase();
+        let tag = if tag.contains(':') {
+            tag
+        } else {
+            format!("{tag}:latest")
+        };
+        let size = |models: &[OllamaModel]| {
+            models
+                .iter()
+                .find(|m| !m.is_cloud() && m.name.to_lowercase() == tag)
+                .map(OllamaModel::size_gb)
+        };
+        size(&self.local_models).or_else(|| size(&self.fetch_tags()?))
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -567,13 +811,14 @@ static MLX_PYTHON_AVAILABLE: std::sync::OnceLock<bool> = std::sync::OnceLock::ne
 
 fn check_mlx_python() -> bool {
     *MLX_PYTHON_AVAILABLE.get_or_init(|| {
-        std::process::Command::new("python3")
-            .args(["-c", "import mlx_lm"])
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::null())
-            .status()
-            .map(|s| s.success())
-            .unwrap_or(false)
+        crate::subprocess::status(
+            std::process::Command::new("python3")
+                .args(["-c", "import mlx_lm"])
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null()),
+        )
+        .map(|s| s.success())
+        .unwrap_or(false)
     })
 }
 
@@ -749,8 +994,7 @@ impl ModelProvider for MlxProvider {
     }
 
     fn start_pull(&self, model_tag: &str) -> Result<PullHandle, String> {
-        let repo_id = resolve_mlx_fallback_repo(model_tag, &hf_repo_exists)?;
-        let repo_for_thread = repo_id.clone();
+        let tag_for_thread = model_tag.to_string();
         let (tx, rx) = std::sync::mpsc::channel();
 
         // Resolve the hf binary path before spawning the thread so we can
@@ -761,6 +1005,16 @@ impl ModelProvider for MlxProvider {
         })?;
 
         std::thread::spawn(move || {
+            // Checking a guessed repo is a network round trip, so it runs
+            // here rather than on the caller's (often UI) thread.
+            let repo_for_thread =
+                match resolve_mlx_fallback_repo(&tag_for_thread, &hf_repo_exists_cached) {
+                    Ok(repo) => repo,
+                    Err(e) => {
+                        let _ = tx.send(PullEvent::Error(e));
+                        return;
+                    }
+                };
             let _ = tx.send(PullEvent::Progress {
                 status: format!("Downloading {}...", repo_for_thread),
                 percent: None,
@@ -770,22 +1024,41 @@ impl ModelProvider for MlxProvider {
             // `--` terminates option parsing so a repo id beginning with `-`
             // (reachable via the unauthenticated localhost /api/v1/download
             // endpoint) cannot be misinterpreted as a flag like --local-dir.
-            let result = std::process::Command::new(&hf_bin)
-                .args(["download", "--", &repo_for_thread])
-                .stdout(std::process::Stdio::piped())
-                .stderr(std::process::Stdio::piped())
-                .output();
+            // Progress bars go to stderr, which is read as it arrives.
+            let child = crate::subprocess::spawn(
+                std::process::Command::new(&hf_bin)
+                    .args(["download", "--", &repo_for_thread])
+                    .stdout(std::process::Stdio::null())
+                    .stderr(std::process::Stdio::piped()),
+            );
+            let mut child = match child {
+                Ok(child) => child,
+                Err(e) => {
+                    let _ = tx.send(PullEvent::Error(format!("failed to run hf: {e}")));
+                    return;
+                }
+            };
 
-            match result {
-                Ok(output) if output.status.success() => {
+            let mut messages = Vec::new();
+            let mut progress = HfDownloadProgress::default();
+            if let Some(stderr) = child.stderr.take() {
+                for_each_progress_line(stderr, |line| match progress.update(line) {
+                    Some(event) => {
+                        let _ = tx.send(event);
+                    }
+                    None => messages.push(line.to_string()),
+                });
+            }
+
+            match child.wait() {
+                Ok(status) if status.success() => {
                     let _ = tx.send(PullEvent::Done);
                 }
-                Ok(output) => {
-                    let stderr = String::from_utf8_lossy(&output.stderr);
+                Ok(status) => {
                     let _ = tx.send(PullEvent::Error(format!(
                         "hf download failed (exit {}): {}",
-                        output.status.code().unwrap_or(-1),
-                        stderr.trim()
+                        status.code().unwrap_or(-1),
+                        messages.join("\n")
                     )));
                 }
                 Err(e) => {
@@ -795,8 +1068,9 @@ impl ModelProvider for MlxProvider {
         });
 
         Ok(PullHandle {
-            model_tag: repo_id,
+            model_tag: model_tag.to_string(),
             receiver: rx,
+            bytes_downloaded: Default::default(),
         })
     }
 }
@@ -1259,6 +1533,7 @@ impl LlamaCppProvider {
         Ok(PullHandle {
             model_tag: tag,
             receiver: rx,
+            bytes_downloaded: Default::default(),
         })
     }
 }
@@ -1508,13 +1783,14 @@ fn find_binary(name: &str) -> Option<String> {
 /// health endpoint. Returns `true` if the server responds.
 fn probe_llama_server(base_url: &str) -> bool {
     let url = format!("{}/health", base_url.trim_end_matches('/'));
-    std::process::Command::new("curl")
-        .args(["-sf", "--max-time", "2", &url])
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false)
+    crate::subprocess::status(
+        std::process::Command::new("curl")
+            .args(["-sf", "--max-time", "2", &url])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null()),
+    )
+    .map(|s| s.success())
+    .unwrap_or(false)
 }
 
 /// Simple percent-encoding for URL query parameters.
@@ -1816,11 +2092,12 @@ impl ModelProvider for DockerModelRunnerProvider {
 
             // `--` terminates option parsing so a tag beginning with `-`
             // cannot inject docker CLI flags.
-            let result = std::process::Command::new("docker")
-                .args(["model", "pull", "--", &tag])
-                .stdout(std::process::Stdio::piped())
-                .stderr(std::process::Stdio::piped())
-                .output();
+            let result = crate::subprocess::output(
+                std::process::Command::new("docker")
+                    .args(["model", "pull", "--", &tag])
+                    .stdout(std::process::Stdio::piped())
+                    .stderr(std::process::Stdio::piped()),
+            );
 
             match result {
                 Ok(output) if output.status.success() => {
@@ -1842,6 +2119,7 @@ impl ModelProvider for DockerModelRunnerProvider {
         Ok(PullHandle {
             model_tag: model_tag.to_string(),
             receiver: rx,
+            bytes_downloaded: Default::default(),
         })
     }
 }
@@ -2065,6 +2343,105 @@ fn lmstudio_download_status_percent(st: &LmStudioDownloadStatus) -> Option<f64>
         })
 }
 
+/// Call `f` with each non-empty line of CLI output. Progress bars redraw
+/// themselves with `\r`, so carriage returns end a line too.
+fn for_each_progress_line(reader: impl std::io::Read, mut f: impl FnMut(&str)) {
+    use std::io::BufRead;
+    let mut reader = std::io::BufReader::new(reader);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        // Read up to '\n', then split any '\r' redraws within it.
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {
+                for part in buf.split(|&b| b == b'\r' || b == b'\n') {
+                    let line = String::from_utf8_lossy(part);
+                    let line = line.trim();
+                    if !line.is_empty() {
+                        f(line);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Percent complete from one line of CLI download output, for tools that
+/// print text rather than structured progress (`hf download`, tqdm bars).
+///
+/// Understands an explicit percentage (`45%`, `Fetching 4 files:  50%|█████
+/// | 2/4`) and, failing that, a `done/total` pair such as `3.2G/7.1G` or
+/// `512MiB/2.0GiB`. Rates like `69.1MB/s` are not mistaken for a ratio.
+fn cli_progress_percent(line: &str) -> Option<f64> {
+    // An explicit percentage wins; tqdm puts it before the bar.
+    for (idx, _) in line.match_indices('%') {
+        let head = &line[..idx];
+        let start = head
+            .rfind(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .map_or(0, |i| i + 1);
+        if let Ok(pct) = head[start..].parse::<f64>()
+            && (0.0..=100.0).contains(&pct)
+        {
+            return Some(pct);
+        }
+    }
+
+    line.split(|c: char| c.is_whitespace() || matches!(c, '|' | '[' | ']' | ',' | '(' | ')'))
+        .find_map(|token| {
+            let (done, total) = token.split_once('/')?;
+            let done = parse_progress_amount(done)?;
+            let total = parse_progress_amount(total)?;
+            (total > 0.0 && done <= total).then(|| done / total * 100.0)
+        })
+}
+
+/// Progress across an `hf download` run. The CLI prints an aggregate
+/// `Fetching N files: X%` bar alongside one bar per file; only the aggregate
+/// tracks the whole download, so per-file bars update the status text and
+/// carry the last aggregate percent instead of jumping back to 0%.
+#[derive(Debug, Default)]
+struct HfDownloadProgress {
+    overall: Option<f64>,
+}
+
+impl HfDownloadProgress {
+    /// The event for one line of output, or `None` when it isn't progress.
+    fn update(&mut self, line: &str) -> Option<PullEvent> {
+        let pct = cli_progress_percent(line)?;
+        if line.trim_start().starts_with("Fetching ") {
+            self.overall = Some(pct);
+        }
+        Some(PullEvent::Progress {
+            status: line.to_string(),
+            percent: self.overall,
+        })
+    }
+}
+
+/// A byte or item count as printed in progress output: `7`, `3.2G`,
+/// `7.1GB`, `512MiB`, `980k`.
+fn parse_progress_amount(s: &str) -> Option<f64> {
+    let unit_start = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let value: f64 = s[..unit_start].parse().ok()?;
+    let unit = s[unit_start..].to_ascii_uppercase();
+    let unit = unit
+        .strip_suffix("IB")
+        .or_else(|| unit.strip_suffix('B'))
+        .unwrap_or(&unit);
+    let scale = match unit {
+        "" => 1.0,
+        "K" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        _ => return None,
+    };
+    Some(value * scale)
+}
+
 fn lmstudio_download_terminal_status(status: &str) -> Option<LmStudioDownloadTerminalStatus> {
     match status {
         "completed" | "already_downloaded" => Some(LmStudioDownloadTerminalStatus::Done),
@@ -2413,6 +2790,7 @@ impl ModelProvider for LmStudioProvider {
         Ok(PullHandle {
             model_tag: model_tag.to_string(),
             receiver: rx,
+            bytes_downloaded: Default::default(),
         })
     }
 }
@@ -2969,82 +3347,451 @@ pub fn ramalama_pull_tag(hf_name: &str) -> Option<String> {
 }
 
 // ---------------------------------------------------------------------------
-// Docker Model Runner name-matching helpers
+// Jan / GPT4All providers (filesystem scanners)
 // ---------------------------------------------------------------------------
 
-/// Embedded catalog of HF models confirmed to exist in Docker Hub's ai/ namespace.
-/// Generated by `scripts/scrape_docker_models.py` and refreshed alongside the model DB.
-const DOCKER_MODELS_JSON: &str = include_str!("../data/docker_models.json");
+/// Insert a GGUF file stem (and its quant-stripped base) into an installed
+/// set, matching the shape `is_model_installed_llamacpp` expects.
+fn insert_gguf_stem(set: &mut HashSet<String>, stem: &str) {
+    let lower = stem.to_lowercase();
+    if let Some(base) = strip_gguf_quant_suffix(&lower) {
+        set.insert(base);
+    }
+    set.insert(lower);
+}
 
-#[derive(serde::Deserialize)]
-struct DockerModelCatalog {
-    models: Vec<DockerModelEntry>,
+/// Stem of the last path segment of a URL or path ending in `.gguf`.
+fn gguf_stem_of(path: &str) -> Option<&str> {
+    path.rsplit(['/', '\\'])
+        .next()?
+        .strip_suffix(".gguf")
+        .filter(|stem| !stem.is_empty())
 }
 
-#[derive(serde::Deserialize)]
-struct DockerModelEntry {
-    hf_name: String,
-    docker_tag: String,
+/// Jan desktop app (jan.ai). Models live in the Jan data folder, one
+/// directory per model: `models/<id>/model.json` in the legacy layout and
+/// `llamacpp/models/<id>/model.yml` since Jan 0.6.
+///
+/// Jan has no pull API llmfit can drive; `start_pull` returns an
+/// informational error pointing at Jan's model hub.
+pub struct JanProvider {
+    data_dirs: Vec<PathBuf>,
 }
 
-/// Lazily parsed Docker Model Runner catalog.
-fn docker_mr_catalog() -> &'static [(String, String)] {
-    use std::sync::OnceLock;
-    static CATALOG: OnceLock<Vec<(String, String)>> = OnceLock::new();
-    CATALOG.get_or_init(|| {
-        let Ok(catalog) = serde_json::from_str::<DockerModelCatalog>(DOCKER_MODELS_JSON) else {
-            return Vec::new();
+impl Default for JanProvider {
+    fn default() -> Self {
+        let data_dirs = if let Ok(dir) = std::env::var("JAN_DATA_FOLDER") {
+            vec![PathBuf::from(dir)]
+        } else {
+            let mut dirs = Vec::new();
+            if let Some(home) = dirs::home_dir() {
+                dirs.push(home.join("jan"));
+            }
+            if let Some(data) = dirs::data_dir() {
+                dirs.push(data.join("Jan").join("data"));
+            }
+            dirs
         };
-        catalog
-            .models
-            .into_iter()
-            .map(|e| (e.hf_name.to_lowercase(), e.docker_tag))
-            .collect()
-    })
+        Self { data_dirs }
+    }
 }
 
-/// Returns `true` if this HF model has a confirmed Docker Model Runner image.
-pub fn has_docker_mr_mapping(hf_name: &str) -> bool {
-    docker_mr_pull_tag(hf_name).is_some()
+impl JanProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn model_dirs(&self) -> Vec<PathBuf> {
+        let mut out = Vec::new();
+        for data in &self.data_dirs {
+            for models in [data.join("models"), data.join("llamacpp").join("models")] {
+                if let Ok(entries) = std::fs::read_dir(models) {
+                    out.extend(entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()));
+                }
+            }
+        }
+        out
+    }
+
+    /// Returns `(installed_models, count)`, where count is the number of
+    /// model directories found.
+    pub fn installed_models_counted(&self) -> (HashSet<String>, usize) {
+        let mut set = HashSet::new();
+        let dirs = self.model_dirs();
+        for dir in &dirs {
+            if let Some(id) = dir.file_name().and_then(|n| n.to_str()) {
+                insert_gguf_stem(&mut set, id);
+            }
+            let names = if let Ok(json) = std::fs::read_to_string(dir.join("model.json")) {
+                parse_jan_model_json(&json)
+            } else if let Ok(yml) = std::fs::read_to_string(dir.join("model.yml")) {
+                parse_jan_model_yml(&yml)
+            } else {
+                Vec::new()
+            };
+            for name in names {
+                insert_gguf_stem(&mut set, &name);
+            }
+        }
+        (set, dirs.len())
+    }
 }
 
-/// Given an HF model name, return the Docker Model Runner tag to use for pulling.
-/// Returns `None` if the model has no confirmed Docker image.
-pub fn docker_mr_pull_tag(hf_name: &str) -> Option<String> {
-    let lower = hf_name.to_lowercase();
-    docker_mr_catalog()
-        .iter()
-        .find(|(name, _)| *name == lower)
-        .map(|(_, tag)| tag.clone())
+#[derive(serde::Deserialize)]
+struct JanModelJson {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    sources: Vec<JanModelSource>,
 }
 
-/// Docker Model Runner uses the Ollama naming convention (e.g. "ai/llama3.1:8b").
-/// We generate candidates from the confirmed catalog, plus base-name variants for
-/// matching against locally installed models.
-pub fn hf_name_to_docker_mr_candidates(hf_name: &str) -> Vec<String> {
-    let Some(tag) = docker_mr_pull_tag(hf_name) else {
+#[derive(serde::Deserialize)]
+struct JanModelSource {
+    #[serde(default)]
+    filename: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
+}
+
+/// Model names from a legacy Jan `model.json`: the model id plus the GGUF
+/// file stem and HF repo name of each download source.
+fn parse_jan_model_json(contents: &str) -> Vec<String> {
+    let Ok(manifest) = serde_json::from_str::<JanModelJson>(contents) else {
         return Vec::new();
     };
-    let mut candidates = vec![tag.clone()];
-    // Also add without "ai/" prefix for matching installed models
-    if let Some(stripped) = tag.strip_prefix("ai/") {
-        candidates.push(stripped.to_string());
+    let mut names: Vec<String> = manifest.id.into_iter().collect();
+    for source in manifest.sources {
+        if let Some(stem) = source.filename.as_deref().and_then(gguf_stem_of) {
+            names.push(stem.to_string());
+        }
+        let Some(url) = source.url else { continue };
+        if let Some(stem) = gguf_stem_of(&url) {
+            names.push(stem.to_string());
+        }
+        // https://huggingface.co/<owner>/<repo>/resolve/main/<file>.gguf
+        if let Some(rest) = url.split("huggingface.co/").nth(1)
+            && let Some(repo) = rest.split('/').nth(1)
+        {
+            let repo = repo
+                .strip_suffix("-GGUF")
+                .or_else(|| repo.strip_suffix("-gguf"))
+                .unwrap_or(repo);
+            names.push(repo.to_string());
+        }
     }
-    // Add base repo name (without size tag) e.g. "ai/llama3.1"
-    if let Some(base) = tag.split(':').next() {
-        candidates.push(base.to_string());
+    names
+}
+
+/// Model names from a Jan 0.6+ `model.yml`. Only the flat `model_path` and
+/// `name` keys are needed, so this reads `key: value` lines rather than
+/// pulling in a YAML parser.
+fn parse_jan_model_yml(contents: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim().trim_matches(['"', '\'']);
+        match key.trim() {
+            "model_path" => {
+                if let Some(stem) = gguf_stem_of(value) {
+                    names.push(stem.to_string());
+                }
+            }
+            "name" if !value.is_empty() => names.push(value.to_string()),
+            _ => {}
+        }
     }
-    candidates
+    names
 }
 
-/// Check if any of the Docker Model Runner candidates for an HF model
-/// appear in the installed set.
-pub fn is_model_installed_docker_mr(hf_name: &str, installed: &HashSet<String>) -> bool {
-    let candidates = hf_name_to_docker_mr_candidates(hf_name);
-    candidates.iter().any(|candidate| {
-        installed
-            .iter()
-            .any(|installed_name| docker_mr_installed_matches(installed_name, candidate))
+impl ModelProvider for JanProvider {
+    fn name(&self) -> &str {
+        "Jan"
+    }
+
+    fn is_available(&self) -> bool {
+        self.data_dirs.iter().any(|d| d.is_dir())
+    }
+
+    fn installed_models(&self) -> HashSet<String> {
+        self.installed_models_counted().0
+    }
+
+    fn start_pull(&self, _model_tag: &str) -> Result<PullHandle, String> {
+        Err("Jan does not expose a download API. \
+             Download the model from Jan's Hub instead."
+            .to_string())
+    }
+}
+
+/// GPT4All desktop app (nomic.ai). Models are flat `.gguf` files in the
+/// download folder, which defaults to `<local data>/nomic.ai/GPT4All` and can
+/// be moved via `modelPath` in `GPT4All.ini`.
+///
+/// Like Jan, GPT4All has no pull API llmfit can drive.
+pub struct Gpt4AllProvider {
+    models_dir: PathBuf,
+}
+
+impl Default for Gpt4AllProvider {
+    fn default() -> Self {
+        let configured = dirs::config_dir()
+            .and_then(|dir| std::fs::read_to_string(dir.join("nomic.ai").join("GPT4All.ini")).ok())
+            .and_then(|ini| parse_gpt4all_ini_model_path(&ini));
+        let models_dir = configured.unwrap_or_else(|| {
+            dirs::data_local_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("nomic.ai")
+                .join("GPT4All")
+        });
+        Self { models_dir }
+    }
+}
+
+impl Gpt4AllProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `(installed_models, gguf_file_count)`.
+    pub fn installed_models_counted(&self) -> (HashSet<String>, usize) {
+        let mut set = HashSet::new();
+        let mut count = 0;
+        if let Ok(entries) = std::fs::read_dir(&self.models_dir) {
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                // Partial downloads are written as "incomplete-<file>.gguf".
+                if let Some(stem) = name.to_str().and_then(gguf_stem_of)
+                    && !stem.starts_with("incomplete-")
+                {
+                    count += 1;
+                    insert_gguf_stem(&mut set, stem);
+                }
+            }
+        }
+        (set, count)
+    }
+}
+
+/// The `modelPath` setting from `GPT4All.ini` (Qt settings format), if set.
+fn parse_gpt4all_ini_model_path(contents: &str) -> Option<PathBuf> {
+    contents.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        let value = value.trim().trim_matches('"');
+        (key.trim() == "modelPath" && !value.is_empty()).then(|| PathBuf::from(value))
+    })
+}
+
+impl ModelProvider for Gpt4AllProvider {
+    fn name(&self) -> &str {
+        "GPT4All"
+    }
+
+    fn is_available(&self) -> bool {
+        self.models_dir.is_dir()
+    }
+
+    fn installed_models(&self) -> HashSet<String> {
+        self.installed_models_counted().0
+    }
+
+    fn start_pull(&self, _model_tag: &str) -> Result<PullHandle, String> {
+        Err("GPT4All does not expose a download API. \
+             Download the model from GPT4All's Models page instead."
+            .to_string())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Groq cloud provider
+// ---------------------------------------------------------------------------
+
+/// Groq's OpenAI-compatible model list.
+pub const GROQ_MODELS_URL: &str = "https://api.groq.com/openai/v1/models";
+
+/// Groq — hosted inference for models too big to run locally.
+///
+/// Enabled when `GROQ_API_KEY` is set; the served models come from
+/// [`GROQ_MODELS_URL`]. Cloud models run remotely, so nothing is ever
+/// installed and `start_pull` completes immediately.
+pub struct GroqProvider {
+    api_key: Option<String>,
+}
+
+impl Default for GroqProvider {
+    fn default() -> Self {
+        let api_key = std::env::var("GROQ_API_KEY")
+            .ok()
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty());
+        Self { api_key }
+    }
+}
+
+impl GroqProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `GROQ_API_KEY` is set. Without it Groq is never probed.
+    pub fn has_api_key(&self) -> bool {
+        self.api_key.is_some()
+    }
+
+    /// Single-pass startup probe.
+    /// Returns `(available, model_ids)`; unavailable without an API key.
+    pub fn detect_with_models(&self) -> (bool, Vec<String>) {
+        let Some(ref key) = self.api_key else {
+            return (false, Vec::new());
+        };
+        let Ok(resp) = ureq::get(GROQ_MODELS_URL)
+            .config()
+            .timeout_global(Some(std::time::Duration::from_secs(3)))
+            .build()
+            .header("Authorization", &format!("Bearer {}", key))
+            .call()
+        else {
+            return (false, Vec::new());
+        };
+        match resp.into_body().read_json::<OpenAiModelList>() {
+            Ok(list) => (true, groq_model_ids(list)),
+            Err(_) => (false, Vec::new()),
+        }
+    }
+}
+
+/// Sorted, de-duplicated model ids from a Groq `/models` response.
+fn groq_model_ids(list: OpenAiModelList) -> Vec<String> {
+    let mut ids: Vec<String> = list.data.into_iter().map(|m| m.id).collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+impl ModelProvider for GroqProvider {
+    fn name(&self) -> &str {
+        "Groq"
+    }
+
+    fn is_available(&self) -> bool {
+        self.detect_with_models().0
+    }
+
+    fn installed_models(&self) -> HashSet<String> {
+        HashSet::new()
+    }
+
+    fn start_pull(&self, model_tag: &str) -> Result<PullHandle, String> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let _ = tx.send(PullEvent::Done);
+        Ok(PullHandle {
+            model_tag: model_tag.to_string(),
+            receiver: rx,
+            bytes_downloaded: std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0)),
+        })
+    }
+}
+
+/// Comparable stem of a model id: the repo part, lowercased, without
+/// Groq's serving suffixes (`-versatile`, `-instant`) or `-instruct`/`-chat`.
+fn groq_match_stem(id: &str) -> String {
+    let mut stem = id.rsplit('/').next().unwrap_or(id).to_lowercase();
+    for suffix in ["-versatile", "-instant", "-instruct", "-chat", "-it"] {
+        if let Some(stripped) = stem.strip_suffix(suffix) {
+            stem = stripped.to_string();
+        }
+    }
+    stem
+}
+
+/// The Groq model id serving an HF model, if any, e.g.
+/// `meta-llama/Llama-3.3-70B-Instruct` → `llama-3.3-70b-versatile`.
+pub fn groq_model_for<'a>(hf_name: &str, groq_models: &'a [String]) -> Option<&'a str> {
+    let stem = groq_match_stem(hf_name);
+    groq_models
+        .iter()
+        .find(|id| groq_match_stem(id) == stem)
+        .map(String::as_str)
+}
+
+// ---------------------------------------------------------------------------
+// Docker Model Runner name-matching helpers
+// ---------------------------------------------------------------------------
+
+/// Embedded catalog of HF models confirmed to exist in Docker Hub's ai/ namespace.
+/// Generated by `scripts/scrape_docker_models.py` and refreshed alongside the model DB.
+const DOCKER_MODELS_JSON: &str = include_str!("../data/docker_models.json");
+
+#[derive(serde::Deserialize)]
+struct DockerModelCatalog {
+    models: Vec<DockerModelEntry>,
+}
+
+#[derive(serde::Deserialize)]
+struct DockerModelEntry {
+    hf_name: String,
+    docker_tag: String,
+}
+
+/// Lazily parsed Docker Model Runner catalog.
+fn docker_mr_catalog() -> &'static [(String, String)] {
+    use std::sync::OnceLock;
+    static CATALOG: OnceLock<Vec<(String, String)>> = OnceLock::new();
+    CATALOG.get_or_init(|| {
+        let Ok(catalog) = serde_json::from_str::<DockerModelCatalog>(DOCKER_MODELS_JSON) else {
+            return Vec::new();
+        };
+        catalog
+            .models
+            .into_iter()
+            .map(|e| (e.hf_name.to_lowercase(), e.docker_tag))
+            .collect()
+    })
+}
+
+/// Returns `true` if this HF model has a confirmed Docker Model Runner image.
+pub fn has_docker_mr_mapping(hf_name: &str) -> bool {
+    docker_mr_pull_tag(hf_name).is_some()
+}
+
+/// Given an HF model name, return the Docker Model Runner tag to use for pulling.
+/// Returns `None` if the model has no confirmed Docker image.
+pub fn docker_mr_pull_tag(hf_name: &str) -> Option<String> {
+    let lower = hf_name.to_lowercase();
+    docker_mr_catalog()
+        .iter()
+        .find(|(name, _)| *name == lower)
+        .map(|(_, tag)| tag.clone())
+}
+
+/// Docker Model Runner uses the Ollama naming convention (e.g. "ai/llama3.1:8b").
+/// We generate candidates from the confirmed catalog, plus base-name variants for
+/// matching against locally installed models.
+pub fn hf_name_to_docker_mr_candidates(hf_name: &str) -> Vec<String> {
+    let Some(tag) = docker_mr_pull_tag(hf_name) else {
+        return Vec::new();
+    };
+    let mut candidates = vec![tag.clone()];
+    // Also add without "ai/" prefix for matching installed models
+    if let Some(stripped) = tag.strip_prefix("ai/") {
+        candidates.push(stripped.to_string());
+    }
+    // Add base repo name (without size tag) e.g. "ai/llama3.1"
+    if let Some(base) = tag.split(':').next() {
+        candidates.push(base.to_string());
+    }
+    candidates
+}
+
+/// Check if any of the Docker Model Runner candidates for an HF model
+/// appear in the installed set.
+pub fn is_model_installed_docker_mr(hf_name: &str, installed: &HashSet<String>) -> bool {
+    let candidates = hf_name_to_docker_mr_candidates(hf_name);
+    candidates.iter().any(|candidate| {
+        installed
+            .iter()
+            .any(|installed_name| docker_mr_installed_matches(installed_name, candidate))
     })
 }
 
@@ -3294,6 +4041,50 @@ pub fn hf_repo_exists(repo_id: &str) -> bool {
         .is_ok()
 }
 
+/// Hugging Face token from `HF_TOKEN` or `HUGGING_FACE_HUB_TOKEN`, if set.
+fn hf_token() -> Option<String> {
+    std::env::var("HF_TOKEN")
+        .or_else(|_| std::env::var("HUGGING_FACE_HUB_TOKEN"))
+        .ok()
+        .filter(|t| !t.trim().is_empty())
+}
+
+/// `Some(exists)` when Hugging Face answered definitively, `None` when it
+/// could not be reached or the answer is ambiguous.
+///
+/// Sends a HEAD for the repo's `config.json`, which every MLX repo ships.
+/// Anonymous requests for a missing repo get a 401 rather than a 404, so
+/// both count as missing. With a token configured, a 401 means the token
+/// was rejected, which says nothing about the repo.
+fn hf_repo_status(repo_id: &str) -> Option<bool> {
+    let url = format!(
+        "https://huggingface.co/{}/resolve/main/config.json",
+        repo_id
+    );
+    let token = hf_token();
+    let mut req = ureq::head(&url)
+        .config()
+        .timeout_global(Some(std::time::Duration::from_millis(1200)))
+        .build();
+    if let Some(token) = &token {
+        req = req.header("Authorization", &format!("Bearer {}", token));
+    }
+    match req.call() {
+        Ok(_) => Some(true),
+        Err(ureq::Error::StatusCode(code)) => hf_status_means_missing(code, token.is_some()),
+        Err(_) => None,
+    }
+}
+
+/// Map an error status from [`hf_repo_status`]'s probe to an answer.
+fn hf_status_means_missing(code: u16, authenticated: bool) -> Option<bool> {
+    match code {
+        404 => Some(false),
+        401 if !authenticated => Some(false),
+        _ => None,
+    }
+}
+
 /// Resolve the first GGUF repo that appears to exist remotely.
 pub fn first_existing_gguf_repo(hf_name: &str) -> Option<String> {
     if let Some(repo) = gguf_pull_tag(hf_name)
@@ -3545,6 +4336,85 @@ pub fn mlx_pull_tag(hf_name: &str) -> String {
         })
 }
 
+/// How long a Hugging Face repo existence check stays cached.
+const HF_VALIDATION_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Cached Hugging Face existence checks, keyed by repo id. Persisted at
+/// `~/.cache/llmfit/hf_validation.json`.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct HfValidationCache {
+    #[serde(default)]
+    repos: std::collections::HashMap<String, HfValidationEntry>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+struct HfValidationEntry {
+    exists: bool,
+    /// Unix seconds when the check ran.
+    checked_at: u64,
+}
+
+impl HfValidationCache {
+    fn path() -> Option<PathBuf> {
+        Some(dirs::cache_dir()?.join("llmfit").join("hf_validation.json"))
+    }
+
+    fn load() -> Self {
+        Self::path()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) {
+        let Some(path) = Self::path() else { return };
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        if let Ok(json) = serde_json::to_string_pretty(self) {
+            let _ = std::fs::write(path, json);
+        }
+    }
+
+    /// The cached answer for `repo_id`, if checked within the TTL.
+    fn lookup(&self, repo_id: &str, now: u64) -> Option<bool> {
+        self.repos
+            .get(repo_id)
+            .filter(|e| now.saturating_sub(e.checked_at) < HF_VALIDATION_TTL_SECS)
+            .map(|e| e.exists)
+    }
+
+    fn record(&mut self, repo_id: &str, exists: bool, now: u64) {
+        self.repos.insert(
+            repo_id.to_string(),
+            HfValidationEntry {
+                exists,
+                checked_at: now,
+            },
+        );
+    }
+}
+
+/// [`hf_repo_status`] behind a cache of definite answers, so repeated MLX
+/// pulls of a guessed repo don't re-probe Hugging Face. An unreachable
+/// Hugging Face is not cached and counts as missing.
+fn hf_repo_exists_cached(repo_id: &str) -> bool {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut cache = HfValidationCache::load();
+    if let Some(exists) = cache.lookup(repo_id, now) {
+        return exists;
+    }
+    let Some(exists) = hf_repo_status(repo_id) else {
+        return false;
+    };
+    cache.record(repo_id, exists, now);
+    cache.save();
+    exists
+}
+
 /// Resolve the repo id an MLX pull should download, guarding the
 /// `mlx-community/{tag}` fallback (issue #294).
 ///
@@ -3578,8 +4448,8 @@ fn resolve_mlx_fallback_repo(
     let candidate = format!("mlx-community/{model_tag}");
     if !repo_exists(&candidate) {
         return Err(format!(
-            "No MLX build found: {candidate} does not exist on Hugging Face (or could not \
-             be reached). If an MLX build exists under a different name, pass its full \
+            "MLX variant not found on HuggingFace: {candidate} does not exist (or could \
+             not be reached). If an MLX build exists under a different name, pass its full \
              repo id (owner/name)."
         ));
     }
@@ -3850,6 +4720,10 @@ pub fn has_ollama_mapping(hf_name: &str) -> bool {
 }
 
 fn ollama_installed_matches_candidate(installed_name: &str, candidate: &str) -> bool {
+    // `ollama pull llama3` is stored as `llama3:latest`; the two are the
+    // same model on either side of the comparison.
+    let installed_name = strip_latest_tag(installed_name);
+    let candidate = strip_latest_tag(candidate);
     if installed_name == candidate {
         return true;
     }
@@ -3861,7 +4735,19 @@ fn ollama_installed_matches_candidate(installed_name: &str, candidate: &str) ->
         return installed_name.starts_with(&format!("{candidate}-"));
     }
 
-    false
+    // A bare candidate ("llama3") is also installed under the library's
+    // standard variant tags ("llama3:instruct", "llama3:text", ...).
+    installed_name
+        .split_once(':')
+        .is_some_and(|(family, tag)| family == candidate && OLLAMA_ALIAS_TAGS.contains(&tag))
+}
+
+/// Tags the Ollama library publishes for a model's standard variants, which
+/// `ollama list` reports in place of a bare name.
+const OLLAMA_ALIAS_TAGS: &[&str] = &["instruct", "chat", "text"];
+
+fn strip_latest_tag(name: &str) -> &str {
+    name.strip_suffix(":latest").unwrap_or(name)
 }
 
 /// Check if any of the Ollama candidates for an HF model appear in the
@@ -3881,6 +4767,49 @@ pub fn is_model_installed(hf_name: &str, installed: &HashSet<String>) -> bool {
     })
 }
 
+/// The locally installed Ollama copy of an HF model, if any, using the same
+/// matching as [`is_model_installed`].
+pub fn find_ollama_model<'a>(hf_name: &str, models: &'a [OllamaModel]) -> Option<&'a OllamaModel> {
+    let candidates = hf_name_to_ollama_candidates(hf_name);
+    models.iter().find(|m| {
+        let name = m.name.to_lowercase();
+        let stem = name.split(':').next().unwrap_or(&name);
+        candidates.iter().any(|candidate| {
+            ollama_installed_matches_candidate(&name, candidate)
+                || ollama_installed_matches_candidate(stem, candidate)
+        })
+    })
+}
+
+/// Installed Ollama tags that no catalog model maps back to: custom
+/// Modelfiles, community uploads, models newer than the catalog. They count
+/// towards Ollama's installed total but never show up as an installed row,
+/// so callers list them separately. Uses the same matching as
+/// [`find_ollama_model`]; returns lowercased tags, sorted and deduplicated.
+pub fn ollama_orphan_tags<'a, 'b>(
+    installed_tags: impl IntoIterator<Item = &'a str>,
+    catalog_names: impl IntoIterator<Item = &'b str>,
+) -> Vec<String> {
+    let candidates: HashSet<String> = catalog_names
+        .into_iter()
+        .flat_map(hf_name_to_ollama_candidates)
+        .collect();
+    let mut orphans: Vec<String> = installed_tags
+        .into_iter()
+        .map(str::to_lowercase)
+        .filter(|name| {
+            let stem = name.split(':').next().unwrap_or(name);
+            !candidates.iter().any(|candidate| {
+                ollama_installed_matches_candidate(name, candidate)
+                    || ollama_installed_matches_candidate(stem, candidate)
+            })
+        })
+        .collect();
+    orphans.sort();
+    orphans.dedup();
+    orphans
+}
+
 /// Given an HF model name, return the Ollama tag to use for pulling.
 /// Returns `None` if the model has no known Ollama mapping.
 pub fn ollama_pull_tag(hf_name: &str) -> Option<String> {
@@ -3922,6 +4851,114 @@ pub fn tag_matches_model(tag: &str, hf_name: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn recv_async_yields_pull_events_until_the_worker_finishes() {
+        let handle = GroqProvider { api_key: None }
+            .start_pull("llama-3.3-70b-versatile")
+            .unwrap();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            assert!(matches!(handle.recv_async().await, Some(PullEvent::Done)));
+            assert!(handle.recv_async().await.is_none());
+        });
+    }
+
+    #[test]
+    fn ollama_pull_resume_keeps_byte_progress_across_reconnects() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut layers = std::collections::HashMap::new();
+        let bytes = AtomicU64::new(0);
+
+        // The first connection drops halfway through the blob.
+        let first = concat!(
+            "{\"status\":\"pulling manifest\"}\n",
+            "{\"status\":\"pulling abc\",\"digest\":\"sha256:abc\",\"total\":1000,\"completed\":200}\n",
+            "{\"status\":\"pulling abc\",\"digest\":\"sha256:abc\",\"total\":1000,\"completed\":500}\n",
+        );
+        assert!(matches!(
+            read_ollama_pull_stream(first.as_bytes(), &mut layers, &bytes, &tx),
+            PullStreamEnd::Interrupted(_)
+        ));
+        assert_eq!(bytes.load(Ordering::Relaxed), 500);
+
+        // The resumed pull restarts its report below the known offset; the
+        // byte count holds at 500 rather than dropping back.
+        let second = concat!(
+            "{\"status\":\"pulling abc\",\"digest\":\"sha256:abc\",\"total\":1000,\"completed\":0}\n",
+            "{\"status\":\"pulling abc\",\"digest\":\"sha256:abc\",\"total\":1000,\"completed\":1000}\n",
+            "{\"status\":\"success\"}\n",
+        );
+        assert_eq!(
+            read_ollama_pull_stream(second.as_bytes(), &mut layers, &bytes, &tx),
+            PullStreamEnd::Done
+        );
+        assert_eq!(bytes.load(Ordering::Relaxed), 1000);
+        let last_percent = rx
+            .try_iter()
+            .filter_map(|e| match e {
+                PullEvent::Progress { percent, .. } => percent,
+                _ => None,
+            })
+            .last();
+        assert_eq!(last_percent, Some(100.0));
+
+        // A stream that never started downloading isn't retried.
+        let missing = "{\"status\":\"pulling manifest\"}\n";
+        assert!(matches!(
+            read_ollama_pull_stream(missing.as_bytes(), &mut layers, &bytes, &tx),
+            PullStreamEnd::Failed(_)
+        ));
+        let error = "{\"error\":\"pull model manifest: file does not exist\"}\n";
+        assert_eq!(
+            read_ollama_pull_stream(error.as_bytes(), &mut layers, &bytes, &tx),
+            PullStreamEnd::Failed("pull model manifest: file does not exist".to_string())
+        );
+    }
+
+    #[test]
+    fn groq_lists_cloud_models_and_matches_too_tight_ones() {
+        let list: OpenAiModelList = serde_json::from_str(
+            r#"{"data":[
+                {"id":"llama-3.3-70b-versatile","owned_by":"Meta"},
+                {"id":"qwen/qwen3-32b","owned_by":"Alibaba Cloud"},
+                {"id":"llama-3.1-8b-instant","owned_by":"Meta"},
+                {"id":"qwen/qwen3-32b","owned_by":"Alibaba Cloud"}
+            ]}"#,
+        )
+        .unwrap();
+        let ids = groq_model_ids(list);
+        assert_eq!(
+            ids,
+            vec![
+                "llama-3.1-8b-instant",
+                "llama-3.3-70b-versatile",
+                "qwen/qwen3-32b"
+            ]
+        );
+
+        assert_eq!(
+            groq_model_for("meta-llama/Llama-3.3-70B-Instruct", &ids),
+            Some("llama-3.3-70b-versatile")
+        );
+        assert_eq!(
+            groq_model_for("Qwen/Qwen3-32B", &ids),
+            Some("qwen/qwen3-32b")
+        );
+        assert_eq!(groq_model_for("Qwen/Qwen3-14B", &ids), None);
+
+        // Cloud models need no download: the pull finishes immediately.
+        let groq = GroqProvider { api_key: None };
+        assert!(!groq.is_available());
+        assert!(groq.installed_models().is_empty());
+        let handle = groq.start_pull("llama-3.3-70b-versatile").unwrap();
+        assert!(matches!(handle.receiver.try_recv(), Ok(PullEvent::Done)));
+    }
+
     // Install layouts from issue #731 (Windows, LM Studio + Docker Desktop
     // installed but their servers not running) must be recognized. Expected
     // paths are built with join() so separators stay portable across the
@@ -4246,6 +5283,95 @@ mod tests {
         assert_eq!(lmstudio_response_job_id(&without_job), None);
     }
 
+    #[test]
+    fn cli_progress_percent_reads_common_formats() {
+        let cases = [
+            ("45%", Some(45.0)),
+            ("Downloading: 12.5% complete", Some(12.5)),
+            (
+                "Fetching 4 files:  50%|█████     | 2/4 [00:03<00:03,  1.52s/it]",
+                Some(50.0),
+            ),
+            (
+                "model.safetensors:  45%|████▌     | 2.13G/4.72G [00:31<00:37, 69.1MB/s]",
+                Some(45.0),
+            ),
+            ("3.2G/7.1G", Some(3.2 / 7.1 * 100.0)),
+            ("downloaded 512MiB/2.0GiB", Some(25.6)),
+            ("Fetching 7 files: 3/7", Some(3.0 / 7.0 * 100.0)),
+            (
+                "config.json: 100%|██████████| 1.05k/1.05k [00:00<00:00, 4.2MB/s]",
+                Some(100.0),
+            ),
+            // Rates and paths are not progress.
+            ("69.1MB/s", None),
+            (
+                "/Users/me/.cache/huggingface/hub/models--mlx-community--x",
+                None,
+            ),
+            (
+                "Repository Not Found for url: https://huggingface.co/api/models/x",
+                None,
+            ),
+            ("", None),
+        ];
+        for (line, expected) in cases {
+            let got = cli_progress_percent(line);
+            match (got, expected) {
+                (Some(g), Some(e)) => assert!((g - e).abs() < 0.01, "{line:?}: {g} != {e}"),
+                _ => assert_eq!(got, expected, "{line:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn hf_download_percent_follows_the_aggregate_bar() {
+        let mut progress = HfDownloadProgress::default();
+        let percent = |progress: &mut HfDownloadProgress, line: &str| match progress.update(line) {
+            Some(PullEvent::Progress { percent, .. }) => percent,
+            other => panic!("{line:?}: {other:?}"),
+        };
+
+        assert_eq!(
+            percent(&mut progress, "config.json: 100%|██████████| 1.05k/1.05k"),
+            None
+        );
+        assert_eq!(
+            percent(&mut progress, "Fetching 4 files:  25%|██▌       | 1/4"),
+            Some(25.0)
+        );
+        // A new file's bar starting at 0% doesn't drag the total back.
+        assert_eq!(
+            percent(
+                &mut progress,
+                "model.safetensors:   0%|          | 0.00/4.72G"
+            ),
+            Some(25.0)
+        );
+        assert_eq!(
+            percent(&mut progress, "Fetching 4 files:  75%|███████▌  | 3/4"),
+            Some(75.0)
+        );
+        assert!(progress.update("Repository Not Found").is_none());
+    }
+
+    #[test]
+    fn progress_lines_split_on_carriage_returns() {
+        let output = b"Fetching 2 files:   0%|          | 0/2\rFetching 2 files:  50%|#####     | 1/2\r\nDone\n";
+        let mut lines = Vec::new();
+        for_each_progress_line(&output[..], |line| lines.push(line.to_string()));
+        assert_eq!(
+            lines,
+            [
+                "Fetching 2 files:   0%|          | 0/2",
+                "Fetching 2 files:  50%|#####     | 1/2",
+                "Done",
+            ]
+        );
+        let percents: Vec<_> = lines.iter().map(|l| cli_progress_percent(l)).collect();
+        assert_eq!(percents, [Some(0.0), Some(50.0), None]);
+    }
+
     #[test]
     fn test_lmstudio_download_status_percent_and_terminal_mapping() {
         let progress: LmStudioDownloadStatus =
@@ -5001,6 +6127,37 @@ mod tests {
         assert!(!tag.is_empty());
     }
 
+    #[test]
+    fn test_hf_validation_cache_expires_after_ttl() {
+        let mut cache = HfValidationCache::default();
+        cache.record("mlx-community/Missing-4bit", false, 1_000);
+        assert_eq!(
+            cache.lookup("mlx-community/Missing-4bit", 1_000),
+            Some(false)
+        );
+        assert_eq!(
+            cache.lookup(
+                "mlx-community/Missing-4bit",
+                1_000 + HF_VALIDATION_TTL_SECS - 1
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            cache.lookup("mlx-community/Missing-4bit", 1_000 + HF_VALIDATION_TTL_SECS),
+            None
+        );
+        assert_eq!(cache.lookup("mlx-community/Other-4bit", 1_000), None);
+    }
+
+    #[test]
+    fn test_hf_status_401_is_missing_only_without_a_token() {
+        assert_eq!(hf_status_means_missing(404, false), Some(false));
+        assert_eq!(hf_status_means_missing(404, true), Some(false));
+        assert_eq!(hf_status_means_missing(401, false), Some(false));
+        assert_eq!(hf_status_means_missing(401, true), None);
+        assert_eq!(hf_status_means_missing(500, false), None);
+    }
+
     // ── resolve_mlx_fallback_repo (issue #294) ───────────────────────
 
     #[test]
@@ -5086,6 +6243,130 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_ollama_latest_installed_matches_bare_candidate() {
+        assert!(ollama_installed_matches_candidate(
+            "llama3:latest",
+            "llama3"
+        ));
+    }
+
+    #[test]
+    fn test_ollama_bare_installed_matches_latest_candidate() {
+        assert!(ollama_installed_matches_candidate(
+            "llama3",
+            "llama3:latest"
+        ));
+    }
+
+    #[test]
+    fn test_ollama_latest_matches_latest() {
+        assert!(ollama_installed_matches_candidate(
+            "llama3:latest",
+            "llama3:latest"
+        ));
+    }
+
+    #[test]
+    fn test_ollama_instruct_alias_matches_bare_candidate() {
+        assert!(ollama_installed_matches_candidate(
+            "llama3:instruct",
+            "llama3"
+        ));
+    }
+
+    #[test]
+    fn test_ollama_chat_alias_matches_bare_candidate() {
+        assert!(ollama_installed_matches_candidate("llama2:chat", "llama2"));
+    }
+
+    #[test]
+    fn test_ollama_text_alias_matches_bare_candidate() {
+        assert!(ollama_installed_matches_candidate("llama3:text", "llama3"));
+    }
+
+    #[test]
+    fn test_ollama_alias_requires_same_family() {
+        assert!(!ollama_installed_matches_candidate(
+            "llama3.1:latest",
+            "llama3"
+        ));
+        assert!(!ollama_installed_matches_candidate(
+            "llama3.1:instruct",
+            "llama3"
+        ));
+    }
+
+    #[test]
+    fn test_ollama_orphan_tags_lists_installs_without_a_catalog_model() {
+        let catalog = [
+            "meta-llama/Llama-3.1-8B-Instruct",
+            "Qwen/Qwen2.5-Coder-14B-Instruct",
+        ];
+        let installed = [
+            "llama3.1:8b",
+            "qwen2.5-coder:14b-instruct-q4_K_M",
+            "my-custom-bot:latest",
+            "hf-community/pirate-llm:7b",
+            "MY-CUSTOM-BOT:latest",
+        ];
+        assert_eq!(
+            ollama_orphan_tags(installed, catalog),
+            vec!["hf-community/pirate-llm:7b", "my-custom-bot:latest"]
+        );
+        // Every install maps to the catalog: nothing to list.
+        assert!(ollama_orphan_tags(["llama3.1:8b"], catalog).is_empty());
+        // An empty catalog makes every install an orphan.
+        assert_eq!(ollama_orphan_tags(["llama3.1:8b"], []), vec!["llama3.1:8b"]);
+    }
+
+    #[test]
+    fn test_ollama_size_tag_is_not_an_alias() {
+        // A specific size is a different download, not the default build.
+        assert!(!ollama_installed_matches_candidate("llama3:70b", "llama3"));
+    }
+
+    #[test]
+    fn test_ollama_alias_does_not_satisfy_sized_candidate() {
+        assert!(!ollama_installed_matches_candidate(
+            "llama3:instruct",
+            "llama3:8b"
+        ));
+        assert!(!ollama_installed_matches_candidate(
+            "llama3:latest",
+            "llama3:8b"
+        ));
+    }
+
+    #[test]
+    fn test_ollama_unknown_tag_is_not_an_alias() {
+        assert!(!ollama_installed_matches_candidate(
+            "llama3:vision",
+            "llama3"
+        ));
+    }
+
+    #[test]
+    fn test_is_model_installed_via_latest_tag_only() {
+        // "microsoft/Phi-3-mini-4k-instruct" maps to the bare tag "phi3", and
+        // a set holding only the full tags must still match it.
+        let installed: HashSet<String> = ["phi3:latest".to_string()].into();
+        assert!(is_model_installed(
+            "microsoft/Phi-3-mini-4k-instruct",
+            &installed
+        ));
+        let installed: HashSet<String> = ["phi3:instruct".to_string()].into();
+        assert!(is_model_installed(
+            "microsoft/Phi-3-mini-4k-instruct",
+            &installed
+        ));
+        let installed: HashSet<String> = ["phi3.5:latest".to_string()].into();
+        assert!(!is_model_installed(
+            "microsoft/Phi-3-mini-4k-instruct",
+            &installed
+        ));
+    }
+
     // ── parse_repo_gguf_entries ──────────────────────────────────────
 
     #[test]
@@ -5582,6 +6863,7 @@ mod tests {
         let parse = |name: &str, size: u64| OllamaModel {
             name: name.to_string(),
             size,
+            ..Default::default()
         };
         let models = vec![
             parse("qwen3-coder:480b-cloud", 0), // cloud: -cloud suffix + size 0
@@ -5589,7 +6871,7 @@ mod tests {
             parse("llama3.1:8b-instruct-q4_K_M", 4_700_000_000), // local
         ];
 
-        let (set, count) = build_installed_set(models);
+        let (set, count) = build_installed_set(&models);
 
         // Only the local model is counted and inserted.
         assert_eq!(count, 1, "cloud models must not count as installed");
@@ -5610,6 +6892,7 @@ mod tests {
         let cloud = OllamaModel {
             name: "qwen3-coder:480b-cloud".to_string(),
             size: 0,
+            ..Default::default()
         };
         assert!(cloud.is_cloud());
 
@@ -5617,6 +6900,7 @@ mod tests {
         let local = OllamaModel {
             name: "llama3.1:8b".to_string(),
             size: 4_700_000_000,
+            ..Default::default()
         };
         assert!(!local.is_cloud());
 
@@ -5624,7 +6908,150 @@ mod tests {
         let zero = OllamaModel {
             name: "mystery:latest".to_string(),
             size: 0,
+            ..Default::default()
         };
         assert!(zero.is_cloud());
     }
+
+    #[test]
+    fn test_ollama_tags_parses_details() {
+        let json = r#"{
+            "models": [
+                {
+                    "name": "llama3.1:8b-instruct-q4_K_M",
+                    "model": "llama3.1:8b-instruct-q4_K_M",
+                    "modified_at": "2025-05-04T17:37:44.706015396-07:00",
+                    "size": 4920753328,
+                    "digest": "46e0c10c039e019119339687c3c1757cc81b9da49709a3b3924863ba87ca666e",
+                    "details": {
+                        "parent_model": "",
+                        "format": "gguf",
+                        "family": "llama",
+                        "families": ["llama"],
+                        "parameter_size": "8.0B",
+                        "quantization_level": "Q4_K_M"
+                    }
+                },
+                {
+                    "name": "qwen3-coder:480b-cloud",
+                    "size": 0,
+                    "details": { "family": "qwen3moe", "parameter_size": "480B" }
+                },
+                { "name": "imported:latest", "size": 1000000000 }
+            ]
+        }"#;
+        let tags: TagsResponse = serde_json::from_str(json).unwrap();
+        let local = local_models(tags.models);
+        assert_eq!(local.len(), 2, "cloud models are not local");
+
+        let llama = &local[0];
+        assert_eq!(llama.details.format, "gguf");
+        assert_eq!(llama.details.family, "llama");
+        assert_eq!(llama.details.parameter_size, "8.0B");
+        assert_eq!(llama.details.quantization_level, "Q4_K_M");
+        assert_eq!(llama.summary(), "Q4_K_M, 4.9 GB");
+
+        // Missing `details` falls back to empty fields.
+        assert_eq!(local[1].details, OllamaModelDetails::default());
+        assert_eq!(local[1].summary(), "1.0 GB");
+
+        let found = find_ollama_model("meta-llama/Llama-3.1-8B-Instruct", &local);
+        assert_eq!(
+            found.map(|m| m.name.as_str()),
+            Some("llama3.1:8b-instruct-q4_K_M")
+        );
+        assert!(find_ollama_model("Qwen/Qwen3-Coder-480B-A35B-Instruct", &local).is_none());
+
+        // Exact sizes come from the cached listing; nothing listens on the
+        // fallback fetch's port, so unknown tags are None.
+        let provider = OllamaProvider {
+            base_url: "http://127.0.0.1:1".to_string(),
+            fallback_url: None,
+            agent: ollama_agent(),
+            local_models: local.clone(),
+        };
+        assert_eq!(
+            provider.model_size_gb("llama3.1:8b-instruct-q4_K_M"),
+            Some(local[0].size_gb())
+        );
+        assert_eq!(provider.model_size_gb("Imported"), Some(1.0));
+        assert_eq!(provider.model_size_gb("qwen3-coder:480b-cloud"), None);
+        assert_eq!(provider.model_size_gb("missing:7b"), None);
+    }
+
+    #[test]
+    fn test_parse_jan_model_json() {
+        let json = r#"{
+            "id": "llama3.2-3b-instruct",
+            "sources": [{
+                "filename": "Llama-3.2-3B-Instruct-Q8_0.gguf",
+                "url": "https://huggingface.co/bartowski/Llama-3.2-3B-Instruct-GGUF/resolve/main/Llama-3.2-3B-Instruct-Q8_0.gguf"
+            }],
+            "engine": "llama-cpp"
+        }"#;
+        let names = parse_jan_model_json(json);
+        assert!(names.contains(&"llama3.2-3b-instruct".to_string()));
+        assert!(names.contains(&"Llama-3.2-3B-Instruct-Q8_0".to_string()));
+        assert!(names.contains(&"Llama-3.2-3B-Instruct".to_string()));
+
+        let mut set = HashSet::new();
+        for name in &names {
+            insert_gguf_stem(&mut set, name);
+        }
+        assert!(is_model_installed_llamacpp(
+            "meta-llama/Llama-3.2-3B-Instruct",
+            &set
+        ));
+        assert!(parse_jan_model_json("not json").is_empty());
+    }
+
+    #[test]
+    fn test_parse_jan_model_yml() {
+        let yml = "model_path: /home/me/.local/share/Jan/data/llamacpp/models/qwen/model.gguf\n\
+                   name: Qwen2.5-7B-Instruct-Q4_K_M\n\
+                   size_bytes: 4683073184\n";
+        let names = parse_jan_model_yml(yml);
+        assert_eq!(names, vec!["model", "Qwen2.5-7B-Instruct-Q4_K_M"]);
+    }
+
+    #[test]
+    fn test_parse_gpt4all_ini_model_path() {
+        let ini = "[General]\nmodelPath=/data/gpt4all models\nthreadCount=8\n";
+        assert_eq!(
+            parse_gpt4all_ini_model_path(ini),
+            Some(PathBuf::from("/data/gpt4all models"))
+        );
+        assert_eq!(
+            parse_gpt4all_ini_model_path("[General]\nmodelPath=\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_gpt4all_scans_gguf_files() {
+        let dir = std::env::temp_dir().join(format!("llmfit-gpt4all-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in [
+            "Meta-Llama-3-8B-Instruct.Q4_0.gguf",
+            "incomplete-Phi-3-mini-4k-instruct.Q4_0.gguf",
+            "localdocs_v2.db",
+        ] {
+            std::fs::write(dir.join(file), b"").unwrap();
+        }
+
+        let provider = Gpt4AllProvider {
+            models_dir: dir.clone(),
+        };
+        let (set, count) = provider.installed_models_counted();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(count, 1);
+        assert!(set.contains("meta-llama-3-8b-instruct"));
+        assert!(is_model_installed_llamacpp(
+            "meta-llama/Meta-Llama-3-8B-Instruct",
+            &set
+        ));
+        assert!(!set.iter().any(|name| name.contains("phi-3")));
+        assert!(provider.start_pull("anything").is_err());
+    }
 }
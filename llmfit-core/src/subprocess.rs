@@ -0,0 +1,68 @@
+//! The single gate every external program llmfit runs goes through, so safe
+//! mode ([`NO_SUBPROCESS_ENV`]) can switch them all off at once.
+
+use std::io;
+use std::process::{Child, Command, ExitStatus, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Set to `1` to never start external programs (`nvidia-smi`,
+/// `system_profiler`, `rocm-smi`, …). Hardware detection then relies on
+/// sysfs/procfs and in-process APIs only; anything that needs a tool behaves
+/// as if the tool were not installed.
+pub const NO_SUBPROCESS_ENV: &str = "LLMFIT_NO_SUBPROCESS";
+
+static SPAWNED: AtomicUsize = AtomicUsize::new(0);
+static REFUSED: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether safe mode is on: [`NO_SUBPROCESS_ENV`] is `1`, `true`, `yes` or `on`.
+pub fn disabled() -> bool {
+    std::env::var(NO_SUBPROCESS_ENV).is_ok_and(|v| {
+        matches!(
+            v.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes" | "on"
+        )
+    })
+}
+
+fn allow(cmd: &Command) -> io::Result<()> {
+    if disabled() {
+        REFUSED.fetch_add(1, Ordering::Relaxed);
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "not running {}: subprocesses are disabled by {NO_SUBPROCESS_ENV}",
+                cmd.get_program().to_string_lossy()
+            ),
+        ));
+    }
+    SPAWNED.fetch_add(1, Ordering::Relaxed);
+    Ok(())
+}
+
+/// [`Command::output`], unless safe mode is on.
+pub fn output(cmd: &mut Command) -> io::Result<Output> {
+    allow(cmd)?;
+    cmd.output()
+}
+
+/// [`Command::status`], unless safe mode is on.
+pub fn status(cmd: &mut Command) -> io::Result<ExitStatus> {
+    allow(cmd)?;
+    cmd.status()
+}
+
+/// [`Command::spawn`], unless safe mode is on.
+pub fn spawn(cmd: &mut Command) -> io::Result<Child> {
+    allow(cmd)?;
+    cmd.spawn()
+}
+
+/// Programs started by this process so far.
+pub fn spawn_count() -> usize {
+    SPAWNED.load(Ordering::Relaxed)
+}
+
+/// Programs safe mode has refused to start so far.
+pub fn refused_count() -> usize {
+    REFUSED.load(Ordering::Relaxed)
+}
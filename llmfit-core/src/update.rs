@@ -18,7 +18,7 @@ const HF_API: &str = "https://huggingface.co/api/models";
 
 /// Bump this when the `LlmModel` schema changes in a breaking way.
 /// A cache written by an older version will be discarded and re-fetched.
-const CACHE_VERSION: u32 = 4;
+pub(crate) const CACHE_VERSION: u32 = 4;
 
 const ACCEPTED_PIPELINES: &[&str] = &[
     "text-generation",
@@ -417,11 +417,24 @@ struct HfConfig {
     n_routed_experts: Option<u32>,
     #[serde(default)]
     num_local_experts: Option<u32>,
+    // Context window and rope scaling (YaRN / linear / dynamic NTK)
+    #[serde(default)]
+    max_position_embeddings: Option<u32>,
+    #[serde(default)]
+    rope_scaling: Option<HfRopeScaling>,
     // Nested config (Qwen3.5 vision+text models store LLM params under text_config)
     #[serde(default)]
     text_config: Option<Box<HfConfig>>,
 }
 
+#[derive(Debug, Deserialize, Default)]
+struct HfRopeScaling {
+    #[serde(default)]
+    factor: Option<f64>,
+    #[serde(default)]
+    original_max_position_embeddings: Option<u32>,
+}
+
 /// Fetch a model's `config.json` from the HuggingFace resolve endpoint.
 /// Returns `None` on any failure (missing config, network error, parse
 /// error). This is best-effort metadata enrichment, not load-bearing.
@@ -461,6 +474,25 @@ fn resolve_head_dim(cfg: &HfConfig) -> Option<u32> {
     Some(hidden / heads)
 }
 
+/// Derive `(trained_context, max_context)` from a config's position
+/// embeddings and rope scaling, relative to the catalog `context_length`.
+/// The trained window is only reported when it is shorter than
+/// `context_length`, and the scaled window only when it is longer.
+fn resolve_context_window(cfg: &HfConfig, context_length: u32) -> (Option<u32>, Option<u32>) {
+    let rope = cfg.rope_scaling.as_ref();
+    let native = rope
+        .and_then(|r| r.original_max_position_embeddings)
+        .or(cfg.max_position_embeddings);
+    let scaled = match (native, rope.and_then(|r| r.factor)) {
+        (Some(n), Some(f)) if f > 1.0 => Some((n as f64 * f) as u32),
+        _ => cfg.max_position_embeddings,
+    };
+    (
+        native.filter(|&n| n < context_length),
+        scaled.filter(|&s| s > context_length),
+    )
+}
+
 // ── HF API fetching ───────────────────────────────────────────────────────────
 
 fn hf_get_list_for_pipeline(
@@ -649,6 +681,10 @@ fn map_to_llm_model(hf: HfApiModel, token: Option<&str>) -> Option<LlmModel> {
     };
 
     let architecture = cfg.as_ref().and_then(|c| c.model_type.clone());
+    let (trained_context, max_context) = cfg
+        .as_ref()
+        .map(|c| resolve_context_window(c.text_config.as_deref().unwrap_or(c), context_length))
+        .unwrap_or((None, None));
 
     Some(LlmModel {
         name: hf.id.clone(),
@@ -687,6 +723,9 @@ fn map_to_llm_model(hf: HfApiModel, token: Option<&str>) -> Option<LlmModel> {
         vocab_size,
         shared_expert_intermediate_size,
         architecture,
+        family: crate::models::parse_family(&hf.id),
+        trained_context,
+        max_context,
     })
 }
 
@@ -1035,6 +1074,29 @@ mod tests {
         assert_eq!(derived, Some(2 * 10944));
     }
 
+    #[test]
+    fn test_resolve_context_window_from_rope_scaling() {
+        // Qwen2.5-7B-Instruct: 32k positions, YaRN factor 4 documented
+        let json = r#"{
+            "max_position_embeddings": 32768,
+            "rope_scaling": {"type": "yarn", "factor": 4.0, "original_max_position_embeddings": 32768}
+        }"#;
+        let cfg: HfConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(resolve_context_window(&cfg, 32_768), (None, Some(131_072)));
+
+        // Llama 3.1 style: catalog advertises the scaled window, trained at 8k
+        let json = r#"{
+            "max_position_embeddings": 131072,
+            "rope_scaling": {"rope_type": "llama3", "factor": 8.0, "original_max_position_embeddings": 8192}
+        }"#;
+        let cfg: HfConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(resolve_context_window(&cfg, 131_072), (Some(8_192), None));
+
+        // No rope scaling: nothing beyond context_length
+        let cfg: HfConfig = serde_json::from_str(r#"{"max_position_embeddings": 4096}"#).unwrap();
+        assert_eq!(resolve_context_window(&cfg, 4_096), (None, None));
+    }
+
     #[test]
     fn test_hfconfig_parses_nested_text_config() {
         // Qwen3.5 style: architecture params nested under text_config
@@ -0,0 +1,71 @@
+use llmfit_core::fit::{FitLevel, ModelFit, RunMode};
+use llmfit_core::hardware::{GpuBackend, GpuInfo};
+use llmfit_core::{ModelDatabase, SystemSpecs};
+
+fn nvidia(name: &str, vram_gb: f64, count: u32) -> GpuInfo {
+    GpuInfo {
+        name: name.to_string(),
+        vram_gb: Some(vram_gb),
+        backend: GpuBackend::Cuda,
+        count,
+        unified_memory: false,
+        driver_version: None,
+    }
+}
+
+fn llama_8b() -> llmfit_core::models::LlmModel {
+    ModelDatabase::new()
+        .get_all_models()
+        .iter()
+        .find(|m| m.name == "meta-llama/Llama-3.1-8B-Instruct")
+        .expect("Llama 3.1 8B is in the bundled database")
+        .clone()
+}
+
+#[test]
+fn cpu_only_specs_analyze_from_system_ram() {
+    let specs = SystemSpecs::new(32.0, 24.0, 8);
+    assert!(!specs.has_gpu);
+    assert!(matches!(
+        specs.backend,
+        GpuBackend::CpuArm | GpuBackend::CpuX86
+    ));
+
+    let fit = ModelFit::analyze(&llama_8b(), &specs);
+    assert_eq!(fit.run_mode, RunMode::CpuOnly);
+    assert_eq!(fit.memory_available_gb, 24.0);
+    assert!(
+        fit.fit_level != FitLevel::TooTight,
+        "notes: {:?}",
+        fit.notes
+    );
+
+    // The same model doesn't fit once almost no memory is free.
+    let tight = ModelFit::analyze(&llama_8b(), &SystemSpecs::new(32.0, 2.0, 8));
+    assert_eq!(tight.fit_level, FitLevel::TooTight);
+}
+
+#[test]
+fn gpu_specs_analyze_against_the_given_vram() {
+    let specs =
+        SystemSpecs::new(64.0, 56.0, 16).with_gpu(nvidia("NVIDIA GeForce RTX 4090", 24.0, 1));
+    assert!(specs.has_gpu);
+    assert_eq!(specs.backend, GpuBackend::Cuda);
+    assert_eq!(specs.gpu_name.as_deref(), Some("NVIDIA GeForce RTX 4090"));
+
+    let fit = ModelFit::analyze(&llama_8b(), &specs);
+    assert_eq!(fit.run_mode, RunMode::Gpu);
+    assert_eq!(fit.memory_available_gb, 24.0);
+    assert_eq!(fit.fit_level, FitLevel::Perfect, "notes: {:?}", fit.notes);
+}
+
+#[test]
+fn added_gpus_pool_their_vram_behind_the_largest() {
+    let specs = SystemSpecs::new(128.0, 120.0, 32)
+        .with_gpu(nvidia("NVIDIA GeForce RTX 3060", 12.0, 1))
+        .with_gpu(nvidia("NVIDIA GeForce RTX 3090", 24.0, 2));
+    assert_eq!(specs.gpu_name.as_deref(), Some("NVIDIA GeForce RTX 3090"));
+    assert_eq!(specs.gpu_vram_gb, Some(24.0));
+    assert_eq!(specs.total_gpu_vram_gb, Some(60.0));
+    assert_eq!(specs.gpu_count, 3);
+}
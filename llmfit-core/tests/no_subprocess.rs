@@ -0,0 +1,27 @@
+use llmfit_core::SystemSpecs;
+use llmfit_core::doctor::collect_diagnostics;
+use llmfit_core::subprocess::{self, NO_SUBPROCESS_ENV};
+
+// Env vars are process-global, so everything touching LLMFIT_NO_SUBPROCESS
+// lives in this one test.
+#[test]
+fn safe_mode_never_spawns_a_subprocess() {
+    unsafe { std::env::set_var(NO_SUBPROCESS_ENV, "1") };
+    assert!(subprocess::disabled());
+
+    let specs = SystemSpecs::detect();
+    assert!(specs.total_ram_gb > 0.0, "procfs detection still works");
+    // The doctor report shells out to vulkaninfo and npu-smi on every OS.
+    let report = collect_diagnostics("test");
+    assert!(report.contains(NO_SUBPROCESS_ENV), "{report}");
+
+    assert_eq!(subprocess::spawn_count(), 0);
+    assert!(subprocess::refused_count() > 0);
+
+    let err = subprocess::output(&mut std::process::Command::new("true")).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::PermissionDenied);
+    assert_eq!(subprocess::spawn_count(), 0);
+
+    unsafe { std::env::set_var(NO_SUBPROCESS_ENV, "0") };
+    assert!(!subprocess::disabled());
+}
@@ -0,0 +1,63 @@
+use llmfit_core::fit::{FitLevel, ModelFit};
+use llmfit_core::hardware::{GpuBackend, SPECS_ENV};
+use llmfit_core::{ModelDatabase, SystemSpecs};
+
+/// A 96 GB M2 Max MacBook Pro, as `llmfit system --json` would report it,
+/// with a CPU name no real machine reports.
+const M2_MAX_SPECS: &str = r#"{
+    "total_ram_gb": 96.0,
+    "available_ram_gb": 80.0,
+    "total_cpu_cores": 12,
+    "cpu_name": "Fixture CPU",
+    "has_gpu": true,
+    "gpu_vram_gb": 96.0,
+    "total_gpu_vram_gb": 96.0,
+    "gpu_available_gb": 72.0,
+    "gpu_name": "Apple M2 Max",
+    "gpu_count": 1,
+    "unified_memory": true,
+    "backend": "Metal",
+    "gpus": [
+        {
+            "name": "Apple M2 Max",
+            "vram_gb": 96.0,
+            "backend": "Metal",
+            "count": 1,
+            "unified_memory": true,
+            "driver_version": null
+        }
+    ]
+}"#;
+
+// Env vars are process-global, so everything touching LLMFIT_SPECS lives in
+// this one test.
+#[test]
+fn llmfit_specs_env_overrides_detection() {
+    unsafe { std::env::set_var(SPECS_ENV, M2_MAX_SPECS) };
+    let specs = SystemSpecs::detect();
+
+    assert_eq!(specs.cpu_name, "Fixture CPU");
+    assert_eq!(specs.backend, GpuBackend::Metal);
+    assert!(specs.unified_memory);
+    assert_eq!(specs.gpus.len(), 1);
+    assert_eq!(specs.gpus[0].name, "Apple M2 Max");
+    // Omitted fields take their defaults.
+    assert!(!specs.cluster_mode);
+    assert_eq!(specs.cluster_node_count, 0);
+
+    let db = ModelDatabase::new();
+    let model = db
+        .get_all_models()
+        .iter()
+        .find(|m| m.name == "meta-llama/Llama-3.1-70B-Instruct")
+        .expect("Llama 3.1 70B is in the bundled database");
+    let fit = ModelFit::analyze(model, &specs);
+    assert_eq!(fit.fit_level, FitLevel::Perfect, "notes: {:?}", fit.notes);
+
+    // A value that doesn't parse is ignored in favour of real detection.
+    unsafe { std::env::set_var(SPECS_ENV, "{not json") };
+    let detected = SystemSpecs::detect();
+    assert_ne!(detected.cpu_name, "Fixture CPU");
+
+    unsafe { std::env::remove_var(SPECS_ENV) };
+}
@@ -1,3 +1,14 @@
 fn main() {
+    // tauri-build's own error for a missing frontendDist is hard to read;
+    // say which file is missing and why it matters.
+    let index = std::path::Path::new("ui/index.html");
+    println!("cargo:rerun-if-changed={}", index.display());
+    if !index.exists() {
+        panic!(
+            "llmfit-desktop frontend assets are missing: expected {} (the `frontendDist` \
+             in tauri.conf.json). The desktop window would open blank without it.",
+            index.display()
+        );
+    }
     tauri_build::build()
 }
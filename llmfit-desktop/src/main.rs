@@ -1,11 +1,15 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use llmfit_core::fit::{FitLevel, InferenceRuntime, ModelFit, RunMode};
-use llmfit_core::hardware::SystemSpecs;
-use llmfit_core::models::ModelDatabase;
+mod metrics;
+
+use llmfit_core::fit::{CalcConfig, FitLevel, InferenceRuntime, ModelFit, RunMode};
+use llmfit_core::hardware::{GpuBackend, SystemSpecs};
+use llmfit_core::models::{ModelDatabase, QUANT_HIERARCHY};
 use llmfit_core::providers::{ModelProvider, OllamaProvider, PullEvent};
+use metrics::Metrics;
 use serde::Serialize;
-use std::sync::Mutex;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex, OnceLock};
 use tauri::State;
 
 #[derive(Serialize)]
@@ -15,6 +19,7 @@ struct GpuInfoJs {
     backend: String,
     count: u32,
     unified_memory: bool,
+    driver_version: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -23,6 +28,10 @@ struct SystemInfo {
     available_ram_gb: f64,
     cpu_name: String,
     cpu_cores: usize,
+    /// Primary GPU name (empty without a GPU) and total VRAM across cards,
+    /// for consumers that only show one GPU. `gpus` has the full list.
+    gpu: String,
+    vram_gb: Option<f64>,
     gpus: Vec<GpuInfoJs>,
     unified_memory: bool,
 }
@@ -32,6 +41,9 @@ struct ModelFitInfo {
     name: String,
     params_b: f64,
     quant: String,
+    /// Quants the detail view can pin with `get_model_detail_at_quant`;
+    /// empty for pre-quantized (AWQ/GPTQ) weights.
+    quant_options: Vec<String>,
     fit_level: String,
     run_mode: String,
     score: f64,
@@ -40,10 +52,15 @@ struct ModelFitInfo {
     utilization_pct: f64,
     estimated_tps: f64,
     use_case: String,
+    use_case_description: String,
+    use_case_emoji: String,
     runtime: String,
+    runtime_icon: String,
     installed: bool,
+    installed_provider: Option<String>,
     notes: Vec<String>,
     release_date: Option<String>,
+    docker_command: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -54,9 +71,62 @@ struct PullStatus {
     error: Option<String>,
 }
 
+/// Backend readiness, so the frontend can show an error state instead of a
+/// blank window when detection or analysis comes up empty.
+#[derive(Serialize, Clone, Debug)]
+struct AppHealth {
+    ready: bool,
+    specs_detected: bool,
+    catalog_models: usize,
+    ollama_available: bool,
+    errors: Vec<String>,
+}
+
 struct AppState {
     ollama: OllamaProvider,
     pull_handle: Mutex<Option<llmfit_core::providers::PullHandle>>,
+    metrics: Arc<Metrics>,
+    health: OnceLock<AppHealth>,
+}
+
+impl AppState {
+    fn new(metrics: Arc<Metrics>) -> Self {
+        Self {
+            ollama: OllamaProvider::new(),
+            pull_handle: Mutex::new(None),
+            metrics,
+            health: OnceLock::new(),
+        }
+    }
+
+    /// Probe hardware, the model catalog and Ollama on first request and
+    /// record what worked, so startup doesn't wait on detection.
+    fn health(&self) -> &AppHealth {
+        self.health.get_or_init(|| self.probe_health())
+    }
+
+    fn probe_health(&self) -> AppHealth {
+        let mut errors = Vec::new();
+
+        let specs = SystemSpecs::detect();
+        let specs_detected = specs.total_ram_gb > 0.0;
+        if !specs_detected {
+            errors.push("Hardware detection found no system memory".to_string());
+        }
+
+        let catalog_models = ModelDatabase::new().get_all_models().len();
+        if catalog_models == 0 {
+            errors.push("The model catalog is empty".to_string());
+        }
+
+        AppHealth {
+            ready: specs_detected && catalog_models > 0,
+            specs_detected,
+            catalog_models,
+            ollama_available: self.ollama.is_available(),
+            errors,
+        }
+    }
 }
 
 #[tauri::command]
@@ -71,6 +141,7 @@ fn get_system_specs() -> Result<SystemInfo, String> {
             backend: format!("{:?}", g.backend),
             count: g.count,
             unified_memory: g.unified_memory,
+            driver_version: g.driver_version.clone(),
         })
         .collect();
     Ok(SystemInfo {
@@ -78,67 +149,137 @@ fn get_system_specs() -> Result<SystemInfo, String> {
         available_ram_gb: specs.available_ram_gb,
         cpu_name: specs.cpu_name.clone(),
         cpu_cores: specs.total_cpu_cores,
+        gpu: specs.gpu_name.clone().unwrap_or_default(),
+        vram_gb: specs.total_gpu_vram_gb,
         gpus,
         unified_memory: specs.unified_memory,
     })
 }
 
+/// Ranked fits as a GitHub-Flavored Markdown table, for pasting into
+/// issues and docs.
+#[tauri::command]
+fn get_markdown_table() -> Result<String, String> {
+    let specs = SystemSpecs::detect();
+    let db = ModelDatabase::new();
+    let fits = ModelFit::analyze_many_parallel_with_config(
+        db.get_all_models(),
+        &specs,
+        &CalcConfig::from_user_config(),
+    );
+    let fits = llmfit_core::fit::rank_models_by_fit(fits);
+    let refs: Vec<&ModelFit> = fits.iter().collect();
+    Ok(llmfit_core::fit::to_markdown_table(
+        &refs,
+        llmfit_core::fit::MarkdownColumn::ALL,
+    ))
+}
+
 #[tauri::command]
 fn get_model_fits() -> Result<Vec<ModelFitInfo>, String> {
     let specs = SystemSpecs::detect();
     let db = ModelDatabase::new();
+    let installed = llmfit_core::analysis::InstalledIndex::detect_all();
+    let config = CalcConfig::from_user_config();
 
     let mut fits: Vec<ModelFit> = db
         .get_all_models()
         .iter()
-        .map(|m| ModelFit::analyze(m, &specs))
+        .map(|m| {
+            let mut fit = ModelFit::analyze_with_config(m, &specs, config.clone());
+            installed.mark(&mut fit);
+            fit
+        })
         .collect();
 
     fits = llmfit_core::fit::rank_models_by_fit(fits);
 
     Ok(fits
-        .into_iter()
-        .map(|f| ModelFitInfo {
-            name: f.model.name.clone(),
-            params_b: f.model.parameters_raw.unwrap_or(0) as f64 / 1e9,
-            quant: f.best_quant.clone(),
-            fit_level: match f.fit_level {
-                FitLevel::Perfect => "Perfect".to_string(),
-                FitLevel::Good => "Good".to_string(),
-                FitLevel::Marginal => "Marginal".to_string(),
-                FitLevel::TooTight => "Too Tight".to_string(),
-            },
-            run_mode: match f.run_mode {
-                RunMode::Gpu => "GPU".to_string(),
-                RunMode::CpuOffload => "CPU Offload".to_string(),
-                RunMode::CpuOnly => "CPU Only".to_string(),
-                RunMode::MoeOffload => "MoE Offload".to_string(),
-                RunMode::TensorParallel => "Tensor Parallel".to_string(),
-            },
-            score: f.score,
-            memory_required_gb: f.memory_required_gb,
-            memory_available_gb: f.memory_available_gb,
-            utilization_pct: f.utilization_pct,
-            estimated_tps: f.estimated_tps,
-            use_case: format!("{:?}", f.use_case),
-            runtime: match f.runtime {
-                InferenceRuntime::LlamaCpp => "llama.cpp".to_string(),
-                InferenceRuntime::Mlx => "MLX".to_string(),
-                InferenceRuntime::Vllm => "vLLM".to_string(),
-                InferenceRuntime::Unsupported => "unsupported".to_string(),
-            },
-            installed: f.installed,
-            notes: f.notes.clone(),
-            release_date: f.model.release_date.clone(),
-        })
+        .iter()
+        .map(|f| model_fit_info(f, specs.backend))
         .collect())
 }
 
+fn model_fit_info(f: &ModelFit, backend: GpuBackend) -> ModelFitInfo {
+    ModelFitInfo {
+        name: f.model.name.clone(),
+        params_b: f.model.parameters_raw.unwrap_or(0) as f64 / 1e9,
+        quant: f.best_quant.clone(),
+        quant_options: if f.model.is_prequantized() {
+            Vec::new()
+        } else {
+            QUANT_HIERARCHY.iter().map(|q| q.to_string()).collect()
+        },
+        fit_level: match f.fit_level {
+            FitLevel::Perfect => "Perfect".to_string(),
+            FitLevel::Good => "Good".to_string(),
+            FitLevel::Marginal => "Marginal".to_string(),
+            FitLevel::TooTight => "Too Tight".to_string(),
+        },
+        run_mode: match f.run_mode {
+            RunMode::Gpu => "GPU".to_string(),
+            RunMode::CpuOffload => "CPU Offload".to_string(),
+            RunMode::CpuOnly => "CPU Only".to_string(),
+            RunMode::MoeOffload => "MoE Offload".to_string(),
+            RunMode::TensorParallel => "Tensor Parallel".to_string(),
+        },
+        score: f.score,
+        memory_required_gb: f.memory_required_gb,
+        memory_available_gb: f.memory_available_gb,
+        utilization_pct: f.utilization_pct,
+        estimated_tps: f.estimated_tps,
+        use_case: format!("{:?}", f.use_case),
+        use_case_description: f.use_case.description().to_string(),
+        use_case_emoji: f.use_case.emoji().to_string(),
+        runtime: match f.runtime {
+            InferenceRuntime::LlamaCpp => "llama.cpp".to_string(),
+            InferenceRuntime::Mlx => "MLX".to_string(),
+            InferenceRuntime::Vllm => "vLLM".to_string(),
+            InferenceRuntime::Unsupported => "unsupported".to_string(),
+        },
+        runtime_icon: f.runtime.icon().to_string(),
+        installed: f.installed,
+        installed_provider: f.installed_provider.clone(),
+        notes: f.notes.clone(),
+        release_date: f.model.release_date.clone(),
+        docker_command: f.docker_run_command(backend),
+    }
+}
+
+/// Re-run the fit for one model with the quantization pinned to `quant`, for
+/// the detail view's quant dropdown. Unknown quants fall back to automatic
+/// selection, as in the TUI.
+#[tauri::command]
+fn get_model_detail_at_quant(name: String, quant: String) -> Result<ModelFitInfo, String> {
+    model_detail_at_quant(&SystemSpecs::detect(), &name, &quant)
+}
+
+fn model_detail_at_quant(
+    specs: &SystemSpecs,
+    name: &str,
+    quant: &str,
+) -> Result<ModelFitInfo, String> {
+    let db = ModelDatabase::new();
+    let model = db
+        .get_all_models()
+        .iter()
+        .find(|m| m.name == name)
+        .ok_or_else(|| format!("Unknown model: {name}"))?;
+    let config = CalcConfig {
+        force_quant: Some(quant.to_string()),
+        ..CalcConfig::from_user_config()
+    };
+    let mut fit = ModelFit::analyze_with_config(model, specs, config);
+    llmfit_core::analysis::InstalledIndex::detect_all().mark(&mut fit);
+    Ok(model_fit_info(&fit, specs.backend))
+}
+
 #[tauri::command]
 fn start_pull(model_tag: String, state: State<'_, AppState>) -> Result<String, String> {
     let handle = state.ollama.start_pull(&model_tag)?;
     let mut pull = state.pull_handle.lock().map_err(|e| e.to_string())?;
     *pull = Some(handle);
+    state.metrics.pull_started();
     Ok("started".to_string())
 }
 
@@ -146,7 +287,10 @@ fn start_pull(model_tag: String, state: State<'_, AppState>) -> Result<String, S
 fn poll_pull(state: State<'_, AppState>) -> Result<PullStatus, String> {
     let pull = state.pull_handle.lock().map_err(|e| e.to_string())?;
     if let Some(ref handle) = *pull {
-        match handle.receiver.try_recv() {
+        state
+            .metrics
+            .pull_progress(handle.bytes_downloaded.load(Ordering::Relaxed));
+        let status = match handle.receiver.try_recv() {
             Ok(PullEvent::Progress { status, percent }) => Ok(PullStatus {
                 status,
                 percent,
@@ -177,7 +321,11 @@ fn poll_pull(state: State<'_, AppState>) -> Result<PullStatus, String> {
                 done: true,
                 error: None,
             }),
+        };
+        if matches!(status, Ok(PullStatus { done: true, .. })) {
+            state.metrics.pull_finished();
         }
+        status
     } else {
         Err("No pull in progress".to_string())
     }
@@ -188,19 +336,88 @@ fn is_ollama_available(state: State<'_, AppState>) -> bool {
     state.ollama.is_available()
 }
 
+#[tauri::command(async)]
+fn get_app_health(state: State<'_, AppState>) -> AppHealth {
+    state.health().clone()
+}
+
 fn main() {
+    let metrics = Arc::new(Metrics::default());
+    if let Some(port) = metrics::metrics_port_from_args() {
+        if let Err(e) = metrics::serve(
+            metrics::metrics_bind_from_args(),
+            port,
+            Arc::clone(&metrics),
+        ) {
+            eprintln!("Failed to start metrics server on port {}: {}", port, e);
+        }
+    }
+
     tauri::Builder::default()
-        .manage(AppState {
-            ollama: OllamaProvider::new(),
-            pull_handle: Mutex::new(None),
-        })
+        .manage(AppState::new(metrics))
         .invoke_handler(tauri::generate_handler![
             get_system_specs,
             get_model_fits,
+            get_markdown_table,
             start_pull,
             poll_pull,
             is_ollama_available,
+            get_app_health,
+            get_model_detail_at_quant,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn app_health_reports_ready_after_startup() {
+        let state = AppState::new(Arc::new(Metrics::default()));
+        let health = state.health();
+
+        assert!(health.specs_detected);
+        assert!(health.catalog_models > 0);
+        assert!(health.ready, "{:?}", health.errors);
+        assert!(health.errors.is_empty());
+        assert_eq!(health.ollama_available, state.ollama.is_available());
+    }
+
+    #[test]
+    fn model_detail_at_quant_recomputes_memory_for_the_pinned_quant() {
+        // Pinned hardware, so the llama.cpp path (and its quant ladder) is
+        // used on every host.
+        let specs = SystemSpecs {
+            total_ram_gb: 64.0,
+            available_ram_gb: 48.0,
+            total_cpu_cores: 16,
+            cpu_name: "Test CPU".to_string(),
+            has_gpu: true,
+            gpu_vram_gb: Some(24.0),
+            total_gpu_vram_gb: Some(24.0),
+            gpu_available_gb: None,
+            gpu_name: Some("NVIDIA GeForce RTX 4090".to_string()),
+            gpu_count: 1,
+            unified_memory: false,
+            backend: llmfit_core::hardware::GpuBackend::Cuda,
+            gpus: vec![],
+            cluster_mode: false,
+            cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
+        };
+        let name = "meta-llama/Llama-3.1-8B-Instruct";
+
+        let q8 = model_detail_at_quant(&specs, name, "Q8_0").unwrap();
+        let q4 = model_detail_at_quant(&specs, name, "Q4_K_M").unwrap();
+
+        assert_eq!(q8.quant, "Q8_0");
+        assert_eq!(q4.quant, "Q4_K_M");
+        assert!(q8.memory_required_gb > q4.memory_required_gb);
+        assert!(q8.quant_options.contains(&"Q4_K_M".to_string()));
+
+        assert!(model_detail_at_quant(&specs, "no/such-model", "Q4_K_M").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! Optional Prometheus `/metrics` endpoint, enabled with `--metrics-port`.
+//!
+//! Served by a plain `std::net` listener on a background thread so the
+//! desktop app doesn't pull in an HTTP framework for one text endpoint.
+//! Binds to 127.0.0.1 unless `--metrics-bind` says otherwise.
+
+use llmfit_core::hardware::SystemSpecs;
+use llmfit_core::providers::{ModelProvider, OllamaProvider};
+use std::io::{BufRead, BufReader, Write};
+use std::net::{IpAddr, Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long a client may take to send its request line.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a hardware snapshot is reused across scrapes. Prometheus
+/// usually scrapes every 15-60s; full detection spawns several probes.
+const SPECS_TTL: Duration = Duration::from_secs(30);
+
+/// Pull counters shared between the Tauri commands and the metrics server.
+#[derive(Default)]
+pub struct Metrics {
+    /// Bytes from pulls that have already finished.
+    finished_bytes: AtomicU64,
+    /// Bytes of the pull currently in flight.
+    active_bytes: AtomicU64,
+    pull_active: AtomicBool,
+}
+
+impl Metrics {
+    pub fn pull_started(&self) {
+        self.active_bytes.store(0, Ordering::Relaxed);
+        self.pull_active.store(true, Ordering::Relaxed);
+    }
+
+    pub fn pull_progress(&self, bytes: u64) {
+        self.active_bytes.store(bytes, Ordering::Relaxed);
+    }
+
+    pub fn pull_finished(&self) {
+        if self.pull_active.swap(false, Ordering::Relaxed) {
+            let bytes = self.active_bytes.swap(0, Ordering::Relaxed);
+            self.finished_bytes.fetch_add(bytes, Ordering::Relaxed);
+        }
+    }
+
+    fn pull_bytes_total(&self) -> u64 {
+        self.finished_bytes.load(Ordering::Relaxed) + self.active_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Render all metrics in the Prometheus text exposition format.
+    fn render(&self, specs: &SystemSpecs, installed: usize) -> String {
+        let vram = specs
+            .gpu_available_gb
+            .or(specs.total_gpu_vram_gb)
+            .unwrap_or(0.0);
+
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, value: String| {
+            out.push_str(&format!("# HELP {name} {help}\n"));
+            out.push_str(&format!("# TYPE {name} {kind}\n"));
+            out.push_str(&format!("{name} {value}\n"));
+        };
+        metric(
+            "llmfit_installed_models_total",
+            "gauge",
+            "Models installed in the local Ollama instance.",
+            installed.to_string(),
+        );
+        metric(
+            "llmfit_available_vram_gb",
+            "gauge",
+            "GPU memory available for models, in GB.",
+            format!("{vram:.2}"),
+        );
+        metric(
+            "llmfit_available_ram_gb",
+            "gauge",
+            "Available system RAM, in GB.",
+            format!("{:.2}", specs.available_ram_gb),
+        );
+        metric(
+            "llmfit_pull_bytes_downloaded_total",
+            "counter",
+            "Bytes downloaded by model pulls since startup.",
+            self.pull_bytes_total().to_string(),
+        );
+        metric(
+            "llmfit_pull_active",
+            "gauge",
+            "1 while a model pull is in progress.",
+            u8::from(self.pull_active.load(Ordering::Relaxed)).to_string(),
+        );
+        out
+    }
+}
+
+/// Parse `--metrics-port <PORT>` / `--metrics-port=<PORT>` from the process
+/// arguments. `None` (the default) leaves the endpoint disabled.
+pub fn metrics_port_from_args() -> Option<u16> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--metrics-port" {
+            return args.next()?.parse().ok();
+        }
+        if let Some(port) = arg.strip_prefix("--metrics-port=") {
+            return port.parse().ok();
+        }
+    }
+    None
+}
+
+/// Parse `--metrics-bind <ADDR>` / `--metrics-bind=<ADDR>`. Defaults to
+/// 127.0.0.1 so the endpoint isn't reachable from the network unless asked.
+pub fn metrics_bind_from_args() -> IpAddr {
+    let mut args = std::env::args().skip(1);
+    let mut bind = None;
+    while let Some(arg) = args.next() {
+        if arg == "--metrics-bind" {
+            bind = args.next();
+        } else if let Some(addr) = arg.strip_prefix("--metrics-bind=") {
+            bind = Some(addr.to_string());
+        }
+    }
+    bind.and_then(|addr| addr.parse().ok())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST))
+}
+
+/// State shared by the connection threads.
+struct Server {
+    metrics: Arc<Metrics>,
+    ollama: OllamaProvider,
+    specs: Mutex<Option<(Instant, SystemSpecs)>>,
+}
+
+impl Server {
+    /// The last hardware snapshot, re-detected once it is older than
+    /// [`SPECS_TTL`].
+    fn specs(&self) -> SystemSpecs {
+        let mut cached = self.specs.lock().unwrap_or_else(|e| e.into_inner());
+        match cached.as_ref() {
+            Some((at, specs)) if at.elapsed() < SPECS_TTL => specs.clone(),
+            _ => {
+                let specs = SystemSpecs::detect();
+                *cached = Some((Instant::now(), specs.clone()));
+                specs
+            }
+        }
+    }
+}
+
+/// Start serving `/metrics` on `bind:port` in a background thread. Each
+/// connection gets its own thread, so a slow client can't block scrapes.
+pub fn serve(bind: IpAddr, port: u16, metrics: Arc<Metrics>) -> std::io::Result<()> {
+    let listener = TcpListener::bind((bind, port))?;
+    let server = Arc::new(Server {
+        metrics,
+        ollama: OllamaProvider::new(),
+        specs: Mutex::new(None),
+    });
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let server = Arc::clone(&server);
+            std::thread::spawn(move || {
+                let _ = handle(stream, &server);
+            });
+        }
+    });
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream, server: &Server) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    stream.set_write_timeout(Some(READ_TIMEOUT))?;
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("");
+
+    let (status, body) = if path == "/metrics" {
+        let installed = server.ollama.installed_models().len();
+        ("200 OK", server.metrics.render(&server.specs(), installed))
+    } else {
+        ("404 Not Found", "not found\n".to_string())
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn specs() -> SystemSpecs {
+        let mut specs = SystemSpecs::new(64.0, 48.0, 16);
+        specs.gpu_available_gb = Some(24.0);
+        specs
+    }
+
+    #[test]
+    fn render_emits_help_type_and_value_for_each_metric() {
+        let metrics = Metrics::default();
+        let out = metrics.render(&specs(), 3);
+        let lines: Vec<&str> = out.lines().collect();
+        // HELP, TYPE and sample line for each of the five metrics.
+        assert_eq!(lines.len(), 15);
+        for chunk in lines.chunks(3) {
+            let name = chunk[2].split_whitespace().next().unwrap();
+            assert!(chunk[0].starts_with(&format!("# HELP {name} ")));
+            assert!(
+                chunk[1] == format!("# TYPE {name} gauge")
+                    || chunk[1] == format!("# TYPE {name} counter")
+            );
+        }
+        assert!(out.contains("llmfit_installed_models_total 3\n"));
+        assert!(out.contains("llmfit_available_vram_gb 24.00\n"));
+        assert!(out.contains("llmfit_available_ram_gb 48.00\n"));
+        assert!(out.ends_with("llmfit_pull_active 0\n"));
+    }
+
+    #[test]
+    fn pull_bytes_counter_only_grows_across_pulls() {
+        let metrics = Metrics::default();
+        metrics.pull_started();
+        metrics.pull_progress(500);
+        assert!(
+            metrics
+                .render(&specs(), 0)
+                .contains("llmfit_pull_active 1\n")
+        );
+        metrics.pull_finished();
+        metrics.pull_started();
+        metrics.pull_progress(200);
+        let out = metrics.render(&specs(), 0);
+        assert!(out.contains("llmfit_pull_bytes_downloaded_total 700\n"));
+        assert!(out.contains("# TYPE llmfit_pull_bytes_downloaded_total counter\n"));
+    }
+}
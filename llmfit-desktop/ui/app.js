@@ -50,7 +50,8 @@ function renderSpecs(specs) {
       const countStr = gpu.count > 1 ? ' ×' + gpu.count : '';
       const vramStr = gpu.vram_gb != null ? gpu.vram_gb.toFixed(1) + ' GB VRAM' : t('system.sharedMemory');
       const backendStr = gpu.backend !== 'None' ? gpu.backend : '';
-      const details = [vramStr, backendStr].filter(Boolean).join(' · ');
+      const driverStr = gpu.driver_version ? t('system.driver', { version: gpu.driver_version }) : '';
+      const details = [vramStr, backendStr, driverStr].filter(Boolean).join(' · ');
       card.innerHTML = '<span class="spec-label">' + esc(label) + '</span>' +
         '<span class="spec-value">' + esc(gpu.name + countStr) + '</span>' +
         '<span class="spec-detail">' + esc(details) + '</span>';
@@ -110,10 +111,26 @@ function showModal(fit) {
       '</ul></div>';
   }
 
+  let dockerHtml = '';
+  if (fit.docker_command) {
+    dockerHtml = '<div class="modal-section"><h4>' + esc(t('desktop.dockerCommand')) + '</h4>' +
+      '<code class="docker-command">' + esc(fit.docker_command) + '</code></div>';
+  }
+
   const installedBadge = fit.installed
-    ? '<span class="badge badge-installed">' + esc(t('desktop.installed')) + '</span>'
+    ? '<span class="badge badge-installed">' + esc(t('desktop.installed')) +
+      (fit.installed_provider ? ' · ' + esc(fit.installed_provider) : '') + '</span>'
     : '<span class="badge badge-not-installed">' + esc(t('desktop.notInstalled')) + '</span>';
 
+  const options = fit.quant_options || [];
+  const quantHtml = options.length
+    ? '<select class="quant-select">' +
+      (options.includes(fit.quant) ? [] : [fit.quant]).concat(options)
+        .map(q => '<option' + (q === fit.quant ? ' selected' : '') + '>' + esc(q) + '</option>')
+        .join('') +
+      '</select>'
+    : '<span class="stat-value">' + esc(fit.quant) + '</span>';
+
   const downloadBtn = (!fit.installed && ollamaAvailable)
     ? '<button class="btn-download">' + esc(t('desktop.downloadViaOllama')) + '</button>'
     : '';
@@ -131,7 +148,7 @@ function showModal(fit) {
       </div>
       <div class="modal-stat">
         <span class="stat-label">${esc(t('desktop.quantization'))}</span>
-        <span class="stat-value">${esc(fit.quant)}</span>
+        ${quantHtml}
       </div>
       <div class="modal-stat">
         <span class="stat-label">${esc(t('desktop.runtime'))}</span>
@@ -147,7 +164,7 @@ function showModal(fit) {
       </div>
       <div class="modal-stat">
         <span class="stat-label">${esc(t('desktop.useCase'))}</span>
-        <span class="stat-value">${esc(translateUseCase(fit.use_case))}</span>
+        <span class="stat-value" title="${esc(fit.use_case_description)}">${esc(fit.use_case_emoji)} ${esc(translateUseCase(fit.use_case))}</span>
       </div>
     </div>
 
@@ -168,6 +185,8 @@ function showModal(fit) {
       </div>
     </div>
 
+    ${dockerHtml}
+
     ${notesHtml}
 
     <div id="pull-status" class="pull-status" style="display:none">
@@ -186,9 +205,21 @@ function showModal(fit) {
   const dlBtn = body.querySelector('.btn-download');
   if (dlBtn) dlBtn.addEventListener('click', () => pullModel(fit.name));
 
+  const quantSelect = body.querySelector('.quant-select');
+  if (quantSelect) quantSelect.addEventListener('change', () => showQuant(fit.name, quantSelect.value));
+
   modal.classList.add('visible');
 }
 
+async function showQuant(name, quant) {
+  try {
+    const fit = await invoke('get_model_detail_at_quant', { name, quant });
+    if (currentModalFit && currentModalFit.name === name) showModal(fit);
+  } catch (e) {
+    console.error('Failed to analyze at ' + quant + ':', e);
+  }
+}
+
 function closeModal() {
   currentModalFit = null;
   document.getElementById('model-modal').classList.remove('visible');
@@ -248,15 +279,15 @@ function renderModels(fits) {
   }
   tbody.innerHTML = fits.map((f, i) => `
     <tr class="model-row" data-index="${i}">
-      <td><strong>${esc(f.name)}</strong>${f.installed ? ' <span class="installed-dot" title="' + esc(t('desktop.installed')) + '">●</span>' : ''}</td>
+      <td><strong>${esc(f.name)}</strong>${f.installed ? ' <span class="installed-dot" title="' + esc(t('desktop.installed') + (f.installed_provider ? ' · ' + f.installed_provider : '')) + '">●</span>' : ''}</td>
       <td>${esc(f.params_b.toFixed(1))}B</td>
       <td>${esc(f.quant)}</td>
       <td class="${fitClass(f.fit_level)}">${esc(translateFitLevel(f.fit_level))}</td>
-      <td class="${modeClass(f.run_mode)}">${esc(translateRunMode(f.run_mode))}</td>
+      <td class="${modeClass(f.run_mode)}" title="${esc(f.runtime)}">${esc(f.runtime_icon)} ${esc(translateRunMode(f.run_mode))}</td>
       <td>${esc(f.score.toFixed(0))}</td>
       <td>${esc(f.memory_required_gb.toFixed(1))} GB</td>
       <td>${esc(f.estimated_tps.toFixed(1))}</td>
-      <td>${esc(translateUseCase(f.use_case))}</td>
+      <td title="${esc(f.use_case_description)}">${esc(f.use_case_emoji)} ${esc(translateUseCase(f.use_case))}</td>
     </tr>
   `).join('');
 
@@ -322,10 +353,29 @@ document.getElementById('locale-select').addEventListener('change', (e) => {
 
 subscribe(rerenderForLocale);
 
+function renderHealth(health) {
+  const banner = document.getElementById('health-banner');
+  if (!health || health.ready) {
+    banner.hidden = true;
+    return;
+  }
+  const reasons = health.errors || [];
+  banner.innerHTML = '<strong>' + esc(t('desktop.backendNotReady')) + '</strong>' +
+    (reasons.length ? ' ' + reasons.map(esc).join(' · ') : '');
+  banner.hidden = false;
+}
+
 async function init() {
   applyStaticTranslations();
   document.getElementById('locale-select').value = getLocale();
-  ollamaAvailable = await invoke('is_ollama_available') || false;
+  try {
+    const health = await invoke('get_app_health');
+    renderHealth(health);
+    ollamaAvailable = health.ollama_available;
+  } catch (e) {
+    console.error('Failed to load backend health:', e);
+    renderHealth({ ready: false, errors: [String(e)] });
+  }
   loadSpecs();
   loadModels();
 }
@@ -22,7 +22,8 @@
         unifiedMemory: 'Unified (CPU + GPU shared)',
         errorLoading: 'Error loading specs',
         cores: ({ count }) => `${count} cores`,
-        gpuIndexed: ({ index }) => `GPU ${index}`
+        gpuIndexed: ({ index }) => `GPU ${index}`,
+        driver: ({ version }) => `driver ${version}`
       },
       desktop: {
         pageTitle: 'llmfit',
@@ -32,7 +33,9 @@
         loadingModels: 'Loading models...',
         noModels: 'No models found',
         errorLoadingModels: 'Error loading models',
+        backendNotReady: 'The llmfit backend is not ready.',
         notes: 'Notes',
+        dockerCommand: 'Docker Command',
         fitAnalysis: 'Fit Analysis',
         installed: 'Installed',
         notInstalled: 'Not Installed',
@@ -102,7 +105,8 @@
         unifiedMemory: '统一内存（CPU 与 GPU 共享）',
         errorLoading: '加载硬件信息失败',
         cores: ({ count }) => `${count} 核`,
-        gpuIndexed: ({ index }) => `GPU ${index}`
+        gpuIndexed: ({ index }) => `GPU ${index}`,
+        driver: ({ version }) => `驱动 ${version}`
       },
       desktop: {
         pageTitle: 'llmfit',
@@ -112,7 +116,9 @@
         loadingModels: '正在加载模型...',
         noModels: '未找到匹配模型',
         errorLoadingModels: '加载模型失败',
+        backendNotReady: 'llmfit 后端未就绪。',
         notes: '说明',
+        dockerCommand: 'Docker 命令',
         fitAnalysis: '适配分析',
         installed: '已安装',
         notInstalled: '未安装',
@@ -2,12 +2,17 @@ use std::path::PathBuf;
 use std::sync::OnceLock;
 
 use colored::*;
-use llmfit_core::fit::{FitLevel, ModelFit, RunMode, SortColumn};
+use llmfit_core::analysis::RagPair;
+use llmfit_core::fit::{
+    FitLevel, InferenceRuntime, ModelFit, RunMode, SortColumn, fit_level_text, format_context,
+};
 use llmfit_core::hardware::SystemSpecs;
 use llmfit_core::models::LlmModel;
 use llmfit_core::plan::PlanEstimate;
 use tabled::{Table, Tabled, settings::Style};
 
+use crate::download_history::{DownloadRecord, DownloadResult, format_epoch};
+
 #[derive(Tabled)]
 struct ModelRow {
     #[tabled(rename = "Status")]
@@ -36,7 +41,9 @@ struct ModelRow {
     release_date: String,
 }
 
-pub fn display_all_models(models: &[LlmModel], sort: SortColumn) {
+/// Catalog order for `llmfit list`: only the columns that don't need a
+/// hardware analysis (date, params, ctx, mem) reorder the list.
+fn sort_catalog(models: &[LlmModel], sort: SortColumn) -> Vec<&LlmModel> {
     let mut models: Vec<&LlmModel> = models.iter().collect();
     match sort {
         SortColumn::ReleaseDate => {
@@ -68,6 +75,11 @@ pub fn display_all_models(models: &[LlmModel], sort: SortColumn) {
         }
         _ => {}
     }
+    models
+}
+
+pub fn display_all_models(models: &[LlmModel], sort: SortColumn) {
+    let models = sort_catalog(models, sort);
     println!("\n{}", "=== Available LLM Models ===".bold().cyan());
     println!(
         "Total models: {} (sorted by: {})\n",
@@ -88,7 +100,7 @@ pub fn display_all_models(models: &[LlmModel], sort: SortColumn) {
             runtime: "-".to_string(),
             mode: "-".to_string(),
             mem_use: "-".to_string(),
-            context: format!("{}k", m.context_length / 1000),
+            context: format_context(m.context_length),
             release_date: m
                 .release_date
                 .clone()
@@ -155,6 +167,86 @@ pub fn display_model_fits(fits: &[ModelFit]) {
     }
 }
 
+/// Installed Ollama tags that no database model maps to, so they have no
+/// row above (custom Modelfiles, community models). Prints nothing when
+/// every install is accounted for.
+pub fn display_ollama_orphans(orphans: &[String]) {
+    if orphans.is_empty() {
+        return;
+    }
+    println!(
+        "\n{}",
+        format!(
+            "Installed in Ollama but not in the database ({}):",
+            orphans.len()
+        )
+        .bold()
+    );
+    for tag in orphans {
+        println!("  {}", tag);
+    }
+}
+
+/// A RAG generator + embedding model pair, or why there is none.
+pub fn display_rag_pair(pair: Option<&RagPair>) {
+    let Some(pair) = pair else {
+        println!("No generator + embedding model pair fits in memory together on this machine.");
+        return;
+    };
+    println!("\n{}", "=== Recommended for RAG ===".bold().cyan());
+    for (role, fit) in [
+        ("Generator:", &pair.generator),
+        ("Embedder:", &pair.embedder),
+    ] {
+        println!(
+            "{:<11} {} ({}, {}, {:.1} GB, {} ctx)",
+            role.bold(),
+            fit.model.name,
+            fit.fit_text(),
+            fit.best_quant,
+            fit.memory_required_gb,
+            format_context(fit.model.context_length),
+        );
+    }
+    let pool = if pair.shared_pool {
+        format!(
+            "{:.1} GB available",
+            pair.generator
+                .memory_available_gb
+                .min(pair.embedder.memory_available_gb)
+        )
+    } else {
+        "separate GPU and CPU pools".to_string()
+    };
+    println!(
+        "{:<11} {:.1} GB together ({})",
+        "Memory:".bold(),
+        pair.combined_memory_gb,
+        pool
+    );
+}
+
+/// `recommend --rag --json`: the pair with both fits in the usual JSON shape.
+pub fn display_json_rag_pair(specs: &SystemSpecs, pair: Option<&RagPair>) {
+    let rag = pair.map(|pair| {
+        serde_json::json!({
+            "generator": fit_to_json(&pair.generator),
+            "embedder": fit_to_json(&pair.embedder),
+            "generator_score": pair.generator_score,
+            "combined_memory_gb": round2(pair.combined_memory_gb),
+            "shared_pool": pair.shared_pool,
+        })
+    });
+    let output = serde_json::json!({
+        "system": system_json(specs),
+        "rag": rag,
+    });
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&output).expect("JSON serialization failed")
+    );
+}
+
 pub fn display_model_detail(fit: &ModelFit) {
     println!("\n{}", format!("=== {} ===", fit.model.name).bold().cyan());
     println!();
@@ -260,6 +352,9 @@ pub fn display_model_detail(fit: &ModelFit) {
         fit.fit_emoji(),
         fit.fit_text().color(fit_color)
     );
+    if let Some(freed) = fit.fit_level_if_freed {
+        println!("  After closing apps: {}", fit_level_text(freed));
+    }
     println!("  Run Mode: {}", fit.run_mode_text());
     println!(
         "  Memory Utilization: {:.1}% ({:.1} / {:.1} GB)",
@@ -413,6 +508,11 @@ pub fn display_model_diff(fits: &[ModelFit], sort_label: &str) {
         metric_width,
         col_width,
     );
+
+    if let [a, b] = fits {
+        let diff = llmfit_core::fit::explain_ranking(a, b);
+        println!("\n{} {}", "Why:".bold(), diff.summary());
+    }
 }
 
 fn print_metric_row(metric: &str, values: Vec<String>, metric_width: usize, col_width: usize) {
@@ -472,7 +572,7 @@ pub fn display_search_results(models: &[&LlmModel], query: &str) {
             runtime: "-".to_string(),
             mode: "-".to_string(),
             mem_use: "-".to_string(),
-            context: format!("{}k", m.context_length / 1000),
+            context: format_context(m.context_length),
             release_date: m
                 .release_date
                 .clone()
@@ -484,6 +584,50 @@ pub fn display_search_results(models: &[&LlmModel], query: &str) {
     println!("{}", table);
 }
 
+#[derive(Tabled)]
+struct DownloadRow {
+    #[tabled(rename = "Date")]
+    date: String,
+    #[tabled(rename = "Model")]
+    model: String,
+    #[tabled(rename = "Provider")]
+    provider: String,
+    #[tabled(rename = "Size")]
+    size: String,
+    #[tabled(rename = "Status")]
+    status: String,
+}
+
+pub fn display_download_history(records: &[&DownloadRecord]) {
+    if records.is_empty() {
+        println!("\n{}", "No downloads recorded yet.".yellow());
+        return;
+    }
+
+    println!("\n{}", "=== Download History ===".bold().cyan());
+    println!("Showing {} most recent download(s)\n", records.len());
+
+    let rows: Vec<DownloadRow> = records
+        .iter()
+        .map(|r| DownloadRow {
+            date: format_epoch(r.timestamp),
+            model: r.model_name.clone(),
+            provider: r.provider.clone(),
+            size: r
+                .size_gb
+                .map(|gb| format!("{:.1} GB", gb))
+                .unwrap_or_else(|| "\u{2014}".to_string()),
+            status: match &r.result {
+                DownloadResult::Success => "ok".green().to_string(),
+                DownloadResult::Error(e) => format!("{} {}", "failed:".red(), e),
+            },
+        })
+        .collect();
+
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    println!("{}", table);
+}
+
 // ────────────────────────────────────────────────────────────────────
 // JSON output for machine consumption (OpenClaw skills, scripts, etc.)
 // ────────────────────────────────────────────────────────────────────
@@ -499,6 +643,17 @@ pub fn display_json_system(specs: &SystemSpecs) {
     );
 }
 
+/// Serialize download history records (newest first) to JSON and print to stdout.
+pub fn display_json_download_history(records: &[&DownloadRecord]) {
+    let output = serde_json::json!({
+        "downloads": records,
+    });
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&output).expect("JSON serialization failed")
+    );
+}
+
 /// Serialize system specs + model fits to JSON and print to stdout.
 pub fn display_json_fits(specs: &SystemSpecs, fits: &[ModelFit]) {
     let models: Vec<serde_json::Value> = fits.iter().map(fit_to_json).collect();
@@ -608,8 +763,11 @@ fn display_estimate_basis(fit: &ModelFit) {
                 );
             }
         }
-        "cpu_constant" => {
-            println!("  Method: CPU heuristic constant (no GPU acceleration assumed)");
+        "cpu_bandwidth_roofline" => {
+            println!(
+                "  Method: CPU roofline — lower of ~{:.0} GB/s RAM bandwidth and the core count",
+                basis.ddr_bandwidth_gbps.unwrap_or_default()
+            );
         }
         _ => {
             println!("  Method: per-backend heuristic constant — GPU not in the bandwidth table,");
@@ -619,6 +777,12 @@ fn display_estimate_basis(fit: &ModelFit) {
             );
         }
     }
+    if let Some(factor) = basis.backend_tps_factor.filter(|f| *f != 1.0) {
+        println!(
+            "  Vulkan backend: x{:.2} (llama.cpp on Vulkan runs below the ROCm-calibrated roofline)",
+            factor
+        );
+    }
     println!(
         "  Models single-request generation at ctx <= {} tokens; prompt processing",
         basis.assumed_context
@@ -638,6 +802,28 @@ fn display_estimate_basis(fit: &ModelFit) {
     println!();
 }
 
+/// A ready-to-run command for a model fit, for copying from the TUI:
+/// `mlx_lm.chat` for MLX, `vllm serve` for vLLM, `ollama run` when the model
+/// has an Ollama tag, and otherwise the llama.cpp command.
+pub fn generate_run_command(fit: &ModelFit) -> Option<String> {
+    match fit.runtime {
+        InferenceRuntime::Mlx => {
+            let tag = llmfit_core::providers::mlx_pull_tag(&fit.model.name);
+            let repo = if tag.contains('/') {
+                tag
+            } else {
+                format!("mlx-community/{tag}")
+            };
+            Some(format!("mlx_lm.chat --model {repo}"))
+        }
+        InferenceRuntime::Vllm => Some(format!("vllm serve {}", fit.model.name)),
+        InferenceRuntime::LlamaCpp => llmfit_core::providers::ollama_pull_tag(&fit.model.name)
+            .map(|tag| format!("ollama run {tag}"))
+            .or_else(|| generate_llamacpp_command(fit)),
+        InferenceRuntime::Unsupported => None,
+    }
+}
+
 /// Generate a llama.cpp command string for a model fit.
 fn generate_llamacpp_command(fit: &ModelFit) -> Option<String> {
     if fit.run_mode == RunMode::TensorParallel {
@@ -705,9 +891,8 @@ fn llamacpp_supports_fit_arg() -> bool {
 
     *SUPPORTS_FIT_ARG.get_or_init(|| {
         let candidate = llamacpp_binary_arg();
-        let Ok(output) = std::process::Command::new(&candidate)
-            .arg("--help")
-            .output()
+        let Ok(output) =
+            llmfit_core::subprocess::output(std::process::Command::new(&candidate).arg("--help"))
         else {
             return false;
         };
@@ -789,6 +974,10 @@ fn fit_to_json(fit: &ModelFit) -> serde_json::Value {
         .as_object_mut()
         .expect("fit_to_json returns an object");
     obj.insert("fit_level".to_string(), serde_json::json!(fit.fit_text()));
+    obj.insert(
+        "fit_level_if_freed".to_string(),
+        serde_json::json!(fit.fit_level_if_freed.map(fit_level_text)),
+    );
     obj.insert(
         "run_mode".to_string(),
         serde_json::json!(fit.run_mode_text()),
@@ -820,6 +1009,13 @@ pub fn display_model_plan(plan: &PlanEstimate) {
     println!("{} {}", "Model:".bold(), plan.model_name);
     println!("{} {}", "Provider:".bold(), plan.provider);
     println!("{} {}", "Context:".bold(), plan.context);
+    if plan.batch_size > 1 {
+        println!(
+            "{} {} concurrent requests, each with its own KV cache",
+            "Batch size:".bold(),
+            plan.batch_size
+        );
+    }
     println!("{} {}", "Quantization:".bold(), plan.quantization);
     println!("{} {}", "KV cache:".bold(), plan.kv_quant.label());
     if let Some(tps) = plan.target_tps {
@@ -910,6 +1106,46 @@ pub fn display_model_plan(plan: &PlanEstimate) {
         }
         println!();
     }
+
+    if let Some(conv) = &plan.conversation {
+        println!(
+            "{}",
+            format!("Conversation ({} turns):", conv.turns)
+                .bold()
+                .underline()
+        );
+        println!("  {:>6} {:>9} {:>10}", "turn", "context", "memory");
+        for point in &conv.growth {
+            let mem = format!("{:>10}", format!("{:.1} GB", point.memory_required_gb));
+            let mem = if point.memory_required_gb > conv.memory_budget_gb {
+                mem.red().to_string()
+            } else {
+                mem
+            };
+            println!("  {:>6} {:>9} {}", point.turn, point.context, mem);
+        }
+        match conv.exhausts_memory_at_turn {
+            Some(turn) => println!(
+                "  {}",
+                format!(
+                    "Runs out of the {:.1} GB budget at turn {} ({} tokens per turn)",
+                    conv.memory_budget_gb, turn, conv.tokens_per_turn
+                )
+                .yellow()
+            ),
+            None => println!(
+                "  Stays within the {:.1} GB budget for all {} turns",
+                conv.memory_budget_gb, conv.turns
+            ),
+        }
+        if let Some(turn) = conv.context_full_at_turn {
+            println!(
+                "  Context window full at turn {}; older turns get dropped from there",
+                turn
+            );
+        }
+        println!();
+    }
 }
 
 pub fn display_json_plan(plan: &PlanEstimate) {
@@ -991,6 +1227,51 @@ pub fn display_csv_fits(fits: &[ModelFit]) {
     writer.flush().expect("CSV flush failed");
 }
 
+/// Flat row struct for `llmfit list --format csv`.
+#[derive(serde::Serialize)]
+struct CsvModelRow<'a> {
+    name: &'a str,
+    provider: &'a str,
+    parameter_count: &'a str,
+    params_billion: f64,
+    min_ram_gb: f64,
+    recommended_ram_gb: f64,
+    min_vram_gb: Option<f64>,
+    quantization: &'a str,
+    context_length: u32,
+    use_case: &'a str,
+    release_date: Option<&'a str>,
+    license: Option<&'a str>,
+    is_moe: bool,
+}
+
+/// Serialize the model catalog as CSV to stdout.
+pub fn display_csv_models(models: &[LlmModel], sort: SortColumn) {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+
+    for m in sort_catalog(models, sort) {
+        writer
+            .serialize(CsvModelRow {
+                name: &m.name,
+                provider: &m.provider,
+                parameter_count: &m.parameter_count,
+                params_billion: round2(m.params_b()),
+                min_ram_gb: round2(m.min_ram_gb),
+                recommended_ram_gb: round2(m.recommended_ram_gb),
+                min_vram_gb: m.min_vram_gb.map(round2),
+                quantization: &m.quantization,
+                context_length: m.context_length,
+                use_case: &m.use_case,
+                release_date: m.release_date.as_deref(),
+                license: m.license.as_deref(),
+                is_moe: m.is_moe,
+            })
+            .expect("CSV serialization failed");
+    }
+
+    writer.flush().expect("CSV flush failed");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1033,8 +1314,13 @@ mod tests {
                 vocab_size: None,
                 shared_expert_intermediate_size: None,
                 architecture: None,
+                family: String::new(),
+                trained_context: None,
+                max_context: None,
             },
             fit_level: FitLevel::Good,
+            fit_level_if_freed: None,
+            actual_weights_gb: None,
             run_mode,
             memory_required_gb: 4.0,
             memory_available_gb: 8.0,
@@ -1053,8 +1339,11 @@ mod tests {
             use_case,
             runtime: InferenceRuntime::LlamaCpp,
             installed: false,
+            installed_provider: None,
             fits_with_turboquant: false,
             effective_context_length: 8_192,
+            batch_size: 1,
+            kv_quant: llmfit_core::models::KvQuant::Fp16,
             usable_context: 8_192,
             estimate_basis: Default::default(),
             measured_tps: None,
@@ -1087,6 +1376,39 @@ mod tests {
         assert_eq!(shared["capability_ids"], serde_json::json!(["tool_use"]));
     }
 
+    #[test]
+    fn run_command_prefers_ollama_tag_then_llamacpp() {
+        let mut fit = mock_fit(RunMode::Gpu, UseCase::Chat, "chat");
+        assert!(
+            generate_run_command(&fit)
+                .expect("expected command")
+                .starts_with("llama-cli -hf test/model-7b-GGUF:Q4_K_M")
+        );
+
+        fit.model.name = "meta-llama/Llama-3.1-8B-Instruct".to_string();
+        assert_eq!(
+            generate_run_command(&fit).as_deref(),
+            Some("ollama run llama3.1:8b")
+        );
+    }
+
+    #[test]
+    fn run_command_follows_mlx_and_vllm_runtimes() {
+        let mut fit = mock_fit(RunMode::Gpu, UseCase::Chat, "chat");
+        fit.runtime = InferenceRuntime::Mlx;
+        assert!(
+            generate_run_command(&fit)
+                .expect("expected command")
+                .starts_with("mlx_lm.chat --model mlx-community/")
+        );
+
+        fit.runtime = InferenceRuntime::Vllm;
+        assert_eq!(
+            generate_run_command(&fit).as_deref(),
+            Some("vllm serve test/model-7b")
+        );
+    }
+
     #[test]
     fn llamacpp_command_uses_effective_context() {
         let fit = mock_fit(RunMode::Gpu, UseCase::Chat, "chat");
@@ -19,12 +19,18 @@ pub struct DownloadRecord {
     pub timestamp: u64,
     /// File path on disk, for providers that store files directly (e.g. LlamaCpp).
     pub file_path: Option<String>,
+    /// Estimated download size for the chosen quantization, when known.
+    #[serde(default)]
+    pub size_gb: Option<f64>,
 }
 
 /// Persistent download history, saved to `~/.config/llmfit/download_history.json`.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DownloadHistory {
     pub records: Vec<DownloadRecord>,
+    /// Where `save` writes; `None` (the default) keeps the history in memory.
+    #[serde(skip)]
+    path: Option<PathBuf>,
 }
 
 const MAX_RECORDS: usize = 100;
@@ -39,19 +45,23 @@ impl DownloadHistory {
     }
 
     pub fn load() -> Self {
-        Self::config_path()
+        let path = Self::config_path();
+        let mut history: Self = path
+            .as_ref()
             .and_then(|path| fs::read_to_string(path).ok())
             .and_then(|s| serde_json::from_str(&s).ok())
-            .unwrap_or_default()
+            .unwrap_or_default();
+        history.path = path;
+        history
     }
 
     pub fn save(&self) {
-        if let Some(path) = Self::config_path() {
+        if let Some(path) = &self.path {
             if let Some(parent) = path.parent() {
                 let _ = fs::create_dir_all(parent);
             }
             if let Ok(json) = serde_json::to_string_pretty(self) {
-                let _ = fs::write(&path, json);
+                let _ = fs::write(path, json);
             }
         }
     }
@@ -66,6 +76,11 @@ impl DownloadHistory {
         self.save();
     }
 
+    /// The most recent `limit` records, newest first.
+    pub fn recent(&self, limit: usize) -> impl Iterator<Item = &DownloadRecord> {
+        self.records.iter().rev().take(limit)
+    }
+
     pub fn remove(&mut self, index: usize) {
         if index < self.records.len() {
             self.records.remove(index);
@@ -80,3 +95,49 @@ impl DownloadHistory {
             .unwrap_or(0)
     }
 }
+
+/// Format epoch seconds as a simple `YYYY-MM-DD` date string (UTC).
+pub fn format_epoch(epoch: u64) -> String {
+    llmfit_core::dateutil::format_days((epoch / 86_400) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, timestamp: u64) -> DownloadRecord {
+        DownloadRecord {
+            model_name: name.to_string(),
+            provider: "Ollama".to_string(),
+            result: DownloadResult::Success,
+            timestamp,
+            file_path: None,
+            size_gb: None,
+        }
+    }
+
+    #[test]
+    fn add_record_keeps_the_newest_records_up_to_the_cap() {
+        let mut history = DownloadHistory::default();
+        for i in 0..(MAX_RECORDS as u64 + 5) {
+            history.add_record(record(&format!("model-{i}"), i));
+        }
+        assert_eq!(history.records.len(), MAX_RECORDS);
+        // The five oldest entries were dropped.
+        assert_eq!(history.records[0].model_name, "model-5");
+        assert_eq!(
+            history.records.last().unwrap().model_name,
+            format!("model-{}", MAX_RECORDS + 4)
+        );
+    }
+
+    #[test]
+    fn recent_lists_newest_first() {
+        let mut history = DownloadHistory::default();
+        history.add_record(record("first", 1));
+        history.add_record(record("second", 2));
+        history.add_record(record("third", 3));
+        let names: Vec<_> = history.recent(2).map(|r| r.model_name.as_str()).collect();
+        assert_eq!(names, vec!["third", "second"]);
+    }
+}
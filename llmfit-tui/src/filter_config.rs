@@ -1,15 +1,20 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Default file name for views exported/imported from the TUI.
+pub const VIEW_FILE: &str = "llmfit-view.json";
 
 /// Persisted filter state, saved to `~/.config/llmfit/filters.json`.
+/// The same format is used for exported views (`E`/`O` in the TUI,
+/// `--view <FILE>` on the command line).
 ///
 /// Every field is optional so the file degrades gracefully when new filters are
 /// added or the model database changes between runs.  Multi-select filters are
 /// stored as `name -> selected` maps so additions/removals in the model list
 /// don't corrupt saved state.
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct FilterConfig {
     pub fit_filter: Option<String>,
     pub availability_filter: Option<String>,
@@ -17,6 +22,8 @@ pub struct FilterConfig {
     pub sort_column: Option<String>,
     pub sort_ascending: Option<bool>,
     pub installed_first: Option<bool>,
+    /// Params column and filters use active parameters for MoE models.
+    pub params_active: Option<bool>,
     pub search_query: Option<String>,
 
     // Multi-select popup filters: name → selected
@@ -34,6 +41,9 @@ pub struct FilterConfig {
     pub filter_params_max: Option<String>,
     pub filter_mem_pct_min: Option<String>,
     pub filter_mem_pct_max: Option<String>,
+    /// Minimum acceptable tok/s, and whether slower models are hidden.
+    pub target_tps: Option<String>,
+    pub target_tps_only: Option<bool>,
 
     /// Custom download directory for GGUF models.
     pub download_dir: Option<String>,
@@ -65,6 +75,18 @@ impl FilterConfig {
         }
     }
 
+    /// Write this config to an arbitrary file, e.g. to share a view.
+    pub fn export_to(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, json).map_err(|e| e.to_string())
+    }
+
+    /// Read a config previously written by `export_to`.
+    pub fn import_from(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&contents).map_err(|e| format!("invalid view file: {}", e))
+    }
+
     /// Apply a saved name→selected map onto a positional `Vec<bool>`,
     /// matching by the corresponding names vector.  Entries not present
     /// in the saved map keep their current (default) value.
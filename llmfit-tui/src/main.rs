@@ -4,6 +4,8 @@ mod download_history;
 mod events;
 mod filter_config;
 mod mcp_server;
+mod model_notes;
+mod seen_models;
 mod serve_api;
 mod serve_shared;
 mod theme;
@@ -18,7 +20,7 @@ use std::thread;
 use std::time::Duration;
 
 use llmfit_core::bench;
-use llmfit_core::fit::{ModelFit, SortColumn, backend_compatible};
+use llmfit_core::fit::{CalcConfig, MarkdownColumn, ModelFit, SortColumn, backend_compatible};
 use llmfit_core::hardware::SystemSpecs;
 use llmfit_core::models::ModelDatabase;
 use llmfit_core::plan::{PlanRequest, estimate_model_plan, resolve_model_selector};
@@ -35,6 +37,22 @@ fn parse_positive_usize(value: &str) -> Result<usize, String> {
     Ok(parsed)
 }
 
+fn parse_backend_name(value: &str) -> Result<String, String> {
+    llmfit_core::hardware::parse_backend(value)
+        .map(|_| value.trim().to_lowercase())
+        .ok_or_else(|| {
+            format!(
+                "unknown backend: {value} (expected one of: cpu, cuda, metal, rocm, vulkan, sycl, ascend, openvino)"
+            )
+        })
+}
+
+fn parse_quant(value: &str) -> Result<String, String> {
+    llmfit_core::plan::supported_quant(value)
+        .map(str::to_string)
+        .ok_or_else(|| format!("unknown quantization: {value} (e.g. Q8_0, Q4_K_M, mlx-4bit)"))
+}
+
 const DEFAULT_DASHBOARD_HOST: &str = "127.0.0.1";
 const DEFAULT_DASHBOARD_PORT: u16 = 8787;
 
@@ -79,6 +97,15 @@ impl From<SortArg> for SortColumn {
     }
 }
 
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+    /// GitHub-Flavored Markdown table of fits against this machine
+    Markdown,
+}
+
 #[derive(clap::ValueEnum, Clone, Copy, Debug)]
 enum FitArg {
     All,
@@ -106,8 +133,13 @@ GLOBAL FLAGS:
   --memory <SIZE>    Override GPU VRAM (e.g. \"32G\", \"32000M\", \"1.5T\").
   --ram <SIZE>       Override system RAM (e.g. \"64G\", \"128000M\").
   --cpu-cores <N>    Override detected CPU core count.
+  --backend <NAME>   Pin the acceleration backend (cpu, cuda, metal, rocm,
+                     vulkan, sycl, ascend, openvino) to compare rankings,
+                     e.g. CPU-only.
   --max-context N    Cap context length for memory estimation (tokens).
                      Falls back to OLLAMA_CONTEXT_LENGTH env var if unset.
+  --batch-size N     Concurrent requests per model (shared team servers);
+                     reserves N × the per-request KV cache. Default 1.
 
 EXIT CODES:
   0  Success
@@ -164,11 +196,28 @@ struct Cli {
     #[arg(long, value_name = "CORES", value_parser = parse_positive_usize)]
     cpu_cores: Option<usize>,
 
+    /// Pin the acceleration backend for every fit (cpu, cuda, metal, rocm,
+    /// vulkan, sycl, ascend, openvino). `cpu` ignores detected GPUs entirely, which is
+    /// handy for comparing CPU-only rankings on a GPU machine.
+    #[arg(long, value_name = "NAME", value_parser = parse_backend_name)]
+    backend: Option<String>,
+
     /// Cap context length used for memory estimation (tokens).
     /// Falls back to OLLAMA_CONTEXT_LENGTH if not set.
     #[arg(long, value_name = "TOKENS", value_parser = clap::value_parser!(u32).range(1..))]
     max_context: Option<u32>,
 
+    /// Concurrent requests the inference server handles (e.g. Ollama's
+    /// OLLAMA_NUM_PARALLEL). Each holds its own KV cache, so fit accounts
+    /// for batch-size × the per-request KV cache.
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    batch_size: u32,
+
+    /// Size and score every model at this quantization (e.g. Q4_K_M)
+    /// instead of the best one that fits. Pre-quantized models keep theirs.
+    #[arg(long, value_name = "QUANT", value_parser = parse_quant)]
+    quant: Option<String>,
+
     /// Force the interactive TUI, ignoring any subcommand or output flags.
     /// Useful in Docker where a baked-in CMD would otherwise run a subcommand:
     /// docker run --rm -it ghcr.io/alexsjones/llmfit --tui
@@ -183,6 +232,11 @@ struct Cli {
     /// Falls back to LOCALMAXXING_API_KEY env var.
     #[arg(long, value_name = "KEY", env = "LOCALMAXXING_API_KEY")]
     api_key: Option<String>,
+
+    /// Start the TUI with a view (filters, sort, search) previously
+    /// exported with `E`.
+    #[arg(long, value_name = "FILE")]
+    view: Option<std::path::PathBuf>,
 }
 
 #[derive(Subcommand)]
@@ -299,6 +353,12 @@ EXIT CODES:
 
 AGENT USAGE:
   llmfit list --json
+  llmfit list --format csv
+  llmfit list --markdown > models.md
+
+  Markdown output scores every model against this machine and prints a
+  GitHub-Flavored Markdown table (model, params, score, tok/s, memory, fit,
+  quant) for pasting into issues and docs.
 
   JSON output: array of model objects with fields: name, provider,
   parameter_count, min_ram_gb, recommended_ram_gb, min_vram_gb,
@@ -307,6 +367,12 @@ AGENT USAGE:
         /// Sort models by column: date, params, ctx, mem
         #[arg(long, value_enum, default_value_t = SortArg::Date)]
         sort: SortArg,
+        /// Output format (defaults to table, or the global --json / --csv)
+        #[arg(long, value_enum)]
+        format: Option<OutputFormat>,
+        /// Shorthand for --format markdown
+        #[arg(long, conflicts_with = "format")]
+        markdown: bool,
     },
 
     /// Find models that fit your system (classic table output)
@@ -413,7 +479,9 @@ Compare two models side-by-side, or auto-compare top N filtered models.
 
 When two model selectors are given, compares those two models. When none are
 given, picks the top N models (default 2) after applying fit-level and sort
-filters, and compares them.
+filters, and compares them. With exactly two models, a closing \"Why:\" line
+explains the ranking in plain English, e.g. \"A wins on quality (+13.5) but
+loses on speed (-6.0); net +7.5\".
 
 PRECONDITIONS:
   Requires hardware detection for fit analysis. At least 2 models must pass
@@ -500,6 +568,11 @@ AGENT USAGE:
         /// Target decode speed in tokens/sec
         #[arg(long, value_name = "TOK_S")]
         target_tps: Option<f64>,
+
+        /// Also estimate memory over a chat of this many turns, each adding
+        /// 512 tokens of history on top of --context
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        turns: Option<u32>,
     },
 
     /// Recommend top models for your hardware (JSON-friendly)
@@ -527,13 +600,17 @@ AGENT USAGE:
   llmfit recommend --force-runtime llamacpp  # get llama.cpp results on Apple Silicon
   llmfit recommend --license apache-2.0,mit
   llmfit recommend --output-llamacpp  # include llama.cpp commands in output
+  llmfit recommend --rag  # generator + embedding model pair for RAG
 
   JSON output is the default. Fields: { system: {...}, models: [{ name,
   provider, parameter_count, fit_level, run_mode, score, score_components
   { quality, speed, fit, context }, estimated_tps, disk_size_gb,
   memory_required_gb, memory_available_gb, utilization_pct, best_quant,
   effective_context_length, use_case, license, runtime, capabilities,
-  llamacpp_command (when --output-llamacpp) }] }")]
+  llamacpp_command (when --output-llamacpp) }] }
+  With --rag: { system: {...}, rag: { generator: {...}, embedder: {...},
+  generator_score, combined_memory_gb, shared_pool } } (rag is null when no
+  pair fits).")]
     Recommend {
         /// Limit number of recommendations
         #[arg(short = 'n', long, default_value = "5")]
@@ -556,7 +633,7 @@ AGENT USAGE:
         #[arg(long, value_name = "RUNTIME")]
         force_runtime: Option<String>,
 
-        /// Filter by capability: vision, tool_use, audio, tts (comma-separated)
+        /// Filter by capability: vision, tool_use, json_mode, audio, tts (comma-separated)
         #[arg(long, value_name = "CAPS")]
         capability: Option<String>,
 
@@ -571,6 +648,11 @@ AGENT USAGE:
         /// Include suggested llama.cpp commands in output for llama.cpp-compatible models
         #[arg(long)]
         output_llamacpp: bool,
+
+        /// Recommend a generator + embedding model pair for RAG that fits
+        /// in memory together (long-context, instruction-following generator)
+        #[arg(long, conflicts_with = "use_case")]
+        rag: bool,
     },
 
     /// Download a GGUF model from HuggingFace for use with llama.cpp
@@ -625,6 +707,69 @@ AGENT USAGE:
         output_dir: Option<std::path::PathBuf>,
     },
 
+    /// Print a Bash script that installs the models that fit this machine
+    #[command(long_about = "\
+Print a Bash script that installs the models that fit this machine.
+
+Each model gets one command, preceded by a comment with its name, score and
+download size: 'ollama pull <tag>' for models with an Ollama tag,
+'mlx_lm.convert --hf-path <repo>' for MLX models, and 'huggingface-cli
+download <repo>' for GGUF weights. Useful for setting up a new machine in one
+go: llmfit export-install-script > install.sh
+
+PRECONDITIONS:
+  None. Running the script needs the matching tools installed.
+
+SIDE EFFECTS:
+  None — the script is written to stdout, nothing is downloaded.
+
+EXIT CODES:
+  0  Success
+  1  Invalid --filter or --provider value
+
+AGENT USAGE:
+  llmfit export-install-script --filter fit=perfect --provider ollama")]
+    ExportInstallScript {
+        /// Fit levels to include: fit=<perfect|good|marginal|tight>, with a
+        /// trailing '+' for that level or better
+        #[arg(long, default_value = "fit=good+")]
+        filter: String,
+
+        /// Only use one download source: ollama, mlx or gguf. By default each
+        /// model uses the source the TUI would pull it from.
+        #[arg(long)]
+        provider: Option<String>,
+    },
+
+    /// Show recent model downloads and their outcome
+    #[command(long_about = "\
+Show recent model downloads and their outcome.
+
+Every pull started from the TUI (Ollama, llama.cpp, MLX, Docker, LM Studio,
+vLLM) is recorded with its provider, timestamp, estimated size and whether it
+succeeded. Useful for tracking which models you have tried and for debugging
+repeated failed downloads.
+
+PRECONDITIONS:
+  None. Prints an empty list if nothing has been downloaded yet.
+
+SIDE EFFECTS:
+  None — reads ~/.config/llmfit/download_history.json.
+
+EXIT CODES:
+  0  Success
+
+AGENT USAGE:
+  llmfit history --json -n 50
+
+  JSON output fields: { downloads: [{ model_name, provider, result,
+  timestamp, file_path, size_gb }] } (newest first)")]
+    History {
+        /// Number of records to show
+        #[arg(short = 'n', long, default_value = "20")]
+        limit: usize,
+    },
+
     /// Search HuggingFace for GGUF models compatible with llama.cpp
     #[command(long_about = "\
 Search HuggingFace for GGUF models compatible with llama.cpp.
@@ -847,6 +992,7 @@ pub(crate) struct HardwareOverrides {
     pub memory: Option<String>,
     pub ram: Option<String>,
     pub cpu_cores: Option<usize>,
+    pub backend: Option<String>,
 }
 
 /// Detect system specs with optional hardware overrides.
@@ -854,6 +1000,11 @@ pub(crate) struct HardwareOverrides {
 /// on unified-memory systems where `--ram` would also update VRAM.
 pub(crate) fn detect_specs(overrides: &HardwareOverrides) -> SystemSpecs {
     let mut specs = SystemSpecs::detect();
+    if let Some(warning) =
+        llmfit_core::hardware::rosetta_warning(llmfit_core::hardware::is_rosetta_translated())
+    {
+        eprintln!("Warning: {warning}");
+    }
 
     if let Some(ram_str) = &overrides.ram {
         match llmfit_core::hardware::parse_memory_size(ram_str) {
@@ -883,6 +1034,15 @@ pub(crate) fn detect_specs(overrides: &HardwareOverrides) -> SystemSpecs {
         specs = specs.with_cpu_core_override(cores);
     }
 
+    // `--backend` is validated by clap, so an unknown name can't reach here.
+    if let Some(backend) = overrides
+        .backend
+        .as_deref()
+        .and_then(llmfit_core::hardware::parse_backend)
+    {
+        specs = specs.with_backend_override(backend);
+    }
+
     specs
 }
 
@@ -997,6 +1157,9 @@ fn ensure_dashboard_available(
     if let Some(cores) = overrides.cpu_cores {
         command.arg("--cpu-cores").arg(cores.to_string());
     }
+    if let Some(backend) = &overrides.backend {
+        command.arg("--backend").arg(backend);
+    }
     if let Some(ctx) = context_limit {
         command.arg("--max-context").arg(ctx.to_string());
     }
@@ -1011,7 +1174,7 @@ fn ensure_dashboard_available(
         .stdout(Stdio::null())
         .stderr(Stdio::null());
 
-    let mut child = match command.spawn() {
+    let mut child = match llmfit_core::subprocess::spawn(&mut command) {
         Ok(child) => child,
         Err(err) => {
             eprintln!("Warning: could not start dashboard server: {err}");
@@ -1056,6 +1219,7 @@ fn run_fit(
     csv: bool,
     overrides: &HardwareOverrides,
     context_limit: Option<u32>,
+    calc_config: &CalcConfig,
 ) {
     let specs = detect_specs(overrides);
     let db = ModelDatabase::new();
@@ -1072,19 +1236,21 @@ fn run_fit(
         .filter(|m| !backend_compatible(m, &specs))
         .count();
 
-    let mut fits =
-        llmfit_core::analysis::build_model_fits(&db, &specs, &installed, context_limit, None);
+    let mut fits = llmfit_core::analysis::build_model_fits_with_config(
+        &db,
+        &specs,
+        &installed,
+        context_limit,
+        None,
+        calc_config,
+    );
 
     if perfect {
         fits.retain(|f| f.fit_level == llmfit_core::fit::FitLevel::Perfect);
     }
 
     if tool_use {
-        fits.retain(|f| {
-            f.model
-                .capabilities
-                .contains(&llmfit_core::models::Capability::ToolUse)
-        });
+        fits.retain(|f| f.model.supports_tools());
     }
 
     fits = llmfit_core::fit::rank_models_by_fit_opts_col(fits, false, sort);
@@ -1106,6 +1272,9 @@ fn run_fit(
             );
         }
         display::display_model_fits(&fits);
+        display::display_ollama_orphans(
+            &installed.ollama_orphans(db.iter().map(|m| m.name.as_str())),
+        );
     }
 }
 
@@ -1182,6 +1351,7 @@ fn run_diff(
     json: bool,
     overrides: &HardwareOverrides,
     context_limit: Option<u32>,
+    calc_config: &CalcConfig,
 ) {
     if limit < 2 {
         eprintln!("Error: --limit must be at least 2 for diff");
@@ -1200,7 +1370,15 @@ fn run_diff(
         .get_all_models()
         .iter()
         .filter(|m| backend_compatible(m, &specs))
-        .map(|m| ModelFit::analyze_with_context_limit(m, &specs, context_limit))
+        .map(|m| {
+            ModelFit::analyze_with_runtime_and_config(
+                m,
+                &specs,
+                context_limit,
+                None,
+                calc_config.clone(),
+            )
+        })
         .collect();
 
     fits.retain(|f| fit_matches_filter(f, fit_filter));
@@ -1248,24 +1426,29 @@ fn run_diff(
 fn run_tui(
     overrides: &HardwareOverrides,
     context_limit: Option<u32>,
+    calc_config: &CalcConfig,
     api_key: Option<String>,
+    view: Option<&std::path::Path>,
 ) -> std::io::Result<()> {
-    run_tui_inner(overrides, context_limit, api_key, false)
+    run_tui_inner(overrides, context_limit, calc_config, api_key, view, false)
 }
 
 /// Launch the TUI with the live-bench view pre-opened.
 fn run_tui_bench(
     overrides: &HardwareOverrides,
     context_limit: Option<u32>,
+    calc_config: &CalcConfig,
     api_key: Option<String>,
 ) -> std::io::Result<()> {
-    run_tui_inner(overrides, context_limit, api_key, true)
+    run_tui_inner(overrides, context_limit, calc_config, api_key, None, true)
 }
 
 fn run_tui_inner(
     overrides: &HardwareOverrides,
     context_limit: Option<u32>,
+    calc_config: &CalcConfig,
     api_key: Option<String>,
+    view: Option<&std::path::Path>,
     open_bench: bool,
 ) -> std::io::Result<()> {
     // Setup terminal
@@ -1285,10 +1468,21 @@ fn run_tui_inner(
     // Create app state (provider detection runs in background threads)
     let specs = detect_specs(overrides);
     let mut app = tui_app::App::with_specs_and_context(specs, context_limit);
+    app.check_new_models();
+    if calc_config.batch_size > 1 {
+        app.set_batch_size(calc_config.batch_size);
+    }
+    if calc_config.force_quant.is_some() {
+        app.set_force_quant(calc_config.force_quant.clone());
+    }
     if api_key.is_some() {
         app.bench_api_key = api_key;
     }
 
+    if let Some(path) = view {
+        app.import_view(path);
+    }
+
     if open_bench {
         app.open_bench();
     }
@@ -1322,6 +1516,10 @@ fn run_tui_inner(
     )?;
     terminal.show_cursor()?;
 
+    if let Some(script) = app.install_script_on_exit {
+        print!("{script}");
+    }
+
     Ok(())
 }
 
@@ -1359,6 +1557,83 @@ fn draw_boot_screen(
     Ok(())
 }
 
+/// Parse an install-script filter such as `fit=good+` into the accepted fit
+/// levels: the named level alone, or with `+` that level and every better one.
+fn parse_fit_filter(filter: &str) -> Result<Vec<llmfit_core::fit::FitLevel>, String> {
+    use llmfit_core::fit::FitLevel;
+
+    const LEVELS: [FitLevel; 4] = [
+        FitLevel::Perfect,
+        FitLevel::Good,
+        FitLevel::Marginal,
+        FitLevel::TooTight,
+    ];
+    let value = filter
+        .trim()
+        .strip_prefix("fit=")
+        .ok_or_else(|| format!("unsupported filter '{filter}' (expected fit=<level>[+])"))?;
+    let (name, or_better) = match value.strip_suffix('+') {
+        Some(name) => (name, true),
+        None => (value, false),
+    };
+    let pos = match name.to_lowercase().as_str() {
+        "perfect" => 0,
+        "good" => 1,
+        "marginal" => 2,
+        "tight" | "too_tight" | "too-tight" => 3,
+        other => {
+            return Err(format!(
+                "unknown fit level '{other}' (use perfect, good, marginal or tight)"
+            ));
+        }
+    };
+    Ok(if or_better {
+        LEVELS[..=pos].to_vec()
+    } else {
+        vec![LEVELS[pos]]
+    })
+}
+
+fn run_export_install_script(
+    filter: &str,
+    provider: Option<&str>,
+    overrides: &HardwareOverrides,
+    context_limit: Option<u32>,
+    calc_config: &CalcConfig,
+) -> Result<(), String> {
+    use llmfit_core::analysis::InstallSource;
+
+    let levels = parse_fit_filter(filter)?;
+    let source = provider
+        .map(|p| {
+            InstallSource::parse(p)
+                .ok_or_else(|| format!("unknown provider '{p}' (use ollama, mlx or gguf)"))
+        })
+        .transpose()?;
+
+    let specs = detect_specs(overrides);
+    let db = ModelDatabase::new();
+    let installed = llmfit_core::analysis::InstalledIndex::empty();
+    let fits =
+        llmfit_core::fit::rank_models_by_fit(llmfit_core::analysis::build_model_fits_with_config(
+            &db,
+            &specs,
+            &installed,
+            context_limit,
+            None,
+            calc_config,
+        ));
+    let selected: Vec<_> = fits
+        .iter()
+        .filter(|f| levels.contains(&f.fit_level))
+        .collect();
+    print!(
+        "{}",
+        llmfit_core::analysis::install_script(&selected, source)
+    );
+    Ok(())
+}
+
 fn run_recommend(
     limit: usize,
     use_case: Option<String>,
@@ -1370,8 +1645,10 @@ fn run_recommend(
     json: bool,
     csv: bool,
     output_llamacpp: bool,
+    rag: bool,
     overrides: &HardwareOverrides,
     context_limit: Option<u32>,
+    calc_config: &CalcConfig,
 ) {
     let specs = detect_specs(overrides);
     let db = ModelDatabase::new();
@@ -1394,8 +1671,14 @@ fn run_recommend(
 
     let installed = llmfit_core::analysis::InstalledIndex::detect_all();
 
-    let mut fits =
-        llmfit_core::analysis::build_model_fits(&db, &specs, &installed, context_limit, forced_rt);
+    let mut fits = llmfit_core::analysis::build_model_fits_with_config(
+        &db,
+        &specs,
+        &installed,
+        context_limit,
+        forced_rt,
+        calc_config,
+    );
 
     // Filter by minimum fit level
     let min_level = match min_fit.to_lowercase().as_str() {
@@ -1458,14 +1741,13 @@ fn run_recommend(
         if !requested.is_empty() {
             fits.retain(|f| {
                 requested.iter().all(|req| match req.as_str() {
-                    "vision" => f
-                        .model
-                        .capabilities
-                        .contains(&llmfit_core::models::Capability::Vision),
-                    "tool_use" | "tools" | "tool-use" | "function_calling" => f
-                        .model
-                        .capabilities
-                        .contains(&llmfit_core::models::Capability::ToolUse),
+                    "vision" => f.model.supports_vision(),
+                    "tool_use" | "tools" | "tool-use" | "function_calling" => {
+                        f.model.supports_tools()
+                    }
+                    "json_mode" | "json" | "json-mode" | "structured_output" => {
+                        f.model.supports_json_mode()
+                    }
                     "audio" => f
                         .model
                         .capabilities
@@ -1485,6 +1767,16 @@ fn run_recommend(
         fits.retain(|f| llmfit_core::models::matches_license_filter(&f.model.license, lic_str));
     }
 
+    if rag {
+        let pair = llmfit_core::analysis::recommend_rag_pair(&fits);
+        if json {
+            display::display_json_rag_pair(&specs, pair.as_ref());
+        } else {
+            display::display_rag_pair(pair.as_ref());
+        }
+        return;
+    }
+
     fits = llmfit_core::fit::rank_models_by_fit(fits);
     fits.truncate(limit);
 
@@ -1913,18 +2205,16 @@ fn run_model(model: &str, server: bool, port: u16, ngl: i32, ctx_size: u32) {
             port,
             model_path.display()
         );
-        let status = std::process::Command::new(bin)
-            .args([
-                "-m",
-                model_path.to_str().unwrap_or(""),
-                "--port",
-                &port.to_string(),
-                "-ngl",
-                &ngl.to_string(),
-                "-c",
-                &ctx_size.to_string(),
-            ])
-            .status();
+        let status = llmfit_core::subprocess::status(std::process::Command::new(bin).args([
+            "-m",
+            model_path.to_str().unwrap_or(""),
+            "--port",
+            &port.to_string(),
+            "-ngl",
+            &ngl.to_string(),
+            "-c",
+            &ctx_size.to_string(),
+        ]));
 
         match status {
             Ok(s) if !s.success() => {
@@ -1945,17 +2235,15 @@ fn run_model(model: &str, server: bool, port: u16, ngl: i32, ctx_size: u32) {
         };
 
         println!("Running {} with llama-cli...\n", model_path.display());
-        let status = std::process::Command::new(bin)
-            .args([
-                "-m",
-                model_path.to_str().unwrap_or(""),
-                "-ngl",
-                &ngl.to_string(),
-                "-c",
-                &ctx_size.to_string(),
-                "-cnv",
-            ])
-            .status();
+        let status = llmfit_core::subprocess::status(std::process::Command::new(bin).args([
+            "-m",
+            model_path.to_str().unwrap_or(""),
+            "-ngl",
+            &ngl.to_string(),
+            "-c",
+            &ctx_size.to_string(),
+            "-cnv",
+        ]));
 
         match status {
             Ok(s) if !s.success() => {
@@ -1970,14 +2258,17 @@ fn run_model(model: &str, server: bool, port: u16, ngl: i32, ctx_size: u32) {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn run_plan(
     model_selector: &str,
     context: u32,
     quant: Option<String>,
     kv_quant: Option<String>,
     target_tps: Option<f64>,
+    turns: Option<u32>,
     json: bool,
     overrides: &HardwareOverrides,
+    calc_config: &CalcConfig,
 ) -> Result<(), String> {
     let db = ModelDatabase::new();
     let specs = detect_specs(overrides);
@@ -2004,9 +2295,11 @@ fn run_plan(
 
     let request = PlanRequest {
         context,
-        quant,
+        quant: quant.or_else(|| calc_config.force_quant.clone()),
         target_tps,
         kv_quant,
+        turns,
+        batch_size: Some(calc_config.batch_size),
     };
     let plan = estimate_model_plan(model, &request, &specs)?;
 
@@ -2741,6 +3034,14 @@ fn main() {
         memory: cli.memory,
         ram: cli.ram,
         cpu_cores: cli.cpu_cores,
+        backend: cli.backend,
+    };
+    // The user's config.toml preferences plus the global calculation flags,
+    // shared by every command that analyzes fits.
+    let calc_config = CalcConfig {
+        batch_size: cli.batch_size,
+        force_quant: cli.quant.clone(),
+        ..CalcConfig::from_user_config()
     };
     let auto_dashboard = !cli.no_dashboard
         && (cli.tui
@@ -2756,7 +3057,13 @@ fn main() {
     // output flags, so a Docker image with a baked-in CMD can still launch
     // the TUI: docker run --rm -it ghcr.io/alexsjones/llmfit --tui
     if cli.tui {
-        if let Err(e) = run_tui(&overrides, context_limit, cli.api_key) {
+        if let Err(e) = run_tui(
+            &overrides,
+            context_limit,
+            &calc_config,
+            cli.api_key,
+            cli.view.as_deref(),
+        ) {
             eprintln!("Error running TUI: {}", e);
             std::process::exit(1);
         }
@@ -2822,16 +3129,51 @@ fn main() {
                 }
             }
 
-            Commands::List { sort } => {
+            Commands::List {
+                sort,
+                format,
+                markdown,
+            } => {
                 let db = ModelDatabase::new();
-                if cli.json {
-                    println!(
+                let format = format.unwrap_or(if markdown {
+                    OutputFormat::Markdown
+                } else if cli.json {
+                    OutputFormat::Json
+                } else if cli.csv {
+                    OutputFormat::Csv
+                } else {
+                    OutputFormat::Table
+                });
+                match format {
+                    OutputFormat::Json => println!(
                         "{}",
                         serde_json::to_string_pretty(db.get_all_models())
                             .expect("JSON serialization failed")
-                    );
-                } else {
-                    display::display_all_models(db.get_all_models(), sort.into());
+                    ),
+                    OutputFormat::Csv => {
+                        display::display_csv_models(db.get_all_models(), sort.into())
+                    }
+                    OutputFormat::Table => {
+                        display::display_all_models(db.get_all_models(), sort.into())
+                    }
+                    OutputFormat::Markdown => {
+                        let specs = detect_specs(&overrides);
+                        let fits = llmfit_core::analysis::build_model_fits_with_config(
+                            &db,
+                            &specs,
+                            &llmfit_core::analysis::InstalledIndex::empty(),
+                            context_limit,
+                            None,
+                            &calc_config,
+                        );
+                        let fits =
+                            llmfit_core::fit::rank_models_by_fit_opts_col(fits, false, sort.into());
+                        let fits: Vec<&ModelFit> = fits.iter().collect();
+                        print!(
+                            "{}",
+                            llmfit_core::fit::to_markdown_table(&fits, MarkdownColumn::ALL)
+                        );
+                    }
                 }
             }
 
@@ -2850,6 +3192,7 @@ fn main() {
                     cli.csv,
                     &overrides,
                     context_limit,
+                    &calc_config,
                 );
             }
 
@@ -2895,8 +3238,13 @@ fn main() {
                     }
                 };
 
-                let mut fit =
-                    ModelFit::analyze_with_context_limit(&models[idx], &specs, context_limit);
+                let mut fit = ModelFit::analyze_with_runtime_and_config(
+                    &models[idx],
+                    &specs,
+                    context_limit,
+                    None,
+                    calc_config.clone(),
+                );
                 fit.measured_tps = llmfit_core::benchmarks::measured_tps_for(
                     &specs,
                     &fit.model.name,
@@ -2925,6 +3273,7 @@ fn main() {
                     cli.json,
                     &overrides,
                     context_limit,
+                    &calc_config,
                 );
             }
 
@@ -2934,9 +3283,18 @@ fn main() {
                 quant,
                 kv_quant,
                 target_tps,
+                turns,
             } => {
                 if let Err(err) = run_plan(
-                    &model, context, quant, kv_quant, target_tps, cli.json, &overrides,
+                    &model,
+                    context,
+                    quant,
+                    kv_quant,
+                    target_tps,
+                    turns,
+                    cli.json,
+                    &overrides,
+                    &calc_config,
                 ) {
                     eprintln!("Error: {}", err);
                     std::process::exit(1);
@@ -2953,6 +3311,7 @@ fn main() {
                 license,
                 json,
                 output_llamacpp,
+                rag,
             } => {
                 run_recommend(
                     limit,
@@ -2965,8 +3324,10 @@ fn main() {
                     json,
                     cli.csv,
                     output_llamacpp,
+                    rag,
                     &overrides,
                     context_limit,
+                    &calc_config,
                 );
             }
 
@@ -2987,6 +3348,29 @@ fn main() {
                 );
             }
 
+            Commands::ExportInstallScript { filter, provider } => {
+                if let Err(err) = run_export_install_script(
+                    &filter,
+                    provider.as_deref(),
+                    &overrides,
+                    context_limit,
+                    &calc_config,
+                ) {
+                    eprintln!("Error: {}", err);
+                    std::process::exit(1);
+                }
+            }
+
+            Commands::History { limit } => {
+                let history = download_history::DownloadHistory::load();
+                let records: Vec<_> = history.recent(limit).collect();
+                if cli.json {
+                    display::display_json_download_history(&records);
+                } else {
+                    display::display_download_history(&records);
+                }
+            }
+
             Commands::HfSearch { query, limit } => {
                 run_hf_search(&query, limit);
             }
@@ -3072,7 +3456,9 @@ fn main() {
                 // No model/flags → launch bench TUI view
                 let is_bare = model.is_none() && !all && !json && !quality && !routing && !share;
                 if is_bare {
-                    if let Err(e) = run_tui_bench(&overrides, context_limit, cli.api_key) {
+                    if let Err(e) =
+                        run_tui_bench(&overrides, context_limit, &calc_config, cli.api_key)
+                    {
                         eprintln!("Error running bench TUI: {}", e);
                         std::process::exit(1);
                     }
@@ -3123,12 +3509,19 @@ fn main() {
             cli.csv,
             &overrides,
             context_limit,
+            &calc_config,
         );
         return;
     }
 
     // Default: launch TUI
-    if let Err(e) = run_tui(&overrides, context_limit, cli.api_key) {
+    if let Err(e) = run_tui(
+        &overrides,
+        context_limit,
+        &calc_config,
+        cli.api_key,
+        cli.view.as_deref(),
+    ) {
         eprintln!("Error running TUI: {}", e);
         std::process::exit(1);
     }
@@ -3173,8 +3566,13 @@ mod tests {
                 vocab_size: None,
                 shared_expert_intermediate_size: None,
                 architecture: None,
+                family: String::new(),
+                trained_context: None,
+                max_context: None,
             },
             fit_level,
+            fit_level_if_freed: None,
+            actual_weights_gb: None,
             run_mode: RunMode::Gpu,
             memory_required_gb: 4.0,
             memory_available_gb: 8.0,
@@ -3193,8 +3591,11 @@ mod tests {
             use_case: llmfit_core::models::UseCase::General,
             runtime: InferenceRuntime::LlamaCpp,
             installed: false,
+            installed_provider: None,
             fits_with_turboquant: false,
             effective_context_length: 8192,
+            batch_size: 1,
+            kv_quant: llmfit_core::models::KvQuant::Fp16,
             usable_context: 8192,
             estimate_basis: Default::default(),
             measured_tps: None,
@@ -3263,6 +3664,9 @@ mod tests {
                 vocab_size: None,
                 shared_expert_intermediate_size: None,
                 architecture: None,
+                family: String::new(),
+                trained_context: None,
+                max_context: None,
             },
             LlmModel {
                 name: "Qwen/Qwen3-Coder-Next".to_string(),
@@ -3295,6 +3699,9 @@ mod tests {
                 vocab_size: None,
                 shared_expert_intermediate_size: None,
                 architecture: None,
+                family: String::new(),
+                trained_context: None,
+                max_context: None,
             },
         ];
 
@@ -3317,4 +3724,18 @@ mod tests {
         // Exact max length — no truncation
         assert_eq!(truncate_str("abc", 3), "abc");
     }
+
+    #[test]
+    fn fit_filter_plus_includes_better_levels() {
+        assert_eq!(
+            parse_fit_filter("fit=good+").unwrap(),
+            vec![FitLevel::Perfect, FitLevel::Good]
+        );
+        assert_eq!(
+            parse_fit_filter("fit=marginal").unwrap(),
+            vec![FitLevel::Marginal]
+        );
+        assert!(parse_fit_filter("fit=great").is_err());
+        assert!(parse_fit_filter("score=80").is_err());
+    }
 }
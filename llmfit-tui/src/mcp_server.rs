@@ -1,5 +1,5 @@
 use llmfit_core::fit::{
-    FitLevel, InferenceRuntime, ModelFit, SortColumn, backend_compatible,
+    CalcConfig, FitLevel, InferenceRuntime, ModelFit, SortColumn, backend_compatible,
     rank_models_by_fit_opts_col,
 };
 use llmfit_core::hardware::{GpuBackend, SystemSpecs};
@@ -217,6 +217,8 @@ impl LlmfitMcpServer {
             quant: params.quant,
             target_tps: params.target_tps,
             kv_quant: None,
+            turns: None,
+            batch_size: None,
         };
 
         match estimate_model_plan(model, &request, &self.specs) {
@@ -333,7 +335,13 @@ impl LlmfitMcpServer {
             .iter()
             .filter(|m| backend_compatible(m, &self.specs))
             .map(|m| {
-                ModelFit::analyze_with_forced_runtime(m, &self.specs, self.context_limit, None)
+                ModelFit::analyze_with_runtime_and_config(
+                    m,
+                    &self.specs,
+                    self.context_limit,
+                    None,
+                    CalcConfig::from_user_config(),
+                )
             })
             .collect();
 
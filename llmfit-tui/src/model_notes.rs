@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// A user's own annotation for one model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ModelNote {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+    /// Lowercase tags without the leading `#`, in the order they were added.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl ModelNote {
+    /// Parse an editor line: `#words` become tags, everything else is the note.
+    /// `"too slow #tried #rag"` → note `"too slow"`, tags `["tried", "rag"]`.
+    pub fn parse(line: &str) -> Self {
+        let mut note = ModelNote::default();
+        let mut words = Vec::new();
+        for word in line.split_whitespace() {
+            match word.strip_prefix('#') {
+                Some(tag) => note.add_tag(tag),
+                None => words.push(word),
+            }
+        }
+        note.note = words.join(" ");
+        note
+    }
+
+    /// Inverse of [`ModelNote::parse`], used to pre-fill the editor.
+    pub fn to_line(&self) -> String {
+        self.tags
+            .iter()
+            .map(|t| format!("#{t}"))
+            .fold(self.note.clone(), |mut line, tag| {
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(&tag);
+                line
+            })
+    }
+
+    pub fn add_tag(&mut self, tag: &str) {
+        if let Some(tag) = normalize_tag(tag)
+            && !self.tags.contains(&tag)
+        {
+            self.tags.push(tag);
+        }
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        normalize_tag(tag).is_some_and(|tag| self.tags.contains(&tag))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.note.is_empty() && self.tags.is_empty()
+    }
+}
+
+/// Lowercase a tag and strip an optional leading `#`; `None` if nothing is left.
+fn normalize_tag(tag: &str) -> Option<String> {
+    let tag = tag.strip_prefix('#').unwrap_or(tag).trim().to_lowercase();
+    (!tag.is_empty()).then_some(tag)
+}
+
+/// Per-model notes and tags, saved to `~/.config/llmfit/model_notes.json`.
+/// Keyed by model name so entries survive catalog updates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ModelNotes {
+    #[serde(default)]
+    pub models: BTreeMap<String, ModelNote>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl ModelNotes {
+    fn config_path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("llmfit").join("model_notes.json"))
+    }
+
+    pub fn load() -> Self {
+        Self::load_from(Self::config_path())
+    }
+
+    fn load_from(path: Option<PathBuf>) -> Self {
+        let mut notes: Self = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        notes.path = path;
+        notes
+    }
+
+    pub fn save(&self) {
+        if let Some(path) = &self.path {
+            if let Some(parent) = path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            if let Ok(json) = serde_json::to_string_pretty(self) {
+                let _ = fs::write(path, json);
+            }
+        }
+    }
+
+    pub fn get(&self, model_name: &str) -> Option<&ModelNote> {
+        self.models.get(model_name)
+    }
+
+    /// Replace the annotation for `model_name`; an empty one removes it.
+    pub fn set(&mut self, model_name: &str, note: ModelNote) {
+        if note.is_empty() {
+            self.models.remove(model_name);
+        } else {
+            self.models.insert(model_name.to_string(), note);
+        }
+        self.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_hashtags_from_note_text() {
+        let note = ModelNote::parse("tried this,  too slow #Tried #rag #tried #");
+        assert_eq!(note.note, "tried this, too slow");
+        assert_eq!(note.tags, vec!["tried", "rag"]);
+        assert_eq!(note.to_line(), "tried this, too slow #tried #rag");
+        assert_eq!(ModelNote::parse(&note.to_line()), note);
+        assert!(ModelNote::parse("   ").is_empty());
+    }
+
+    #[test]
+    fn tags_round_trip_through_the_notes_file() {
+        let path = std::env::temp_dir().join(format!(
+            "llmfit-notes-roundtrip-{}.json",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+
+        let mut notes = ModelNotes::load_from(Some(path.clone()));
+        let mut tagged = ModelNote::default();
+        tagged.add_tag("#RAG");
+        notes.set("Qwen/Qwen2.5-7B-Instruct", tagged);
+        notes.set("meta-llama/Llama-3.1-8B", ModelNote::parse("too slow"));
+
+        let reloaded = ModelNotes::load_from(Some(path.clone()));
+        assert!(
+            reloaded
+                .get("Qwen/Qwen2.5-7B-Instruct")
+                .is_some_and(|n| n.has_tag("#rag"))
+        );
+        assert!(
+            !reloaded
+                .get("meta-llama/Llama-3.1-8B")
+                .unwrap()
+                .has_tag("#rag")
+        );
+        assert_eq!(
+            reloaded.get("meta-llama/Llama-3.1-8B").unwrap().note,
+            "too slow"
+        );
+
+        notes.set("meta-llama/Llama-3.1-8B", ModelNote::default());
+        assert!(
+            ModelNotes::load_from(Some(path.clone()))
+                .get("meta-llama/Llama-3.1-8B")
+                .is_none()
+        );
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+/// The catalog as of the last TUI run, saved to `~/.config/llmfit/seen_models.json`.
+/// Diffed against the current catalog on startup to announce newly added models.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct SeenModels {
+    #[serde(default)]
+    pub models: BTreeSet<String>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl SeenModels {
+    fn config_path() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("llmfit").join("seen_models.json"))
+    }
+
+    pub fn load() -> Self {
+        Self::load_from(Self::config_path())
+    }
+
+    fn load_from(path: Option<PathBuf>) -> Self {
+        let mut seen: Self = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        seen.path = path;
+        seen
+    }
+
+    pub fn save(&self) {
+        if let Some(path) = &self.path {
+            if let Some(parent) = path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            if let Ok(json) = serde_json::to_string_pretty(self) {
+                let _ = fs::write(path, json);
+            }
+        }
+    }
+
+    /// Names in `current` that were not in the catalog last time, sorted.
+    /// Empty on the very first run, when there is nothing to compare against.
+    pub fn new_since<'a>(&self, current: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+        if self.models.is_empty() {
+            return Vec::new();
+        }
+        current
+            .into_iter()
+            .filter(|name| !self.models.contains(*name))
+            .map(str::to_string)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Remember `current` as the catalog for the next run's diff.
+    pub fn record<'a>(&mut self, current: impl IntoIterator<Item = &'a str>) {
+        self.models = current.into_iter().map(str::to_string).collect();
+        self.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_lists_only_models_added_since_the_stored_set() {
+        let path =
+            std::env::temp_dir().join(format!("llmfit-seen-models-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        // First run: nothing stored yet, so nothing is "new".
+        let mut seen = SeenModels::load_from(Some(path.clone()));
+        let first = ["meta-llama/Llama-3.1-8B", "Qwen/Qwen2.5-7B-Instruct"];
+        assert!(seen.new_since(first).is_empty());
+        seen.record(first);
+
+        // Second run after a DB update: one model dropped, two added.
+        let seen = SeenModels::load_from(Some(path.clone()));
+        let updated = [
+            "Qwen/Qwen3-8B",
+            "meta-llama/Llama-3.1-8B",
+            "google/gemma-3-4b-it",
+            "Qwen/Qwen3-8B",
+        ];
+        assert_eq!(
+            seen.new_since(updated),
+            vec!["Qwen/Qwen3-8B", "google/gemma-3-4b-it"]
+        );
+        assert!(seen.new_since(first).is_empty());
+
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -9,7 +9,7 @@ use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
 use axum::{Json, Router};
 use llmfit_core::fit::{
-    FitLevel, InferenceRuntime, ModelFit, SortColumn, backend_compatible,
+    CalcConfig, FitLevel, InferenceRuntime, ModelFit, SortColumn, backend_compatible,
     rank_models_by_fit_opts_col,
 };
 use llmfit_core::hardware::{GpuBackend, SystemSpecs};
@@ -430,6 +430,8 @@ struct PlanBody {
     target_tps: Option<f64>,
     #[serde(default)]
     kv_quant: Option<String>,
+    #[serde(default)]
+    turns: Option<u32>,
     #[serde(alias = "ram")]
     ram_gb: Option<f64>,
     #[serde(alias = "memory")]
@@ -744,6 +746,8 @@ async fn plan_estimate(
         quant: body.quant,
         target_tps: body.target_tps,
         kv_quant,
+        turns: body.turns,
+        batch_size: None,
     };
     let specs = effective_specs(&state.specs, &overrides)?;
 
@@ -770,7 +774,15 @@ fn filtered_fits(
         .models
         .iter()
         .filter(|m| backend_compatible(m, specs))
-        .map(|m| ModelFit::analyze_with_forced_runtime(m, specs, context_limit, forced_rt))
+        .map(|m| {
+            ModelFit::analyze_with_runtime_and_config(
+                m,
+                specs,
+                context_limit,
+                forced_rt,
+                CalcConfig::from_user_config(),
+            )
+        })
         .collect();
 
     let is_apple_silicon = specs.backend == GpuBackend::Metal && specs.unified_memory;
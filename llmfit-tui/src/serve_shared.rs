@@ -34,7 +34,8 @@ pub fn system_json(specs: &SystemSpecs) -> serde_json::Value {
 }
 
 pub fn fit_to_json(fit: &ModelFit) -> serde_json::Value {
-    serde_json::json!({
+    // Built in two literals to stay under the default macro recursion limit.
+    let mut json = serde_json::json!({
         "name": fit.model.name,
         "provider": fit.model.provider,
         "parameter_count": fit.model.parameter_count,
@@ -42,12 +43,15 @@ pub fn fit_to_json(fit: &ModelFit) -> serde_json::Value {
         "context_length": fit.model.context_length,
         "usable_context": fit.usable_context,
         "effective_context_length": fit.effective_context_length,
+        "trained_context": fit.model.native_context(),
+        "beyond_trained_context": fit.beyond_trained_context(),
         "use_case": fit.model.use_case,
         "category": fit.use_case.label(),
         "release_date": fit.model.release_date,
         "is_moe": fit.model.is_moe,
         "fit_level": fit_level_code(fit.fit_level),
         "fit_label": fit.fit_text(),
+        "fit_level_if_freed": fit.fit_level_if_freed.map(fit_level_code),
         "run_mode": run_mode_code(fit.run_mode),
         "run_mode_label": fit.run_mode_text(),
         "score": round1(fit.score),
@@ -71,14 +75,23 @@ pub fn fit_to_json(fit: &ModelFit) -> serde_json::Value {
         "capabilities": fit.model.capabilities,
         "capability_ids": fit.model.capabilities,
         "license": fit.model.license,
+    });
+    let serde_json::Value::Object(install) = serde_json::json!({
         "supports_tp": fit.model.valid_tp_sizes(),
         "installed": fit.installed,
+        "installed_provider": fit.installed_provider,
         "disk_size_gb": round2(fit.model.estimate_disk_gb(&fit.best_quant)),
         "ollama_name": llmfit_core::providers::ollama_pull_tag(&fit.model.name),
         "estimate_basis": fit.estimate_basis,
         "verify_command": generate_llamabench_command(fit),
         "measured_tps": fit.measured_tps,
-    })
+    }) else {
+        unreachable!("json! object literal")
+    };
+    if let Some(obj) = json.as_object_mut() {
+        obj.extend(install);
+    }
+    json
 }
 
 pub fn fit_level_code(fit_level: FitLevel) -> &'static str {
@@ -166,9 +179,12 @@ mod tests {
                 backend: GpuBackend::Cuda,
                 count: 1,
                 unified_memory: false,
+                driver_version: None,
             }],
             cluster_mode: false,
             cluster_node_count: 0,
+            gpu_cores: None,
+            ram_config: None,
         }
     }
 
@@ -201,9 +217,11 @@ mod tests {
         assert_eq!(json["usable_context"], fit.usable_context);
         assert_eq!(
             json["effective_context_length"],
-            llmfit_core::fit::DEFAULT_ESTIMATION_CTX
+            llmfit_core::fit::default_estimation_ctx(model)
         );
         assert!(fit.usable_context <= model.context_length);
+        assert_eq!(json["trained_context"], model.native_context());
+        assert_eq!(json["beyond_trained_context"], fit.beyond_trained_context());
     }
 
     #[test]
@@ -1,6 +1,6 @@
 use ratatui::style::Color;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Available color themes for the TUI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -63,25 +63,40 @@ impl Theme {
         }
     }
 
-    /// Path to the config file: `<config_dir>/llmfit/theme`
+    /// Path to the config file: `<config_dir>/llmfit/theme`, where
+    /// `<config_dir>` is `$XDG_CONFIG_HOME` (default `~/.config`) on Linux,
+    /// `~/Library/Application Support` on macOS and `%APPDATA%` on Windows.
     fn config_path() -> Option<PathBuf> {
-        Some(dirs::config_dir()?.join("llmfit").join("theme"))
+        Some(Self::path_in(&dirs::config_dir()?))
+    }
+
+    fn path_in(config_dir: &Path) -> PathBuf {
+        config_dir.join("llmfit").join("theme")
     }
 
     /// Save the current theme to disk.
     pub fn save(&self) {
         if let Some(path) = Self::config_path() {
-            if let Some(parent) = path.parent() {
-                let _ = fs::create_dir_all(parent);
-            }
-            let _ = fs::write(&path, self.label());
+            self.save_to(&path);
+        }
+    }
+
+    fn save_to(&self, path: &Path) {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
         }
+        let _ = fs::write(path, self.label());
     }
 
     /// Load the saved theme from disk, falling back to Default.
     pub fn load() -> Self {
         Self::config_path()
-            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|path| Self::load_from(&path))
+            .unwrap_or(Theme::Default)
+    }
+
+    fn load_from(path: &Path) -> Self {
+        fs::read_to_string(path)
             .map(|s| Self::from_label(s.trim()))
             .unwrap_or(Theme::Default)
     }
@@ -519,3 +534,56 @@ fn catppuccin_mocha_colors() -> ThemeColors {
         status_fg: Color::Rgb(17, 17, 27),    // Crust
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_theme_round_trips_through_the_theme_file() {
+        let dir = std::env::temp_dir().join(format!("llmfit-theme-{}", std::process::id()));
+        let path = Theme::path_in(&dir);
+        assert!(path.ends_with("llmfit/theme"));
+        assert_eq!(Theme::load_from(&path), Theme::Default);
+
+        let mut theme = Theme::Default;
+        loop {
+            theme.save_to(&path);
+            assert_eq!(Theme::load_from(&path), theme);
+            theme = theme.next();
+            if theme == Theme::Default {
+                break;
+            }
+        }
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn theme_path_follows_xdg_config_home() {
+        let expected = match std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from) {
+            Some(xdg) if xdg.is_absolute() => xdg,
+            _ => dirs::home_dir().unwrap().join(".config"),
+        };
+        assert_eq!(Theme::config_path(), Some(expected.join("llmfit/theme")));
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn theme_path_is_in_application_support() {
+        let expected = dirs::home_dir()
+            .unwrap()
+            .join("Library/Application Support/llmfit/theme");
+        assert_eq!(Theme::config_path(), Some(expected));
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn theme_path_is_in_appdata() {
+        let appdata = PathBuf::from(std::env::var_os("APPDATA").unwrap());
+        assert_eq!(
+            Theme::config_path(),
+            Some(appdata.join("llmfit").join("theme"))
+        );
+    }
+}
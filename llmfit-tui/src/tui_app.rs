@@ -1,15 +1,18 @@
-use llmfit_core::fit::{CalcConfig, FitLevel, ModelFit, SortColumn, backend_compatible};
+use llmfit_core::fit::{
+    CalcConfig, ContextUnit, FitLevel, ModelFit, SortColumn, backend_compatible,
+};
 use llmfit_core::hardware::SystemSpecs;
-use llmfit_core::models::{Capability, ModelDatabase, UseCase};
+use llmfit_core::models::{Capability, LlmModel, ModelDatabase, UseCase};
 use llmfit_core::plan::{PlanEstimate, PlanRequest, estimate_model_plan};
 use llmfit_core::providers::{
-    self, DockerModelRunnerProvider, LlamaCppProvider, LmStudioProvider, MlxProvider,
-    ModelProvider, OllamaProvider, PullEvent, PullHandle, RamaLamaProvider, VllmProvider,
-    command_exists,
+    self, DockerModelRunnerProvider, Gpt4AllProvider, GroqProvider, JanProvider, LlamaCppProvider,
+    LmStudioProvider, MlxProvider, ModelProvider, OllamaProvider, PullEvent, PullHandle,
+    RamaLamaProvider, VllmProvider, command_exists,
 };
 use llmfit_core::quality;
 
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::mpsc;
 use std::{cmp, thread};
 
@@ -18,6 +21,8 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use crate::download_history::{DownloadHistory, DownloadRecord, DownloadResult};
 use crate::filter_config::FilterConfig;
+use crate::model_notes::{ModelNote, ModelNotes};
+use crate::seen_models::SeenModels;
 use crate::theme::Theme;
 
 fn floor_char_boundary(value: &str, index: usize) -> usize {
@@ -107,6 +112,32 @@ pub enum ProviderDetectionMsg {
         installed: HashSet<String>,
         installed_count: usize,
     },
+    Groq {
+        available: bool,
+        models: Vec<String>,
+    },
+}
+
+/// One model family in the grouped view (`G`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FamilyGroup {
+    pub family: String,
+    pub expanded: bool,
+    /// Indices into `all_fits` in list order.
+    pub indices: Vec<usize>,
+    /// The family's best fit (best fit level, then score), shown on its row.
+    pub best: usize,
+}
+
+/// What a row of `filtered_fits` stands for in the grouped view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupedRow {
+    /// A family with one model, shown as a plain model row.
+    Model,
+    /// The collapsible row for `family_tree[n]`.
+    Family(usize),
+    /// A variant listed under an expanded family.
+    Variant,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -126,12 +157,15 @@ pub enum InputMode {
     LicensePopup,
     RuntimePopup,
     HelpPopup,
+    HistoryPopup,
+    NewModelsPopup,
     Simulation,
     AdvancedConfig,
     DownloadManager,
     FilterPopup,
     Benchmarks,
     BenchOffer,
+    NoteEdit,
 }
 
 /// Fields in the Filter Popup modal.
@@ -141,6 +175,8 @@ pub enum FilterPopupField {
     ParamsMax,
     MemPctMin,
     MemPctMax,
+    TargetTps,
+    TargetTpsOnly,
     SortDirection,
     FitFilter,
     Availability,
@@ -152,7 +188,9 @@ impl FilterPopupField {
             Self::ParamsMin => Self::ParamsMax,
             Self::ParamsMax => Self::MemPctMin,
             Self::MemPctMin => Self::MemPctMax,
-            Self::MemPctMax => Self::SortDirection,
+            Self::MemPctMax => Self::TargetTps,
+            Self::TargetTps => Self::TargetTpsOnly,
+            Self::TargetTpsOnly => Self::SortDirection,
             Self::SortDirection => Self::FitFilter,
             Self::FitFilter => Self::Availability,
             Self::Availability => Self::ParamsMin,
@@ -165,7 +203,9 @@ impl FilterPopupField {
             Self::ParamsMax => Self::ParamsMin,
             Self::MemPctMin => Self::ParamsMax,
             Self::MemPctMax => Self::MemPctMin,
-            Self::SortDirection => Self::MemPctMax,
+            Self::TargetTps => Self::MemPctMax,
+            Self::TargetTpsOnly => Self::TargetTps,
+            Self::SortDirection => Self::TargetTpsOnly,
             Self::FitFilter => Self::SortDirection,
             Self::Availability => Self::FitFilter,
         }
@@ -179,6 +219,8 @@ struct FilterSnapshot {
     params_max: String,
     mem_pct_min: String,
     mem_pct_max: String,
+    target_tps: String,
+    target_tps_only: bool,
     sort_ascending: bool,
     fit_filter: FitFilter,
     availability_filter: AvailabilityFilter,
@@ -638,6 +680,7 @@ pub enum FitFilter {
     Good,
     Marginal,
     TooTight,
+    Installed,     // Only models a local runtime already has
     TurboQuantFit, // TooTight at fp16 but fits with TurboQuant KV compression
     Runnable,      // Perfect + Good + Marginal (excludes TooTight)
 }
@@ -650,6 +693,7 @@ impl FitFilter {
             FitFilter::Good => "Good",
             FitFilter::Marginal => "Marginal",
             FitFilter::TooTight => "Too Tight",
+            FitFilter::Installed => "Installed Only",
             FitFilter::TurboQuantFit => "TQ+ Fit",
             FitFilter::Runnable => "Runnable",
         }
@@ -661,6 +705,7 @@ impl FitFilter {
             "Good" => FitFilter::Good,
             "Marginal" => FitFilter::Marginal,
             "Too Tight" => FitFilter::TooTight,
+            "Installed Only" => FitFilter::Installed,
             "TQ+ Fit" => FitFilter::TurboQuantFit,
             "Runnable" => FitFilter::Runnable,
             _ => FitFilter::All,
@@ -674,7 +719,8 @@ impl FitFilter {
             FitFilter::Perfect => FitFilter::Good,
             FitFilter::Good => FitFilter::Marginal,
             FitFilter::Marginal => FitFilter::TooTight,
-            FitFilter::TooTight => FitFilter::TurboQuantFit,
+            FitFilter::TooTight => FitFilter::Installed,
+            FitFilter::Installed => FitFilter::TurboQuantFit,
             FitFilter::TurboQuantFit => FitFilter::All,
         }
     }
@@ -686,6 +732,7 @@ pub enum AvailabilityFilter {
     All,
     HasGguf,   // Has GGUF download sources (unsloth, bartowski, etc.)
     Installed, // Already installed in a local runtime
+    Upgrades,  // Not installed and outranks the best installed model
 }
 
 impl AvailabilityFilter {
@@ -694,6 +741,7 @@ impl AvailabilityFilter {
             AvailabilityFilter::All => "All",
             AvailabilityFilter::HasGguf => "GGUF Avail",
             AvailabilityFilter::Installed => "Installed",
+            AvailabilityFilter::Upgrades => "Upgrades",
         }
     }
 
@@ -701,6 +749,7 @@ impl AvailabilityFilter {
         match s {
             "GGUF Avail" => AvailabilityFilter::HasGguf,
             "Installed" => AvailabilityFilter::Installed,
+            "Upgrades" => AvailabilityFilter::Upgrades,
             _ => AvailabilityFilter::All,
         }
     }
@@ -709,7 +758,8 @@ impl AvailabilityFilter {
         match self {
             AvailabilityFilter::All => AvailabilityFilter::HasGguf,
             AvailabilityFilter::HasGguf => AvailabilityFilter::Installed,
-            AvailabilityFilter::Installed => AvailabilityFilter::All,
+            AvailabilityFilter::Installed => AvailabilityFilter::Upgrades,
+            AvailabilityFilter::Upgrades => AvailabilityFilter::All,
         }
     }
 }
@@ -783,6 +833,9 @@ pub const DL_DOCKER: u8 = 0b0100;
 pub const DL_LMSTUDIO: u8 = 0b1000;
 pub const DL_VLLM: u8 = 0b1_0000;
 
+/// Batch sizes cycled by `B`: concurrent requests the server keeps in flight.
+pub const BATCH_SIZES: [u32; 5] = [1, 2, 4, 8, 16];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ActivePullProvider {
     Ollama,
@@ -839,16 +892,55 @@ fn fuzzy_match(query: &str, candidate: &str) -> bool {
     q.peek().is_none()
 }
 
+/// Status line describing how the set of installed models changed, e.g.
+/// "Installed: llama3.1:8b". `None` when nothing changed.
+fn installed_change_message(before: &HashSet<String>, after: &HashSet<String>) -> Option<String> {
+    let list = |names: Vec<&String>| {
+        let mut names: Vec<&str> = names.into_iter().map(String::as_str).collect();
+        names.sort_unstable();
+        const SHOWN: usize = 3;
+        let more = names.len().saturating_sub(SHOWN);
+        names.truncate(SHOWN);
+        let mut text = names.join(", ");
+        if more > 0 {
+            text.push_str(&format!(" (+{} more)", more));
+        }
+        text
+    };
+    let added: Vec<&String> = after.difference(before).collect();
+    let removed: Vec<&String> = before.difference(after).collect();
+    let mut parts = Vec::new();
+    if !added.is_empty() {
+        parts.push(format!("Installed: {}", list(added)));
+    }
+    if !removed.is_empty() {
+        parts.push(format!("Removed: {}", list(removed)));
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("; "))
+    }
+}
+
 pub struct App {
     pub should_quit: bool,
+    /// Install script to print once the terminal is restored (Ctrl+E).
+    pub install_script_on_exit: Option<String>,
     pub input_mode: InputMode,
     pub search_query: String,
+    /// Byte offset into `search_query`, always on a grapheme boundary.
     pub cursor_position: usize,
 
     // Data
     pub specs: SystemSpecs,
     pub all_fits: Vec<ModelFit>,
     pub filtered_fits: Vec<usize>, // indices into all_fits
+    // Family grouping (G): one row per family, Enter expands
+    pub grouped_view: bool,
+    pub family_tree: Vec<FamilyGroup>,
+    pub grouped_rows: Vec<GroupedRow>, // parallel to filtered_fits when grouped
+    pub expanded_families: HashSet<String>,
     pub providers: Vec<String>,
     pub selected_providers: Vec<bool>,
     pub use_cases: Vec<UseCase>,
@@ -861,6 +953,13 @@ pub struct App {
     pub availability_filter: AvailabilityFilter,
     pub tp_filter: TpFilter,
     pub installed_first: bool,
+    /// Show installed models in a pinned section above the rest of the
+    /// table. Unlike `installed_first` this leaves `all_fits` ordered by the
+    /// sort column and is not persisted.
+    pub show_installed_pinned: bool,
+    /// Show and filter MoE models by active (per-token) parameters instead
+    /// of the total. Dense models always use their total.
+    pub params_active: bool,
     pub sort_column: SortColumn,
     pub sort_ascending: bool,
 
@@ -870,6 +969,19 @@ pub struct App {
 
     // Detail view
     pub show_detail: bool,
+    /// Expand the raw memory arithmetic in the detail view (`M` toggles).
+    pub show_memory_math: bool,
+    /// Quant the detail view re-analyzes the selected model at (`Q` cycles);
+    /// None shows the automatic pick.
+    pub detail_force_quant: Option<&'static str>,
+    /// The selected model re-analyzed at `detail_force_quant`, keyed by that
+    /// quant, so the detail view doesn't re-run the analysis every frame.
+    pub detail_forced_fit: Option<(&'static str, ModelFit)>,
+    /// Lines scrolled in the detail view's notes pane (`[` / `]`), never
+    /// past `detail_notes_max_scroll`.
+    pub detail_notes_scroll: usize,
+    /// How far the notes pane could scroll when last drawn.
+    detail_notes_max_scroll: usize,
     pub show_compare: bool,
     pub compare_mark_model: Option<String>,
     pub show_multi_compare: bool,
@@ -900,6 +1012,9 @@ pub struct App {
     pub ollama_available: bool,
     pub ollama_binary_available: bool,
     pub installed: llmfit_core::analysis::InstalledIndex,
+    /// Installed Ollama tags with no row in `all_fits`, refreshed whenever
+    /// the Ollama install list changes.
+    pub ollama_orphans: Vec<String>,
     ollama: OllamaProvider,
     pub mlx_available: bool,
     mlx: MlxProvider,
@@ -916,6 +1031,14 @@ pub struct App {
     vllm: VllmProvider,
     pub ramalama_available: bool,
     ramalama: RamaLamaProvider,
+    pub jan_available: bool,
+    jan: JanProvider,
+    pub gpt4all_available: bool,
+    gpt4all: Gpt4AllProvider,
+    /// Groq cloud API reachable with `GROQ_API_KEY`.
+    pub groq_available: bool,
+    /// Model ids Groq serves, offered for models too big to run locally.
+    pub groq_models: Vec<String>,
 
     // Download state
     pub pull_active: Option<PullHandle>,
@@ -936,6 +1059,11 @@ pub struct App {
     pub show_downloads: bool,
     pub dm_focus: DownloadManagerFocus,
     pub download_history: DownloadHistory,
+
+    // User notes/tags (n to edit, #tag in search to filter)
+    pub model_notes: ModelNotes,
+    pub note_input: String,
+    pub note_cursor: usize,
     pub dm_history_cursor: usize,
     pub dm_history_scroll: usize,
     pub dm_confirm_delete: bool,
@@ -977,6 +1105,10 @@ pub struct App {
     // Help popup
     pub help_scroll: usize,
 
+    // Catalog additions since the last run (N to list them)
+    pub new_models: Vec<String>,
+    pub new_models_cursor: usize,
+
     // Hardware simulation
     pub real_specs: SystemSpecs,
     pub sim_active: bool,
@@ -986,9 +1118,13 @@ pub struct App {
     pub sim_cpu_input: String,
     pub sim_cursor_position: usize,
     context_limit: Option<u32>,
+    /// Concurrent requests fits are sized for (`B` cycles [`BATCH_SIZES`]).
+    pub batch_size: u32,
 
     // Theme
     pub theme: Theme,
+    /// How the Ctx column renders context windows (`K` toggles).
+    pub context_unit: ContextUnit,
 
     // Advanced Configuration
     pub calc_config: CalcConfig,
@@ -1011,6 +1147,10 @@ pub struct App {
     pub filter_params_max_input: String,
     pub filter_mem_pct_min_input: String,
     pub filter_mem_pct_max_input: String,
+    /// Minimum acceptable tok/s. Rows reaching it are highlighted; with
+    /// `filter_target_tps_only` the slower ones are hidden too.
+    pub filter_target_tps_input: String,
+    pub filter_target_tps_only: bool,
     pub filter_sort_ascending: bool,
 
     // Snapshot of filter state when popup is opened — restored on Esc.
@@ -1090,17 +1230,42 @@ impl App {
         let real_specs = specs.clone();
         let db = ModelDatabase::new();
 
-        // Detect llama.cpp synchronously (local filesystem check, fast)
-        let mut llamacpp = LlamaCppProvider::new();
-        if let Some(ref dir) = FilterConfig::load().download_dir {
-            let path = std::path::PathBuf::from(dir);
-            if path.is_dir() {
-                llamacpp.set_models_dir(path);
-            }
-        }
+        // llama.cpp, Jan and GPT4All are detected before the first frame
+        // (mostly filesystem scans, plus a llama-server probe when no binary
+        // is found). Run them side by side so startup waits for the slowest
+        // one, not all three.
+        let download_dir = FilterConfig::load().download_dir;
+        let (llamacpp_probe, jan_probe, gpt4all_probe) = thread::scope(|s| {
+            let llamacpp = s.spawn(|| {
+                let mut llamacpp = LlamaCppProvider::new();
+                if let Some(dir) = &download_dir {
+                    let path = std::path::PathBuf::from(dir);
+                    if path.is_dir() {
+                        llamacpp.set_models_dir(path);
+                    }
+                }
+                let installed = llamacpp.installed_models_counted();
+                (llamacpp, installed)
+            });
+            let jan = s.spawn(|| {
+                let jan = JanProvider::new();
+                let installed = jan.installed_models_counted();
+                (jan, installed)
+            });
+            let gpt4all = s.spawn(|| {
+                let gpt4all = Gpt4AllProvider::new();
+                let installed = gpt4all.installed_models_counted();
+                (gpt4all, installed)
+            });
+            (
+                llamacpp.join().expect("llama.cpp probe panicked"),
+                jan.join().expect("Jan probe panicked"),
+                gpt4all.join().expect("GPT4All probe panicked"),
+            )
+        });
+        let (llamacpp, (llamacpp_installed, llamacpp_installed_count)) = llamacpp_probe;
         let llamacpp_available = llamacpp.is_available();
         let llamacpp_detection_hint = llamacpp.detection_hint().to_string();
-        let (llamacpp_installed, llamacpp_installed_count) = llamacpp.installed_models_counted();
 
         // Start with empty provider state — detection runs in background
         let ollama = OllamaProvider::new();
@@ -1122,6 +1287,13 @@ impl App {
         installed.llamacpp = llamacpp_installed;
         installed.llamacpp_count = llamacpp_installed_count;
 
+        let (jan, jan_installed) = jan_probe;
+        let jan_available = jan.is_available();
+        (installed.jan, installed.jan_count) = jan_installed;
+        let (gpt4all, gpt4all_installed) = gpt4all_probe;
+        let gpt4all_available = gpt4all.is_available();
+        (installed.gpt4all, installed.gpt4all_count) = gpt4all_installed;
+
         // Spawn background provider detection for network-based providers
         let (provider_tx, provider_detection_rx) = mpsc::channel();
         {
@@ -1195,7 +1367,7 @@ impl App {
             });
         }
         {
-            let tx = provider_tx;
+            let tx = provider_tx.clone();
             thread::spawn(move || {
                 let ramalama = RamaLamaProvider::new();
                 let (available, installed, installed_count) = ramalama.detect_with_installed();
@@ -1206,6 +1378,13 @@ impl App {
                 });
             });
         }
+        {
+            let tx = provider_tx;
+            thread::spawn(move || {
+                let (available, models) = GroqProvider::new().detect_with_models();
+                let _ = tx.send(ProviderDetectionMsg::Groq { available, models });
+            });
+        }
 
         // Track how many we're skipping so the UI can surface it.
         let backend_hidden_count = db
@@ -1220,13 +1399,21 @@ impl App {
         let local_index = llmfit_core::share::LocalBenchIndex::load(&specs);
         let community_index = llmfit_core::benchmarks::CommunityBenchIndex::for_specs(&specs);
         let measured_index = llmfit_core::benchmarks::MeasuredTpsIndex::for_specs(&specs);
+        // Scoring and quant preferences from config.toml.
+        let calc_config = CalcConfig::from_user_config();
         let mut all_fits: Vec<ModelFit> = db
             .get_all_models()
             .iter()
             .filter(|m| backend_compatible(m, &specs))
             .map(|m| {
-                let mut fit = ModelFit::analyze_with_context_limit(m, &specs, context_limit);
-                fit.installed = installed.is_installed(&m.name);
+                let mut fit = ModelFit::analyze_with_runtime_and_config(
+                    m,
+                    &specs,
+                    context_limit,
+                    None,
+                    calc_config.clone(),
+                );
+                installed.mark(&mut fit);
                 fit.measured_tps = local_index
                     .as_ref()
                     .and_then(|idx| idx.lookup(&m.name))
@@ -1242,6 +1429,10 @@ impl App {
 
         // Calibrate formula estimates from the user's own benchmark runs.
         llmfit_core::analysis::apply_local_calibration(&mut all_fits);
+        llmfit_core::analysis::apply_installed_family_bonus(
+            &mut all_fits,
+            &calc_config.scoring_weights,
+        );
 
         // Sort by fit level then RAM usage
         all_fits = llmfit_core::fit::rank_models_by_fit(all_fits);
@@ -1261,7 +1452,7 @@ impl App {
             .collect();
         model_providers.sort();
 
-        let mut selected_providers = vec![true; model_providers.len()];
+        let selected_providers = vec![true; model_providers.len()];
         let model_use_cases = [
             UseCase::General,
             UseCase::Coding,
@@ -1273,10 +1464,10 @@ impl App {
         .into_iter()
         .filter(|uc| all_fits.iter().any(|f| f.use_case == *uc))
         .collect::<Vec<_>>();
-        let mut selected_use_cases = vec![true; model_use_cases.len()];
+        let selected_use_cases = vec![true; model_use_cases.len()];
 
         let model_capabilities = Capability::all().to_vec();
-        let mut selected_capabilities = vec![true; model_capabilities.len()];
+        let selected_capabilities = vec![true; model_capabilities.len()];
 
         // Extract unique quantizations
         let mut model_quants: Vec<String> = all_fits
@@ -1286,7 +1477,7 @@ impl App {
             .into_iter()
             .collect();
         model_quants.sort();
-        let mut selected_quants = vec![true; model_quants.len()];
+        let selected_quants = vec![true; model_quants.len()];
 
         // Run modes
         let model_run_modes = vec![
@@ -1295,7 +1486,7 @@ impl App {
             "CPU+GPU".to_string(),
             "CPU".to_string(),
         ];
-        let mut selected_run_modes = vec![true; model_run_modes.len()];
+        let selected_run_modes = vec![true; model_run_modes.len()];
 
         // Params buckets
         let params_buckets = vec![
@@ -1306,7 +1497,7 @@ impl App {
             "30-70B".to_string(),
             "70B+".to_string(),
         ];
-        let mut selected_params_buckets = vec![true; params_buckets.len()];
+        let selected_params_buckets = vec![true; params_buckets.len()];
 
         // Extract unique licenses (including "Unknown" for models without one)
         let mut model_licenses: Vec<String> = all_fits
@@ -1325,7 +1516,7 @@ impl App {
             let unknown = model_licenses.remove(pos);
             model_licenses.push(unknown);
         }
-        let mut selected_licenses = vec![true; model_licenses.len()];
+        let selected_licenses = vec![true; model_licenses.len()];
 
         // Static runtime options — filter by compatibility, not assigned runtime
         let model_runtimes = vec![
@@ -1333,68 +1524,7 @@ impl App {
             "MLX".to_string(),
             "vLLM".to_string(),
         ];
-        let mut selected_runtimes = vec![true; model_runtimes.len()];
-
-        // ── Restore persisted filters ────────────────────────────────
-        let saved = FilterConfig::load();
-
-        let fit_filter = saved
-            .fit_filter
-            .as_deref()
-            .map(FitFilter::from_label)
-            .unwrap_or(FitFilter::All);
-        let availability_filter = saved
-            .availability_filter
-            .as_deref()
-            .map(AvailabilityFilter::from_label)
-            .unwrap_or(AvailabilityFilter::All);
-        let tp_filter = saved
-            .tp_filter
-            .as_deref()
-            .map(TpFilter::from_label)
-            .unwrap_or(TpFilter::All);
-        let sort_column = saved
-            .sort_column
-            .as_deref()
-            .map(sort_column_from_label)
-            .unwrap_or(SortColumn::Score);
-        let sort_ascending = saved.sort_ascending.unwrap_or(false);
-        let installed_first = saved.installed_first.unwrap_or(false);
-        let search_query = saved.search_query.clone().unwrap_or_default();
-        let cursor_position = search_query.len();
-
-        if let Some(ref map) = saved.providers {
-            FilterConfig::apply_map(&model_providers, &mut selected_providers, map);
-        }
-        if let Some(ref map) = saved.use_cases {
-            let names: Vec<String> = model_use_cases
-                .iter()
-                .map(|uc| uc.label().to_string())
-                .collect();
-            FilterConfig::apply_map(&names, &mut selected_use_cases, map);
-        }
-        if let Some(ref map) = saved.capabilities {
-            let names: Vec<String> = model_capabilities
-                .iter()
-                .map(|c| c.label().to_string())
-                .collect();
-            FilterConfig::apply_map(&names, &mut selected_capabilities, map);
-        }
-        if let Some(ref map) = saved.quants {
-            FilterConfig::apply_map(&model_quants, &mut selected_quants, map);
-        }
-        if let Some(ref map) = saved.run_modes {
-            FilterConfig::apply_map(&model_run_modes, &mut selected_run_modes, map);
-        }
-        if let Some(ref map) = saved.params_buckets {
-            FilterConfig::apply_map(&params_buckets, &mut selected_params_buckets, map);
-        }
-        if let Some(ref map) = saved.licenses {
-            FilterConfig::apply_map(&model_licenses, &mut selected_licenses, map);
-        }
-        if let Some(ref map) = saved.runtimes {
-            FilterConfig::apply_map(&model_runtimes, &mut selected_runtimes, map);
-        }
+        let selected_runtimes = vec![true; model_runtimes.len()];
 
         let filtered_count = all_fits.len();
 
@@ -1402,9 +1532,10 @@ impl App {
 
         let mut app = App {
             should_quit: false,
+            install_script_on_exit: None,
             input_mode: InputMode::Normal,
-            search_query,
-            cursor_position,
+            search_query: String::new(),
+            cursor_position: 0,
             specs,
             all_fits,
             filtered_fits: (0..filtered_count).collect(),
@@ -1414,15 +1545,26 @@ impl App {
             selected_use_cases,
             capabilities: model_capabilities,
             selected_capabilities,
-            fit_filter,
-            availability_filter,
-            tp_filter,
-            installed_first,
-            sort_column,
-            sort_ascending,
+            fit_filter: FitFilter::All,
+            availability_filter: AvailabilityFilter::All,
+            tp_filter: TpFilter::All,
+            installed_first: false,
+            show_installed_pinned: false,
+            params_active: false,
+            sort_column: SortColumn::Score,
+            sort_ascending: false,
+            grouped_view: false,
+            family_tree: Vec::new(),
+            grouped_rows: Vec::new(),
+            expanded_families: HashSet::new(),
             selected_row: 0,
             table_state: TableState::default(),
             show_detail: false,
+            show_memory_math: false,
+            detail_notes_scroll: 0,
+            detail_notes_max_scroll: 0,
+            detail_force_quant: None,
+            detail_forced_fit: None,
             show_compare: false,
             compare_mark_model: None,
             show_multi_compare: false,
@@ -1449,6 +1591,7 @@ impl App {
             ollama_available,
             ollama_binary_available,
             installed,
+            ollama_orphans: Vec::new(),
             ollama,
             mlx_available,
             mlx,
@@ -1465,6 +1608,12 @@ impl App {
             vllm,
             ramalama_available,
             ramalama,
+            jan_available,
+            jan,
+            gpt4all_available,
+            gpt4all,
+            groq_available: false,
+            groq_models: Vec::new(),
             pull_active: None,
             pull_status: None,
             pull_percent: None,
@@ -1479,6 +1628,9 @@ impl App {
             show_downloads: false,
             dm_focus: DownloadManagerFocus::History,
             download_history: DownloadHistory::load(),
+            model_notes: ModelNotes::load(),
+            note_input: String::new(),
+            note_cursor: 0,
             dm_history_cursor: 0,
             dm_history_scroll: 0,
             dm_confirm_delete: false,
@@ -1503,6 +1655,8 @@ impl App {
             selected_runtimes,
             runtime_cursor: 0,
             help_scroll: 0,
+            new_models: Vec::new(),
+            new_models_cursor: 0,
             real_specs,
             sim_active: false,
             sim_field: SimulationField::Ram,
@@ -1511,10 +1665,12 @@ impl App {
             sim_cpu_input: String::new(),
             sim_cursor_position: 0,
             context_limit,
+            batch_size: 1,
             theme: Theme::load(),
+            context_unit: ContextUnit::default(),
             backend_hidden_count,
             // Advanced configuration defaults
-            calc_config: CalcConfig::default(),
+            calc_config,
             adv_config_field: AdvConfigField::Efficiency,
             adv_config_cursor_position: 0,
             adv_config_dirty: false,
@@ -1533,7 +1689,9 @@ impl App {
             filter_params_max_input: String::new(),
             filter_mem_pct_min_input: String::new(),
             filter_mem_pct_max_input: String::new(),
-            filter_sort_ascending: sort_ascending,
+            filter_target_tps_input: String::new(),
+            filter_target_tps_only: false,
+            filter_sort_ascending: false,
             filter_snapshot: None,
             // Benchmarks
             show_benchmarks: false,
@@ -1583,20 +1741,9 @@ impl App {
             providers_loading: true,
         };
 
-        // Restore persisted range filters
-        let saved = FilterConfig::load();
-        if let Some(ref v) = saved.filter_params_min {
-            app.filter_params_min_input = v.clone();
-        }
-        if let Some(ref v) = saved.filter_params_max {
-            app.filter_params_max_input = v.clone();
-        }
-        if let Some(ref v) = saved.filter_mem_pct_min {
-            app.filter_mem_pct_min_input = v.clone();
-        }
-        if let Some(ref v) = saved.filter_mem_pct_max {
-            app.filter_mem_pct_max_input = v.clone();
-        }
+        // ── Restore persisted filters ────────────────────────────────
+        app.refresh_ollama_orphans();
+        app.restore_filter_config(&FilterConfig::load());
 
         app.apply_filters();
         app.re_sort();
@@ -1633,6 +1780,15 @@ impl App {
 
     /// Persist the current filter state to disk.
     pub fn save_filters(&self) {
+        let mut config = self.filter_config();
+        // Preserve existing download_dir setting
+        config.download_dir = FilterConfig::load().download_dir;
+        config.save();
+    }
+
+    /// Snapshot the full filter + sort state (everything except the
+    /// machine-specific download directory).
+    pub fn filter_config(&self) -> FilterConfig {
         let use_case_names: Vec<String> = self
             .use_cases
             .iter()
@@ -1644,13 +1800,14 @@ impl App {
             .map(|c| c.label().to_string())
             .collect();
 
-        let config = FilterConfig {
+        FilterConfig {
             fit_filter: Some(self.fit_filter.label().to_string()),
             availability_filter: Some(self.availability_filter.label().to_string()),
             tp_filter: Some(self.tp_filter.label().to_string()),
             sort_column: Some(self.sort_column.label().to_string()),
             sort_ascending: Some(self.sort_ascending),
             installed_first: Some(self.installed_first),
+            params_active: Some(self.params_active),
             search_query: if self.search_query.is_empty() {
                 None
             } else {
@@ -1706,16 +1863,146 @@ impl App {
             } else {
                 Some(self.filter_mem_pct_max_input.clone())
             },
-            // Preserve existing download_dir setting
-            download_dir: FilterConfig::load().download_dir,
-        };
-        config.save();
+            target_tps: if self.filter_target_tps_input.is_empty() {
+                None
+            } else {
+                Some(self.filter_target_tps_input.clone())
+            },
+            target_tps_only: Some(self.filter_target_tps_only),
+            download_dir: None,
+        }
+    }
+
+    /// Restore filter + sort state from a config.  Filters and selections
+    /// missing from the config keep their current value; the search query and
+    /// range inputs are replaced.  Does not refilter; callers follow up with
+    /// `re_sort()`.
+    fn restore_filter_config(&mut self, config: &FilterConfig) {
+        if let Some(ref v) = config.fit_filter {
+            self.fit_filter = FitFilter::from_label(v);
+        }
+        if let Some(ref v) = config.availability_filter {
+            self.availability_filter = AvailabilityFilter::from_label(v);
+        }
+        if let Some(ref v) = config.tp_filter {
+            self.tp_filter = TpFilter::from_label(v);
+        }
+        if let Some(ref v) = config.sort_column {
+            self.sort_column = sort_column_from_label(v);
+        }
+        if let Some(v) = config.sort_ascending {
+            self.sort_ascending = v;
+            self.filter_sort_ascending = v;
+        }
+        if let Some(v) = config.installed_first {
+            self.installed_first = v;
+        }
+        if let Some(v) = config.params_active {
+            self.params_active = v;
+        }
+        self.search_query = config.search_query.clone().unwrap_or_default();
+        self.cursor_position = self.search_query.len();
+
+        if let Some(ref map) = config.providers {
+            FilterConfig::apply_map(&self.providers, &mut self.selected_providers, map);
+        }
+        if let Some(ref map) = config.use_cases {
+            let names: Vec<String> = self
+                .use_cases
+                .iter()
+                .map(|uc| uc.label().to_string())
+                .collect();
+            FilterConfig::apply_map(&names, &mut self.selected_use_cases, map);
+        }
+        if let Some(ref map) = config.capabilities {
+            let names: Vec<String> = self
+                .capabilities
+                .iter()
+                .map(|c| c.label().to_string())
+                .collect();
+            FilterConfig::apply_map(&names, &mut self.selected_capabilities, map);
+        }
+        if let Some(ref map) = config.quants {
+            FilterConfig::apply_map(&self.quants, &mut self.selected_quants, map);
+        }
+        if let Some(ref map) = config.run_modes {
+            FilterConfig::apply_map(&self.run_modes, &mut self.selected_run_modes, map);
+        }
+        if let Some(ref map) = config.params_buckets {
+            FilterConfig::apply_map(&self.params_buckets, &mut self.selected_params_buckets, map);
+        }
+        if let Some(ref map) = config.licenses {
+            FilterConfig::apply_map(&self.licenses, &mut self.selected_licenses, map);
+        }
+        if let Some(ref map) = config.runtimes {
+            FilterConfig::apply_map(&self.runtimes, &mut self.selected_runtimes, map);
+        }
+
+        self.filter_params_min_input = config.filter_params_min.clone().unwrap_or_default();
+        self.filter_params_max_input = config.filter_params_max.clone().unwrap_or_default();
+        self.filter_mem_pct_min_input = config.filter_mem_pct_min.clone().unwrap_or_default();
+        self.filter_mem_pct_max_input = config.filter_mem_pct_max.clone().unwrap_or_default();
+        self.filter_target_tps_input = config.target_tps.clone().unwrap_or_default();
+        if let Some(v) = config.target_tps_only {
+            self.filter_target_tps_only = v;
+        }
+    }
+
+    /// Export the current view (filters, sort, search) to a JSON file so it
+    /// can be shared or restored later with `import_view`.
+    pub fn export_view(&mut self, path: &Path) {
+        self.pull_status = Some(match self.filter_config().export_to(path) {
+            Ok(()) => format!("Exported view to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// A Bash script installing every Perfect or Good model in the current
+    /// view, each through the provider the TUI would pull it from.
+    pub fn export_install_script(&self) -> String {
+        let fits: Vec<&ModelFit> = self
+            .filtered_fits
+            .iter()
+            .map(|&i| &self.all_fits[i])
+            .filter(|f| matches!(f.fit_level, FitLevel::Perfect | FitLevel::Good))
+            .collect();
+        llmfit_core::analysis::install_script(&fits, None)
+    }
+
+    /// Quit and print the install script for the current view to stdout.
+    pub fn quit_with_install_script(&mut self) {
+        self.install_script_on_exit = Some(self.export_install_script());
+        self.save_filters();
+        self.should_quit = true;
+    }
+
+    /// Restore a view previously written by `export_view`.
+    pub fn import_view(&mut self, path: &Path) {
+        match FilterConfig::import_from(path) {
+            Ok(config) => {
+                self.restore_filter_config(&config);
+                self.selected_row = 0;
+                self.re_sort();
+                self.pull_status = Some(format!("Imported view from {}", path.display()));
+            }
+            Err(e) => self.pull_status = Some(format!("Import failed: {}", e)),
+        }
     }
 
     pub fn apply_filters(&mut self) {
         let query = self.search_query.to_lowercase();
         // Split query into space-separated terms for fuzzy matching
         let terms: Vec<&str> = query.split_whitespace().collect();
+        let upgrade_floor = self.best_installed_score();
+        // Hundreds of providers: look them up by name rather than scanning the
+        // list for every model and GGUF source.
+        let selected_providers: HashSet<&str> = self
+            .providers
+            .iter()
+            .zip(&self.selected_providers)
+            .filter(|(_, sel)| **sel)
+            .map(|(p, _)| p.as_str())
+            .collect();
 
         self.filtered_fits = self
             .all_fits
@@ -1744,8 +2031,9 @@ impl App {
                         })
                         .collect::<Vec<_>>()
                         .join(" ");
+                    let note = self.model_notes.get(&fit.model.name);
                     let searchable = format!(
-                        "{} {} {} {} {} {} {} {}",
+                        "{} {} {} {} {} {} {} {} {}",
                         fit.model.name.to_lowercase(),
                         fit.model.provider.to_lowercase(),
                         fit.model.parameter_count.to_lowercase(),
@@ -1753,29 +2041,27 @@ impl App {
                         fit.use_case.label().to_lowercase(),
                         caps_text,
                         license_text,
-                        gguf_text
+                        gguf_text,
+                        note.map(|n| n.note.to_lowercase()).unwrap_or_default()
                     );
-                    // All terms must be present (AND logic)
-                    terms.iter().all(|term| searchable.contains(term))
+                    // All terms must be present (AND logic); `#tag` terms
+                    // match the user's own tags exactly.
+                    terms.iter().all(|term| {
+                        if term.starts_with('#') {
+                            note.is_some_and(|n| n.has_tag(term))
+                        } else {
+                            searchable.contains(term)
+                        }
+                    })
                 };
 
                 // Provider filter (check primary provider and GGUF source providers)
-                let matches_provider = {
-                    let primary_match = self
-                        .providers
+                let matches_provider = selected_providers.contains(fit.model.provider.as_str())
+                    || fit
+                        .model
+                        .gguf_sources
                         .iter()
-                        .position(|p| p == &fit.model.provider)
-                        .map(|idx| self.selected_providers[idx])
-                        .unwrap_or(false);
-                    let gguf_match = fit.model.gguf_sources.iter().any(|gs| {
-                        self.providers
-                            .iter()
-                            .position(|p| p == &gs.provider)
-                            .map(|idx| self.selected_providers[idx])
-                            .unwrap_or(false)
-                    });
-                    primary_match || gguf_match
-                };
+                        .any(|gs| selected_providers.contains(gs.provider.as_str()));
                 let use_case_idx = self.use_cases.iter().position(|uc| *uc == fit.use_case);
                 let matches_use_case = use_case_idx
                     .map(|idx| self.selected_use_cases[idx])
@@ -1796,6 +2082,7 @@ impl App {
                     FitFilter::Good => fit.fit_level == FitLevel::Good,
                     FitFilter::Marginal => fit.fit_level == FitLevel::Marginal,
                     FitFilter::TooTight => fit.fit_level == FitLevel::TooTight,
+                    FitFilter::Installed => fit.installed,
                     FitFilter::TurboQuantFit => fit.fits_with_turboquant,
                     FitFilter::Runnable => fit.fit_level != FitLevel::TooTight,
                 };
@@ -1805,6 +2092,9 @@ impl App {
                     AvailabilityFilter::All => true,
                     AvailabilityFilter::HasGguf => !fit.model.gguf_sources.is_empty(),
                     AvailabilityFilter::Installed => fit.installed,
+                    AvailabilityFilter::Upgrades => {
+                        !fit.installed && upgrade_floor.is_none_or(|best| fit.score > best)
+                    }
                 };
 
                 // Capability filter
@@ -1854,7 +2144,7 @@ impl App {
                     if all_selected {
                         true
                     } else {
-                        let params = fit.model.params_b();
+                        let params = self.shown_params_b(&fit.model);
                         let bucket_idx = if params < 3.0 {
                             0
                         } else if params < 7.0 {
@@ -1925,7 +2215,7 @@ impl App {
 
                 // Params range filter
                 let matches_params_range = {
-                    let params_b = fit.model.params_b();
+                    let params_b = self.shown_params_b(&fit.model);
                     let min_ok = self.filter_params_min_input.is_empty()
                         || params_b >= self.filter_params_min_input.parse::<f64>().unwrap_or(0.0);
                     let max_ok = self.filter_params_max_input.is_empty()
@@ -1951,6 +2241,10 @@ impl App {
                     min_ok && max_ok
                 };
 
+                // Target speed filter
+                let matches_target_tps =
+                    !self.filter_target_tps_only || self.meets_target_tps(fit) != Some(false);
+
                 matches_search
                     && matches_provider
                     && matches_use_case
@@ -1965,19 +2259,224 @@ impl App {
                     && matches_runtime
                     && matches_params_range
                     && matches_mem_range
+                    && matches_target_tps
             })
             .map(|(i, _)| i)
             .collect();
 
-        // Clamp selection
-        if self.filtered_fits.is_empty() {
-            self.selected_row = 0;
-        } else if self.selected_row >= self.filtered_fits.len() {
-            self.selected_row = self.filtered_fits.len() - 1;
+        if self.grouped_view {
+            self.group_filtered_by_family();
+        } else {
+            self.family_tree.clear();
+            self.grouped_rows.clear();
+            if self.show_installed_pinned {
+                let fits = &self.all_fits;
+                self.filtered_fits.sort_by_key(|&i| !fits[i].installed);
+            }
+        }
+
+        // Clamp selection (and a visual-mode anchor) to the new row count
+        let last_row = self.filtered_fits.len().saturating_sub(1);
+        self.selected_row = self.selected_row.min(last_row);
+        if let Some(anchor) = self.visual_anchor.as_mut() {
+            *anchor = (*anchor).min(last_row);
         }
         self.enqueue_capability_probes_for_visible(24);
     }
 
+    /// Collapse `filtered_fits` to one row per model family, keeping the
+    /// list order of each family's first model. Expanded families list all
+    /// their variants under the family row.
+    fn group_filtered_by_family(&mut self) {
+        let mut tree: Vec<FamilyGroup> = Vec::new();
+        let mut by_family: HashMap<String, usize> = HashMap::new();
+        for &idx in &self.filtered_fits {
+            let model = &self.all_fits[idx].model;
+            let family = if model.family.is_empty() {
+                llmfit_core::models::parse_family(&model.name)
+            } else {
+                model.family.clone()
+            };
+            let group = *by_family.entry(family.clone()).or_insert_with(|| {
+                tree.push(FamilyGroup {
+                    expanded: self.expanded_families.contains(&family),
+                    family,
+                    indices: Vec::new(),
+                    best: idx,
+                });
+                tree.len() - 1
+            });
+            tree[group].indices.push(idx);
+        }
+
+        let fit_rank = |level: FitLevel| match level {
+            FitLevel::Perfect => 0,
+            FitLevel::Good => 1,
+            FitLevel::Marginal => 2,
+            FitLevel::TooTight => 3,
+        };
+        let mut rows = Vec::new();
+        let mut kinds = Vec::new();
+        for (n, group) in tree.iter_mut().enumerate() {
+            group.best = group
+                .indices
+                .iter()
+                .copied()
+                .min_by(|&a, &b| {
+                    let (a, b) = (&self.all_fits[a], &self.all_fits[b]);
+                    fit_rank(a.fit_level)
+                        .cmp(&fit_rank(b.fit_level))
+                        .then(b.score.total_cmp(&a.score))
+                })
+                .unwrap_or(group.best);
+            if group.indices.len() == 1 {
+                rows.push(group.best);
+                kinds.push(GroupedRow::Model);
+                continue;
+            }
+            rows.push(group.best);
+            kinds.push(GroupedRow::Family(n));
+            if group.expanded {
+                rows.extend(&group.indices);
+                kinds.extend(std::iter::repeat_n(
+                    GroupedRow::Variant,
+                    group.indices.len(),
+                ));
+            }
+        }
+        self.filtered_fits = rows;
+        self.grouped_rows = kinds;
+        self.family_tree = tree;
+    }
+
+    /// The family whose collapsible row is `row`, in the grouped view.
+    pub fn family_at_row(&self, row: usize) -> Option<&FamilyGroup> {
+        match self.grouped_rows.get(row) {
+            Some(GroupedRow::Family(n)) => self.family_tree.get(*n),
+            _ => None,
+        }
+    }
+
+    /// Toggle the grouped-by-family view, keeping the selected model in view.
+    pub fn toggle_grouped_view(&mut self) {
+        let selected = self.filtered_fits.get(self.selected_row).copied();
+        self.grouped_view = !self.grouped_view;
+        self.apply_filters();
+        self.select_fit_index(selected);
+        self.pull_status = Some(if self.grouped_view {
+            format!(
+                "Grouped by family: {} families (Enter expands, G to ungroup)",
+                self.family_tree.len()
+            )
+        } else {
+            "Showing all models".to_string()
+        });
+    }
+
+    /// Toggle the pinned "Installed" section at the top of the table,
+    /// keeping the selected model in view.
+    pub fn toggle_installed_pinned(&mut self) {
+        let selected = self.filtered_fits.get(self.selected_row).copied();
+        self.show_installed_pinned = !self.show_installed_pinned;
+        self.apply_filters();
+        self.select_fit_index(selected);
+        self.pull_status = Some(if self.show_installed_pinned {
+            format!(
+                "Pinned {} installed models to the top (z to unpin)",
+                self.pinned_installed_rows()
+            )
+        } else {
+            "Installed models unpinned".to_string()
+        });
+    }
+
+    /// Switch the Params column and params filters between total and
+    /// active parameters, keeping the selected model in view.
+    pub fn toggle_params_active(&mut self) {
+        // Re-sorting moves fits around, so find the selection again by name.
+        let selected = self.selected_fit().map(|f| f.model.name.clone());
+        self.params_active = !self.params_active;
+        if self.sort_column == SortColumn::Params {
+            self.re_sort();
+        } else {
+            self.apply_filters();
+        }
+        let selected =
+            selected.and_then(|name| self.all_fits.iter().position(|f| f.model.name == name));
+        self.select_fit_index(selected);
+        self.pull_status = Some(if self.params_active {
+            "Params: active per token for MoE models (X for total)".to_string()
+        } else {
+            "Params: total".to_string()
+        });
+    }
+
+    /// Parameter count in billions as shown in the Params column and used by
+    /// the params filters.
+    pub fn shown_params_b(&self, model: &LlmModel) -> f64 {
+        if self.params_active {
+            model.active_params_b().unwrap_or_else(|| model.params_b())
+        } else {
+            model.params_b()
+        }
+    }
+
+    /// Params column text: the catalog's parameter count, or the active count
+    /// for MoE models while `params_active` is on.
+    pub fn params_cell_text(&self, model: &LlmModel) -> String {
+        match model.active_params_b().filter(|_| self.params_active) {
+            Some(active) => {
+                let text = format!("{:.1}", active);
+                format!("{}B", text.strip_suffix(".0").unwrap_or(&text))
+            }
+            None => model.parameter_count.clone(),
+        }
+    }
+
+    /// Number of rows in the pinned "Installed" section at the top of
+    /// `filtered_fits` (0 when pinning is off or in the grouped view).
+    pub fn pinned_installed_rows(&self) -> usize {
+        if !self.show_installed_pinned || self.grouped_view {
+            return 0;
+        }
+        self.filtered_fits
+            .iter()
+            .take_while(|&&i| self.all_fits[i].installed)
+            .count()
+    }
+
+    /// Expand or collapse the family on the selected row.
+    pub fn toggle_selected_family(&mut self) {
+        let Some(family) = self
+            .family_at_row(self.selected_row)
+            .map(|g| g.family.clone())
+        else {
+            return;
+        };
+        if !self.expanded_families.remove(&family) {
+            self.expanded_families.insert(family);
+        }
+        let row = self.selected_row;
+        self.apply_filters();
+        self.selected_row = row.min(self.filtered_fits.len().saturating_sub(1));
+    }
+
+    /// Enter: expand/collapse a family row in the grouped view, otherwise
+    /// toggle the detail view (which only shows individual models).
+    pub fn activate_selected_row(&mut self) {
+        if !self.show_detail && self.family_at_row(self.selected_row).is_some() {
+            self.toggle_selected_family();
+        } else {
+            self.toggle_detail();
+        }
+    }
+
+    fn select_fit_index(&mut self, fit_idx: Option<usize>) {
+        if let Some(row) = fit_idx.and_then(|i| self.filtered_fits.iter().position(|&f| f == i)) {
+            self.selected_row = row;
+        }
+    }
+
     pub fn selected_fit(&self) -> Option<&ModelFit> {
         self.filtered_fits
             .get(self.selected_row)
@@ -2035,11 +2534,46 @@ impl App {
         self.enqueue_capability_probes_for_visible(24);
     }
 
+    /// Move the selection to the first visible model with the given fit
+    /// level, leaving the active filters untouched.
+    pub fn jump_to_fit_level(&mut self, level: FitLevel) {
+        match self
+            .filtered_fits
+            .iter()
+            .position(|&idx| self.all_fits[idx].fit_level == level)
+        {
+            Some(row) => {
+                self.selected_row = row;
+                self.enqueue_capability_probes_for_visible(24);
+            }
+            None => {
+                self.pull_status = Some(format!("No {:?} models in the current view", level));
+            }
+        }
+    }
+
     pub fn cycle_fit_filter(&mut self) {
         self.fit_filter = self.fit_filter.next();
         self.apply_filters();
     }
 
+    /// Score of the best installed model among the selected use cases: the
+    /// bar the Upgrades availability filter measures against.
+    pub fn best_installed_score(&self) -> Option<f64> {
+        self.all_fits
+            .iter()
+            .filter(|fit| fit.installed)
+            .filter(|fit| {
+                self.use_cases
+                    .iter()
+                    .position(|uc| *uc == fit.use_case)
+                    .map(|idx| self.selected_use_cases[idx])
+                    .unwrap_or(true)
+            })
+            .map(|fit| fit.score)
+            .max_by(|a, b| a.total_cmp(b))
+    }
+
     pub fn cycle_availability_filter(&mut self) {
         self.availability_filter = self.availability_filter.next();
         self.apply_filters();
@@ -2056,7 +2590,8 @@ impl App {
         let has_range = !self.filter_params_min_input.is_empty()
             || !self.filter_params_max_input.is_empty()
             || !self.filter_mem_pct_min_input.is_empty()
-            || !self.filter_mem_pct_max_input.is_empty();
+            || !self.filter_mem_pct_max_input.is_empty()
+            || (self.filter_target_tps_only && self.target_tps().is_some());
         let has_search = !self.search_query.is_empty();
         let has_provider_filter = !self.selected_providers.iter().all(|&s| s);
         let has_use_case_filter = !self.selected_use_cases.iter().all(|&s| s);
@@ -2104,12 +2639,34 @@ impl App {
                 "mem",
                 "%",
             ),
+            self.target_tps()
+                .filter(|_| self.filter_target_tps_only)
+                .map(|t| format!("≥{t} tok/s")),
         ]
         .into_iter()
         .flatten()
         .collect()
     }
 
+    /// The target speed from the Filter popup, if one is set.
+    pub fn target_tps(&self) -> Option<f64> {
+        self.filter_target_tps_input
+            .parse::<f64>()
+            .ok()
+            .filter(|t| *t > 0.0)
+    }
+
+    /// Whether `fit` reaches the target speed, preferring measured tok/s over
+    /// the estimate like the table does. `None` when no target is set.
+    pub fn meets_target_tps(&self, fit: &ModelFit) -> Option<bool> {
+        let target = self.target_tps()?;
+        let tps = fit
+            .measured_tps
+            .as_ref()
+            .map_or(fit.estimated_tps, |m| m.tok_s);
+        Some(tps >= target)
+    }
+
     pub fn cycle_sort_column(&mut self) {
         self.sort_column = self.sort_column.next();
         self.sort_ascending = false;
@@ -2121,6 +2678,10 @@ impl App {
         self.theme.save();
     }
 
+    pub fn cycle_context_unit(&mut self) {
+        self.context_unit = self.context_unit.next();
+    }
+
     pub fn enter_search(&mut self) {
         self.input_mode = InputMode::Search;
     }
@@ -2130,6 +2691,9 @@ impl App {
     }
 
     pub fn search_input(&mut self, c: char) {
+        // Never split a multi-byte character, even if the cursor was set
+        // from outside the editing methods.
+        self.cursor_position = floor_char_boundary(&self.search_query, self.cursor_position);
         self.search_query.insert(self.cursor_position, c);
         self.cursor_position += c.len_utf8();
         // Changing the query should snap the list back to the top so all
@@ -2177,6 +2741,63 @@ impl App {
         self.apply_filters();
     }
 
+    /// Open the one-line note editor for the selected model, pre-filled with
+    /// its current note and `#tags`.
+    pub fn open_note_editor(&mut self) {
+        let Some(fit) = self.selected_fit() else {
+            return;
+        };
+        self.note_input = self
+            .model_notes
+            .get(&fit.model.name)
+            .map(ModelNote::to_line)
+            .unwrap_or_default();
+        self.note_cursor = self.note_input.len();
+        self.input_mode = InputMode::NoteEdit;
+    }
+
+    pub fn note_input_char(&mut self, c: char) {
+        if c.is_control() {
+            return;
+        }
+        self.note_cursor = floor_char_boundary(&self.note_input, self.note_cursor);
+        self.note_input.insert(self.note_cursor, c);
+        self.note_cursor += c.len_utf8();
+    }
+
+    pub fn note_backspace(&mut self) {
+        backspace_grapheme_input(&mut self.note_input, &mut self.note_cursor);
+    }
+
+    pub fn note_delete(&mut self) {
+        delete_grapheme_input(&mut self.note_input, self.note_cursor);
+    }
+
+    pub fn note_cursor_left(&mut self) {
+        self.note_cursor = previous_grapheme_boundary(&self.note_input, self.note_cursor);
+    }
+
+    pub fn note_cursor_right(&mut self) {
+        self.note_cursor = next_grapheme_boundary(&self.note_input, self.note_cursor);
+    }
+
+    /// Save the edited note for the selected model and leave the editor.
+    /// An empty line removes the model's note and tags.
+    pub fn save_note(&mut self) {
+        if let Some(name) = self.selected_fit().map(|f| f.model.name.clone()) {
+            self.model_notes
+                .set(&name, ModelNote::parse(&self.note_input));
+            self.apply_filters();
+        }
+        self.cancel_note();
+    }
+
+    pub fn cancel_note(&mut self) {
+        self.note_input.clear();
+        self.note_cursor = 0;
+        self.input_mode = InputMode::Normal;
+    }
+
     pub fn toggle_downloads(&mut self) {
         self.show_plan = false;
         self.show_compare = false;
@@ -2336,6 +2957,82 @@ impl App {
         self.show_downloads = false;
         self.show_benchmarks = false;
         self.show_detail = !self.show_detail;
+        self.detail_notes_scroll = 0;
+    }
+
+    pub fn toggle_memory_math(&mut self) {
+        self.show_memory_math = !self.show_memory_math;
+    }
+
+    pub fn scroll_detail_notes_up(&mut self) {
+        self.detail_notes_scroll = self.detail_notes_scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_detail_notes_down(&mut self) {
+        self.detail_notes_scroll = (self.detail_notes_scroll + 1).min(self.detail_notes_max_scroll);
+    }
+
+    /// Record how far the notes pane can scroll at its drawn size, pulling
+    /// the offset back if the content shrank or the terminal grew.
+    pub fn set_detail_notes_max_scroll(&mut self, max_scroll: usize) {
+        self.detail_notes_max_scroll = max_scroll;
+        self.detail_notes_scroll = self.detail_notes_scroll.min(max_scroll);
+    }
+
+    /// Step the detail view's forced quant through auto → each GGUF quant
+    /// (best quality first) → back to auto.
+    pub fn cycle_detail_force_quant(&mut self) {
+        let hierarchy = llmfit_core::models::QUANT_HIERARCHY;
+        self.detail_force_quant = match self.detail_force_quant {
+            None => hierarchy.first().copied(),
+            Some(q) => hierarchy
+                .iter()
+                .position(|h| *h == q)
+                .and_then(|i| hierarchy.get(i + 1))
+                .copied(),
+        };
+    }
+
+    /// Bring `detail_forced_fit` up to date with the selection and
+    /// `detail_force_quant`, re-analyzing only when either has changed.
+    pub fn refresh_detail_forced_fit(&mut self) {
+        let (Some(quant), Some(selected)) = (self.detail_force_quant, self.selected_fit()) else {
+            self.detail_forced_fit = None;
+            return;
+        };
+        let installed = selected.installed;
+        let installed_provider = selected.installed_provider.clone();
+        let current = matches!(
+            &self.detail_forced_fit,
+            Some((q, f)) if *q == quant && f.model.name == selected.model.name
+        );
+        if !current {
+            let config = CalcConfig {
+                force_quant: Some(quant.to_string()),
+                batch_size: self.batch_size,
+                ..self.calc_config.clone()
+            };
+            let context_limit = config.context_cap.or(self.context_limit);
+            let forced = ModelFit::analyze_with_runtime_and_config(
+                &selected.model,
+                &self.specs,
+                context_limit,
+                None,
+                config,
+            );
+            self.detail_forced_fit = Some((quant, forced));
+        }
+        if let Some((_, forced)) = &mut self.detail_forced_fit {
+            forced.installed = installed;
+            forced.installed_provider = installed_provider;
+        }
+    }
+
+    /// Size and score every model at `quant` (the `--quant` flag) instead
+    /// of its best fitting quant.
+    pub fn set_force_quant(&mut self, quant: Option<String>) {
+        self.calc_config.force_quant = quant;
+        self.rebuild_fits();
     }
 
     pub fn mark_selected_for_compare(&mut self) {
@@ -2359,13 +3056,34 @@ impl App {
             return;
         };
         let name = fit.model.name.clone();
-        match arboard::Clipboard::new() {
-            Ok(mut clipboard) => match clipboard.set_text(&name) {
-                Ok(()) => self.pull_status = Some(format!("Copied '{}' to clipboard", name)),
-                Err(e) => self.pull_status = Some(format!("Clipboard error: {}", e)),
-            },
-            Err(e) => self.pull_status = Some(format!("Clipboard error: {}", e)),
-        }
+        self.copy_to_clipboard(&name);
+    }
+
+    /// Copy a ready-to-run command for the selected model (`ollama run`,
+    /// `llama-cli -hf`, `mlx_lm.chat` or `vllm serve`).
+    pub fn copy_selected_run_command(&mut self) {
+        let Some(fit) = self.selected_fit() else {
+            self.pull_status = Some("No model selected".to_string());
+            return;
+        };
+        match crate::display::generate_run_command(fit) {
+            Some(command) => self.copy_to_clipboard(&command),
+            None => {
+                self.pull_status = Some(format!("No run command for {}", fit.model.name));
+            }
+        }
+    }
+
+    fn copy_to_clipboard(&mut self, text: &str) {
+        // Headless sessions (SSH without X11/Wayland, containers) have no
+        // clipboard at all; say so instead of surfacing the raw backend error.
+        self.pull_status = Some(match arboard::Clipboard::new() {
+            Ok(mut clipboard) => match clipboard.set_text(text) {
+                Ok(()) => format!("Copied '{}' to clipboard", text),
+                Err(e) => format!("Clipboard error: {}", e),
+            },
+            Err(_) => format!("Clipboard unavailable (headless session?): {}", text),
+        });
     }
 
     pub fn selected_compare_pair(&self) -> Option<(&ModelFit, &ModelFit)> {
@@ -3063,6 +3781,8 @@ impl App {
             quant,
             target_tps,
             kv_quant,
+            turns: None,
+            batch_size: None,
         };
 
         match estimate_model_plan(&fit.model, &request, &self.specs) {
@@ -3193,16 +3913,20 @@ impl App {
         }
     }
 
+    /// Flip the provider under the cursor and re-filter the table at once,
+    /// keeping the selected model highlighted if it is still listed.
     pub fn provider_popup_toggle(&mut self) {
         let filtered = self.provider_filtered_indices();
         if let Some(&idx) = filtered.get(self.provider_cursor) {
+            let selected = self.filtered_fits.get(self.selected_row).copied();
             self.selected_providers[idx] = !self.selected_providers[idx];
             self.apply_filters();
+            self.select_fit_index(selected);
         }
     }
 
     /// Toggle all currently-visible (matching) providers. If they are all
-    /// selected, deselect them; otherwise select them all.
+    /// selected, deselect them; if some or none are, select them all.
     pub fn provider_popup_select_all(&mut self) {
         let filtered = self.provider_filtered_indices();
         if filtered.is_empty() {
@@ -3216,6 +3940,14 @@ impl App {
         self.apply_filters();
     }
 
+    /// Flip the selected state of each currently-visible provider.
+    pub fn provider_popup_invert_selection(&mut self) {
+        for &i in &self.provider_filtered_indices() {
+            self.selected_providers[i] = !self.selected_providers[i];
+        }
+        self.apply_filters();
+    }
+
     pub fn provider_popup_clear_all(&mut self) {
         for &i in &self.provider_filtered_indices() {
             self.selected_providers[i] = false;
@@ -3613,6 +4345,54 @@ impl App {
         self.input_mode = InputMode::Normal;
     }
 
+    pub fn open_history_popup(&mut self) {
+        self.input_mode = InputMode::HistoryPopup;
+    }
+
+    pub fn close_history_popup(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Diff the analyzed catalog against the one seen on the previous run,
+    /// then remember the current catalog for next time.
+    pub fn check_new_models(&mut self) {
+        let fits = &self.all_fits;
+        let names = || fits.iter().map(|f| f.model.name.as_str());
+        let mut seen = SeenModels::load();
+        self.new_models = seen.new_since(names());
+        seen.record(names());
+    }
+
+    pub fn open_new_models_popup(&mut self) {
+        self.new_models_cursor = 0;
+        self.input_mode = InputMode::NewModelsPopup;
+    }
+
+    pub fn close_new_models_popup(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn new_models_up(&mut self) {
+        self.new_models_cursor = self.new_models_cursor.saturating_sub(1);
+    }
+
+    pub fn new_models_down(&mut self) {
+        if self.new_models_cursor + 1 < self.new_models.len() {
+            self.new_models_cursor += 1;
+        }
+    }
+
+    /// Close the popup and search the table for the highlighted new model.
+    pub fn search_new_model(&mut self) {
+        if let Some(name) = self.new_models.get(self.new_models_cursor).cloned() {
+            self.cursor_position = name.len();
+            self.search_query = name;
+            self.selected_row = 0;
+            self.apply_filters();
+        }
+        self.close_new_models_popup();
+    }
+
     // ── Hardware simulation ──────────────────────────────────────────
 
     pub fn open_simulation_popup(&mut self) {
@@ -3654,6 +4434,21 @@ impl App {
         self.input_mode = InputMode::Normal;
     }
 
+    /// Cycle the batch size through [`BATCH_SIZES`] and re-run the analysis.
+    pub fn cycle_batch_size(&mut self) {
+        let next = BATCH_SIZES
+            .iter()
+            .position(|&b| b == self.batch_size)
+            .map(|i| BATCH_SIZES[(i + 1) % BATCH_SIZES.len()])
+            .unwrap_or(BATCH_SIZES[0]);
+        self.set_batch_size(next);
+    }
+
+    pub fn set_batch_size(&mut self, batch_size: u32) {
+        self.batch_size = batch_size.max(1);
+        self.rebuild_fits();
+    }
+
     pub fn reset_simulation(&mut self) {
         self.specs = self.real_specs.clone();
         self.sim_active = false;
@@ -3663,6 +4458,7 @@ impl App {
     /// Re-evaluate all model fits against current `self.specs`, preserving
     /// installed status and filter selections.
     fn rebuild_fits(&mut self) {
+        self.detail_forced_fit = None;
         let db = ModelDatabase::new();
 
         self.backend_hidden_count = db
@@ -3677,15 +4473,25 @@ impl App {
             .iter()
             .filter(|m| backend_compatible(m, &self.specs))
             .map(|m| {
-                let mut fit =
-                    ModelFit::analyze_with_context_limit(m, &self.specs, self.context_limit);
-                fit.installed = self.installed.is_installed(&m.name);
+                let config = CalcConfig {
+                    batch_size: self.batch_size,
+                    ..self.calc_config.clone()
+                };
+                let mut fit = ModelFit::analyze_with_runtime_and_config(
+                    m,
+                    &self.specs,
+                    self.context_limit,
+                    None,
+                    config,
+                );
+                self.installed.mark(&mut fit);
                 fit.measured_tps = measured_index
                     .as_ref()
                     .and_then(|idx| idx.lookup(&m.name, &fit.best_quant));
                 fit
             })
             .collect();
+        self.apply_installed_adjustments();
 
         self.all_fits = llmfit_core::fit::rank_models_by_fit(self.all_fits.drain(..).collect());
         self.selected_row = 0;
@@ -3806,6 +4612,8 @@ impl App {
             params_max: self.filter_params_max_input.clone(),
             mem_pct_min: self.filter_mem_pct_min_input.clone(),
             mem_pct_max: self.filter_mem_pct_max_input.clone(),
+            target_tps: self.filter_target_tps_input.clone(),
+            target_tps_only: self.filter_target_tps_only,
             sort_ascending: self.sort_ascending,
             fit_filter: self.fit_filter,
             availability_filter: self.availability_filter,
@@ -3822,6 +4630,8 @@ impl App {
             self.filter_params_max_input = snap.params_max;
             self.filter_mem_pct_min_input = snap.mem_pct_min;
             self.filter_mem_pct_max_input = snap.mem_pct_max;
+            self.filter_target_tps_input = snap.target_tps;
+            self.filter_target_tps_only = snap.target_tps_only;
             self.sort_ascending = snap.sort_ascending;
             self.fit_filter = snap.fit_filter;
             self.availability_filter = snap.availability_filter;
@@ -3862,6 +4672,14 @@ impl App {
                     return;
                 }
             }
+            FilterPopupField::TargetTps => {
+                if c == '.' && self.filter_target_tps_input.contains('.') {
+                    return;
+                }
+                if !c.is_ascii_digit() && c != '.' {
+                    return;
+                }
+            }
             _ => return,
         }
         let pos = self.filter_cursor_position;
@@ -3892,7 +4710,9 @@ impl App {
             FilterPopupField::ParamsMax => self.filter_params_max_input.len(),
             FilterPopupField::MemPctMin => self.filter_mem_pct_min_input.len(),
             FilterPopupField::MemPctMax => self.filter_mem_pct_max_input.len(),
-            FilterPopupField::SortDirection
+            FilterPopupField::TargetTps => self.filter_target_tps_input.len(),
+            FilterPopupField::TargetTpsOnly
+            | FilterPopupField::SortDirection
             | FilterPopupField::FitFilter
             | FilterPopupField::Availability => 0,
         }
@@ -3904,7 +4724,9 @@ impl App {
             FilterPopupField::ParamsMax => &mut self.filter_params_max_input,
             FilterPopupField::MemPctMin => &mut self.filter_mem_pct_min_input,
             FilterPopupField::MemPctMax => &mut self.filter_mem_pct_max_input,
-            FilterPopupField::SortDirection
+            FilterPopupField::TargetTps => &mut self.filter_target_tps_input,
+            FilterPopupField::TargetTpsOnly
+            | FilterPopupField::SortDirection
             | FilterPopupField::FitFilter
             | FilterPopupField::Availability => {
                 unreachable!("no text input for toggle fields")
@@ -3938,6 +4760,10 @@ impl App {
         self.fit_filter = self.fit_filter.next();
     }
 
+    pub fn filter_toggle_target_tps_only(&mut self) {
+        self.filter_target_tps_only = !self.filter_target_tps_only;
+    }
+
     pub fn cycle_filter_availability(&mut self) {
         self.availability_filter = self.availability_filter.next();
     }
@@ -3988,7 +4814,10 @@ impl App {
     }
 
     pub fn reset_advanced_config(&mut self) {
-        self.calc_config = CalcConfig::default();
+        self.calc_config = CalcConfig {
+            force_quant: self.calc_config.force_quant.take(),
+            ..CalcConfig::from_user_config()
+        };
         self.rebuild_fits_with_config();
         // Refresh input fields to show defaults
         self.open_advanced_config_popup();
@@ -4103,7 +4932,7 @@ impl App {
             },
             context_cap,
             ddr_bandwidth_gbps,
-            ..self.calc_config
+            ..self.calc_config.clone()
         };
 
         // Re-run analysis with new config
@@ -4113,6 +4942,7 @@ impl App {
 
     /// Rebuild fits using the custom calc_config
     fn rebuild_fits_with_config(&mut self) {
+        self.detail_forced_fit = None;
         let db = ModelDatabase::new();
 
         self.backend_hidden_count = db
@@ -4127,15 +4957,19 @@ impl App {
             .iter()
             .filter(|m| backend_compatible(m, &self.specs))
             .map(|m| {
-                let mut fit =
-                    ModelFit::analyze_with_config(m, &self.specs, self.calc_config.clone());
-                fit.installed = self.installed.is_installed(&m.name);
+                let config = CalcConfig {
+                    batch_size: self.batch_size,
+                    ..self.calc_config.clone()
+                };
+                let mut fit = ModelFit::analyze_with_config(m, &self.specs, config);
+                self.installed.mark(&mut fit);
                 fit.measured_tps = measured_index
                     .as_ref()
                     .and_then(|idx| idx.lookup(&m.name, &fit.best_quant));
                 fit
             })
             .collect();
+        self.apply_installed_adjustments();
 
         self.all_fits = llmfit_core::fit::rank_models_by_fit(self.all_fits.drain(..).collect());
         self.selected_row = 0;
@@ -4152,15 +4986,26 @@ impl App {
     /// Re-sort all_fits using current sort column and installed_first preference, then refilter.
     fn re_sort(&mut self) {
         let fits = std::mem::take(&mut self.all_fits);
-        let mut sorted = llmfit_core::fit::rank_models_by_fit_opts_col(
-            fits,
-            self.installed_first,
-            self.sort_column,
-        );
-        if self.sort_ascending {
-            sorted.reverse();
-        }
-        self.all_fits = sorted;
+        self.all_fits = if self.sort_column == SortColumn::Params && self.params_active {
+            // Sort by the count the Params column shows.
+            llmfit_core::fit::rank_models_by_fit_sorted_by(fits, self.installed_first, |a, b| {
+                let cmp = self
+                    .shown_params_b(&b.model)
+                    .total_cmp(&self.shown_params_b(&a.model));
+                if self.sort_ascending {
+                    cmp.reverse()
+                } else {
+                    cmp
+                }
+            })
+        } else {
+            llmfit_core::fit::rank_models_by_fit_sorted(
+                fits,
+                self.installed_first,
+                self.sort_column,
+                self.sort_ascending,
+            )
+        };
         self.apply_filters();
     }
 
@@ -4219,6 +5064,15 @@ impl App {
         }
     }
 
+    /// The Groq model that can serve `fit` remotely: only offered when the
+    /// model is too big to run locally and Groq is reachable.
+    pub fn groq_model_for(&self, fit: &ModelFit) -> Option<&str> {
+        if !self.groq_available || fit.fit_level != FitLevel::TooTight {
+            return None;
+        }
+        providers::groq_model_for(&fit.model.name, &self.groq_models)
+    }
+
     /// Build a user-friendly message explaining why no download is available,
     /// based on the model's weight format.
     fn format_no_download_message(
@@ -4380,18 +5234,51 @@ impl App {
         }
     }
 
+    /// Append the finished (or failed) pull to the persistent download history.
+    fn record_pull(&mut self, provider: String, result: DownloadResult) {
+        let model_name = self
+            .pull_model_name
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string());
+        let size_gb = self
+            .all_fits
+            .iter()
+            .find(|f| f.model.name == model_name)
+            .map(|f| f.model.estimate_disk_gb(&f.best_quant));
+        self.download_history.add_record(DownloadRecord {
+            model_name,
+            provider,
+            result,
+            timestamp: DownloadHistory::epoch_now(),
+            file_path: None,
+            size_gb,
+        });
+    }
+
     /// Poll the active pull for progress. Called each TUI tick.
     pub fn tick_pull(&mut self) {
         self.tick_provider_detection();
         self.enqueue_capability_probes_for_visible(24);
         self.tick_download_capability();
         self.tick_count = self.tick_count.wrapping_add(1);
-        let Some(handle) = &self.pull_active else {
-            return;
+        if self.drain_pull_events() {
+            self.refresh_installed();
+        }
+    }
+
+    /// Drain every pending event from the active pull. Returns true when the
+    /// pull completed (or its sender went away), so the caller can refresh the
+    /// installed-model index once for the whole batch. Events arriving after
+    /// the pull finished are consumed and ignored.
+    fn drain_pull_events(&mut self) -> bool {
+        let Some(handle) = self.pull_active.take() else {
+            return false;
         };
-        // Drain all available events
+        let mut pull_completion_pending = false;
+        let mut finished = false;
         loop {
             match handle.receiver.try_recv() {
+                Ok(_) if finished => {}
                 Ok(PullEvent::Progress { status, percent }) => {
                     if let Some(p) = percent {
                         self.pull_percent = Some(p);
@@ -4406,23 +5293,12 @@ impl App {
                     let done_msg = format!("Download complete via {}!", provider_label);
                     self.pull_status = Some(done_msg);
 
-                    // Record in download history
-                    self.download_history.add_record(DownloadRecord {
-                        model_name: self
-                            .pull_model_name
-                            .clone()
-                            .unwrap_or_else(|| "unknown".to_string()),
-                        provider: provider_label,
-                        result: DownloadResult::Success,
-                        timestamp: DownloadHistory::epoch_now(),
-                        file_path: None,
-                    });
+                    self.record_pull(provider_label, DownloadResult::Success);
 
                     self.pull_percent = None;
-                    self.pull_active = None;
                     self.pull_provider = None;
-                    self.refresh_installed();
-                    return;
+                    pull_completion_pending = true;
+                    finished = true;
                 }
                 Ok(PullEvent::Error(e)) => {
                     let provider_label = self
@@ -4431,34 +5307,29 @@ impl App {
                         .unwrap_or_else(|| "unknown".to_string());
                     self.pull_status = Some(format!("Error: {}", e));
 
-                    // Record failure in download history
-                    self.download_history.add_record(DownloadRecord {
-                        model_name: self
-                            .pull_model_name
-                            .clone()
-                            .unwrap_or_else(|| "unknown".to_string()),
-                        provider: provider_label,
-                        result: DownloadResult::Error(e),
-                        timestamp: DownloadHistory::epoch_now(),
-                        file_path: None,
-                    });
+                    self.record_pull(provider_label, DownloadResult::Error(e));
 
                     self.pull_percent = None;
-                    self.pull_active = None;
                     self.pull_provider = None;
-                    return;
+                    finished = true;
                 }
                 Err(mpsc::TryRecvError::Empty) => break,
                 Err(mpsc::TryRecvError::Disconnected) => {
-                    self.pull_status = Some("Pull ended".to_string());
-                    self.pull_percent = None;
-                    self.pull_active = None;
-                    self.pull_provider = None;
-                    self.refresh_installed();
-                    return;
+                    if !finished {
+                        self.pull_status = Some("Pull ended".to_string());
+                        self.pull_percent = None;
+                        self.pull_provider = None;
+                        pull_completion_pending = true;
+                        finished = true;
+                    }
+                    break;
                 }
             }
         }
+        if !finished {
+            self.pull_active = Some(handle);
+        }
+        pull_completion_pending
     }
 
     fn available_download_providers(
@@ -4552,15 +5423,22 @@ impl App {
         self.start_download_with_provider(model_name, provider);
     }
 
+    /// Ollama's local models with their on-disk quant and size.
+    pub fn ollama_local_models(&self) -> &[llmfit_core::providers::OllamaModel] {
+        self.ollama.local_models()
+    }
+
     /// Re-query all providers for installed models and update all_fits.
     pub fn refresh_installed(&mut self) {
-        let (ollama, ollama_count) = self.ollama.installed_models_counted();
+        let (ollama, ollama_count) = self.ollama.refresh_installed();
         let mlx = self.mlx.installed_models();
         let (llamacpp, llamacpp_count) = self.llamacpp.installed_models_counted();
         let (docker_mr, docker_mr_count) = self.docker_mr.installed_models_counted();
         let (lmstudio, lmstudio_count) = self.lmstudio.installed_models_counted();
         let (vllm, vllm_count) = self.vllm.installed_models_counted();
         let (ramalama, ramalama_count) = self.ramalama.installed_models_counted();
+        let (jan, jan_count) = self.jan.installed_models_counted();
+        let (gpt4all, gpt4all_count) = self.gpt4all.installed_models_counted();
         self.installed = llmfit_core::analysis::InstalledIndex {
             ollama,
             ollama_count,
@@ -4575,14 +5453,61 @@ impl App {
             vllm_count,
             ramalama,
             ramalama_count,
+            jan,
+            jan_count,
+            gpt4all,
+            gpt4all_count,
         };
-        for fit in &mut self.all_fits {
-            fit.installed = self.installed.is_installed(&fit.model.name);
+        let installed_names = |fits: &[ModelFit]| -> HashSet<String> {
+            fits.iter()
+                .filter(|f| f.installed)
+                .map(|f| f.model.name.clone())
+                .collect()
+        };
+        let before = installed_names(&self.all_fits);
+        self.remark_installed();
+        self.refresh_ollama_orphans();
+        if let Some(msg) = installed_change_message(&before, &installed_names(&self.all_fits)) {
+            self.pull_status = Some(msg);
         }
         self.re_sort();
         self.enqueue_capability_probes_for_visible(24);
     }
 
+    /// Re-mark installed status on every fit, then re-apply what depends on
+    /// it.
+    fn remark_installed(&mut self) {
+        for fit in &mut self.all_fits {
+            self.installed.mark(fit);
+        }
+        self.apply_installed_adjustments();
+    }
+
+    /// Use the real size of installed Ollama copies in place of the weights
+    /// estimate, and apply the installed-family ranking bonus.
+    fn apply_installed_adjustments(&mut self) {
+        let local = self.ollama.local_models();
+        for fit in &mut self.all_fits {
+            let size = llmfit_core::providers::find_ollama_model(&fit.model.name, local)
+                .and_then(|copy| self.ollama.model_size_gb(&copy.name));
+            match size {
+                Some(size) => fit.apply_actual_size(size),
+                None => fit.clear_actual_size(),
+            }
+        }
+        llmfit_core::analysis::apply_installed_family_bonus(
+            &mut self.all_fits,
+            &self.calc_config.scoring_weights,
+        );
+    }
+
+    /// Recompute the installed Ollama tags that have no row in the table.
+    pub fn refresh_ollama_orphans(&mut self) {
+        self.ollama_orphans = self
+            .installed
+            .ollama_orphans(self.all_fits.iter().map(|f| f.model.name.as_str()));
+    }
+
     pub fn download_capability_for(&self, model_name: &str) -> DownloadCapability {
         self.download_capabilities
             .get(model_name)
@@ -4685,6 +5610,7 @@ impl App {
                             self.installed.ollama = installed;
                             self.installed.ollama_count = installed_count;
                             self.ollama = provider;
+                            self.refresh_ollama_orphans();
                         }
                         ProviderDetectionMsg::Mlx {
                             available,
@@ -4733,6 +5659,10 @@ impl App {
                             self.installed.ramalama = installed;
                             self.installed.ramalama_count = installed_count;
                         }
+                        ProviderDetectionMsg::Groq { available, models } => {
+                            self.groq_available = available;
+                            self.groq_models = models;
+                        }
                     }
                 }
                 Err(mpsc::TryRecvError::Empty) => break,
@@ -4743,10 +5673,7 @@ impl App {
             }
         }
         if got_any {
-            // Re-mark installed status for all models
-            for fit in &mut self.all_fits {
-                fit.installed = self.installed.is_installed(&fit.model.name);
-            }
+            self.remark_installed();
             self.re_sort();
         }
     }
@@ -5156,6 +6083,8 @@ mod tests {
                 gpus: Vec::new(),
                 cluster_mode: false,
                 cluster_node_count: 0,
+                gpu_cores: None,
+                ram_config: None,
             },
             None,
         )
@@ -5193,6 +6122,9 @@ mod tests {
             vocab_size: None,
             shared_expert_intermediate_size: None,
             architecture: None,
+            family: String::new(),
+            trained_context: None,
+            max_context: None,
         }
     }
 
@@ -5200,6 +6132,8 @@ mod tests {
         ModelFit {
             model: test_model(name),
             fit_level,
+            fit_level_if_freed: None,
+            actual_weights_gb: None,
             run_mode: RunMode::Gpu,
             memory_required_gb: 4.0,
             memory_available_gb: 8.0,
@@ -5218,8 +6152,11 @@ mod tests {
             use_case: UseCase::General,
             runtime: InferenceRuntime::LlamaCpp,
             installed: false,
+            installed_provider: None,
             fits_with_turboquant: false,
             effective_context_length: 8192,
+            batch_size: 1,
+            kv_quant: llmfit_core::models::KvQuant::Fp16,
             usable_context: 8192,
             estimate_basis: Default::default(),
             measured_tps: None,
@@ -5457,6 +6394,399 @@ mod tests {
         app.filter_params_max_input.clear();
         app.filter_mem_pct_min_input.clear();
         app.filter_mem_pct_max_input.clear();
+        app.filter_target_tps_input.clear();
+        app.filter_target_tps_only = false;
+        app.params_active = false;
+    }
+
+    #[test]
+    fn installed_fit_filter_shows_only_installed_models() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.all_fits = vec![
+            test_fit("gemma-2b", FitLevel::Perfect, 90.0),
+            test_fit("gemma-7b", FitLevel::Good, 80.0),
+            test_fit("llama-70b", FitLevel::TooTight, 40.0),
+        ];
+        app.all_fits[1].installed = true;
+        app.all_fits[2].installed = true;
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+
+        app.fit_filter = FitFilter::TooTight;
+        app.cycle_fit_filter();
+        assert_eq!(app.fit_filter, FitFilter::Installed);
+        assert_eq!(app.fit_filter.label(), "Installed Only");
+        assert_eq!(
+            FitFilter::from_label("Installed Only"),
+            FitFilter::Installed
+        );
+        // Installed models are listed whether or not they fit.
+        assert_eq!(app.filtered_fits, vec![1, 2]);
+
+        app.cycle_fit_filter();
+        assert_eq!(app.fit_filter, FitFilter::TurboQuantFit);
+
+        // The availability filter's Installed option lists the same models.
+        app.fit_filter = FitFilter::All;
+        app.availability_filter = AvailabilityFilter::Installed;
+        app.apply_filters();
+        assert_eq!(app.filtered_fits, vec![1, 2]);
+    }
+
+    #[test]
+    fn ollama_orphans_track_installs_without_a_row() {
+        let mut app = test_app();
+        assert!(app.ollama_orphans.is_empty());
+
+        assert!(
+            app.all_fits
+                .iter()
+                .any(|f| f.model.name == "Qwen/Qwen2.5-Coder-14B-Instruct")
+        );
+        for name in ["qwen2.5-coder:14b", "my-modelfile-bot:latest"] {
+            app.installed.ollama.insert(name.to_string());
+        }
+        app.refresh_ollama_orphans();
+        assert_eq!(app.ollama_orphans, ["my-modelfile-bot:latest"]);
+    }
+
+    #[test]
+    fn tools_capability_filter_excludes_models_without_the_flag() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.all_fits = vec![
+            test_fit("qwen3-8b", FitLevel::Perfect, 90.0),
+            test_fit("bge-large", FitLevel::Perfect, 85.0),
+            test_fit("llava-7b", FitLevel::Good, 80.0),
+        ];
+        app.all_fits[0].model.capabilities = vec![Capability::ToolUse, Capability::JsonMode];
+        app.all_fits[2].model.capabilities = vec![Capability::Vision];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.selected_capabilities = vec![true; app.capabilities.len()];
+        app.apply_filters();
+        assert_eq!(app.filtered_fits, vec![0, 1, 2]);
+
+        // Clear every capability, then tick Tool Use alone.
+        app.capability_popup_select_all();
+        app.capability_cursor = app
+            .capabilities
+            .iter()
+            .position(|c| *c == Capability::ToolUse)
+            .unwrap();
+        app.capability_popup_toggle();
+        assert_eq!(app.filtered_fits, vec![0]);
+        assert!(
+            app.filtered_fits
+                .iter()
+                .all(|&i| app.all_fits[i].model.supports_tools())
+        );
+    }
+
+    #[test]
+    fn provider_select_all_and_invert_from_each_starting_state() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.providers = ["A", "B", "C", "D", "E"].map(String::from).to_vec();
+        app.provider_search.clear();
+
+        // All selected: select-all clears, invert clears.
+        app.selected_providers = vec![true; 5];
+        app.provider_popup_select_all();
+        assert_eq!(app.selected_providers, [false; 5]);
+        app.selected_providers = vec![true; 5];
+        app.provider_popup_invert_selection();
+        assert_eq!(app.selected_providers, [false; 5]);
+
+        // None selected: both select everything.
+        app.selected_providers = vec![false; 5];
+        app.provider_popup_select_all();
+        assert_eq!(app.selected_providers, [true; 5]);
+        app.selected_providers = vec![false; 5];
+        app.provider_popup_invert_selection();
+        assert_eq!(app.selected_providers, [true; 5]);
+
+        // Partial: select-all fills in the rest, invert flips each one.
+        let partial = vec![true, false, true, true, false];
+        app.selected_providers = partial.clone();
+        app.provider_popup_select_all();
+        assert_eq!(app.selected_providers, [true; 5]);
+        app.selected_providers = partial;
+        app.provider_popup_invert_selection();
+        assert_eq!(app.selected_providers, [false, true, false, false, true]);
+
+        // Only the providers matching the popup search are touched.
+        app.provider_search = "B".to_string();
+        app.provider_popup_invert_selection();
+        assert_eq!(app.selected_providers, [false, false, false, false, true]);
+    }
+
+    #[test]
+    #[ignore] // Wall-clock check; run with `cargo test --release -- --ignored`
+    fn provider_toggle_refilters_the_full_database_within_a_frame() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.selected_providers.fill(true);
+        app.apply_filters();
+        app.open_provider_popup();
+        app.provider_cursor = 0;
+        let started = std::time::Instant::now();
+        app.provider_popup_toggle();
+        let elapsed = started.elapsed();
+        assert!(
+            elapsed < std::time::Duration::from_millis(10),
+            "provider toggle took {elapsed:?}"
+        );
+    }
+
+    #[test]
+    fn provider_toggle_refilters_instantly_and_clamps_an_emptied_list() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.selected_providers.fill(true);
+        app.apply_filters();
+        let total = app.filtered_fits.len();
+        assert!(total > 100 && app.providers.len() > 1);
+
+        // Park the selection on a model that is neither first nor last.
+        app.selected_row = total / 2;
+        let selected = app.filtered_fits[app.selected_row];
+        // Drop a provider that publishes a model no GGUF source re-hosts.
+        let keep = &app.all_fits[selected].model.provider;
+        let gone = app
+            .filtered_fits
+            .iter()
+            .copied()
+            .find(|&i| {
+                let model = &app.all_fits[i].model;
+                &model.provider != keep && model.gguf_sources.is_empty()
+            })
+            .expect("a model without GGUF sources");
+        let dropped = &app.all_fits[gone].model.provider;
+        let cursor = app.providers.iter().position(|p| p == dropped).unwrap();
+
+        // Toggling another provider off hides its models straight away and
+        // leaves the highlight on the same model.
+        app.open_provider_popup();
+        app.provider_cursor = cursor;
+        app.provider_popup_toggle();
+        assert!(!app.filtered_fits.contains(&gone));
+        assert_eq!(app.filtered_fits.get(app.selected_row), Some(&selected));
+
+        // Toggling it back restores every model.
+        app.provider_popup_toggle();
+        assert_eq!(app.filtered_fits.len(), total);
+        assert_eq!(app.filtered_fits.get(app.selected_row), Some(&selected));
+
+        // Deselecting everyone empties the list; the selection (and any
+        // visual anchor) must drop back to row 0 rather than dangle.
+        app.visual_anchor = Some(total - 1);
+        app.selected_row = total - 1;
+        for cursor in 0..app.providers.len() {
+            app.provider_cursor = cursor;
+            if app.selected_providers[cursor] {
+                app.provider_popup_toggle();
+            }
+        }
+        assert!(app.filtered_fits.is_empty());
+        assert_eq!(app.selected_row, 0);
+        assert_eq!(app.visual_anchor, Some(0));
+        assert!(app.selected_fit().is_none());
+        assert_eq!(app.visual_selection_count(), 1);
+        app.move_down();
+        app.page_down();
+        app.cycle_top_bottom();
+        assert_eq!(app.selected_row, 0);
+    }
+
+    #[test]
+    fn exported_view_round_trips_through_json() {
+        let fits = vec![
+            test_fit("gemma-2b", FitLevel::Perfect, 90.0),
+            test_fit("gemma-7b", FitLevel::Good, 80.0),
+            test_fit("llama-7b", FitLevel::Marginal, 70.0),
+        ];
+
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.all_fits = fits.clone();
+        app.providers = vec!["Google".to_string(), "Meta".to_string()];
+        app.selected_providers = vec![true, false];
+        app.quants = vec!["Q4_K_M".to_string(), "Q8_0".to_string()];
+        app.selected_quants = vec![false, true];
+        app.fit_filter = FitFilter::Runnable;
+        app.availability_filter = AvailabilityFilter::HasGguf;
+        app.sort_column = SortColumn::Params;
+        app.sort_ascending = true;
+        app.installed_first = true;
+        app.search_query = "gemma 7b".to_string();
+        app.filter_params_min_input = "3".to_string();
+        app.filter_mem_pct_max_input = "90".to_string();
+
+        let path =
+            std::env::temp_dir().join(format!("llmfit-view-roundtrip-{}.json", std::process::id()));
+        let exported = app.filter_config();
+        exported.export_to(&path).expect("export view");
+        let imported = FilterConfig::import_from(&path).expect("import view");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(imported, exported);
+
+        let mut restored = test_app();
+        clear_persisted_filters(&mut restored);
+        restored.all_fits = fits;
+        restored.providers = app.providers.clone();
+        restored.selected_providers = vec![true, true];
+        restored.quants = app.quants.clone();
+        restored.selected_quants = vec![true, true];
+        restored.restore_filter_config(&imported);
+
+        assert_eq!(restored.fit_filter, FitFilter::Runnable);
+        assert_eq!(restored.availability_filter, AvailabilityFilter::HasGguf);
+        assert_eq!(restored.sort_column, SortColumn::Params);
+        assert!(restored.sort_ascending);
+        assert!(restored.installed_first);
+        assert_eq!(restored.search_query, "gemma 7b");
+        assert_eq!(restored.cursor_position, "gemma 7b".len());
+        assert_eq!(restored.selected_providers, vec![true, false]);
+        assert_eq!(restored.selected_quants, vec![false, true]);
+        assert_eq!(restored.filter_params_min_input, "3");
+        assert_eq!(restored.filter_mem_pct_max_input, "90");
+        assert_eq!(restored.filter_config(), exported);
+    }
+
+    #[test]
+    fn saved_quant_selection_survives_database_quant_changes() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.quants = vec!["Q4_K_M".to_string(), "Q8_0".to_string()];
+        app.selected_quants = vec![false, true];
+        app.filter_target_tps_input = "15".to_string();
+        app.filter_target_tps_only = true;
+        let json = serde_json::to_string(&app.filter_config()).expect("serialize filters");
+        let saved: FilterConfig = serde_json::from_str(&json).expect("deserialize filters");
+
+        // A newer database dropped Q8_0 and added IQ4_XS: the quant list comes
+        // from the database, saved choices apply by name, new quants keep
+        // their default.
+        let mut restored = test_app();
+        clear_persisted_filters(&mut restored);
+        restored.quants = vec!["IQ4_XS".to_string(), "Q4_K_M".to_string()];
+        restored.selected_quants = vec![true, true];
+        restored.restore_filter_config(&saved);
+
+        assert_eq!(restored.quants, vec!["IQ4_XS", "Q4_K_M"]);
+        assert_eq!(restored.selected_quants, vec![true, false]);
+        assert_eq!(restored.filter_target_tps_input, "15");
+        assert!(restored.filter_target_tps_only);
+    }
+
+    #[test]
+    fn jump_to_fit_level_selects_first_match_without_filtering() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.all_fits = vec![
+            test_fit("alpha", FitLevel::Perfect, 90.0),
+            test_fit("beta", FitLevel::Good, 80.0),
+            test_fit("gamma", FitLevel::Marginal, 70.0),
+            test_fit("delta", FitLevel::Good, 60.0),
+        ];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.apply_filters();
+
+        app.jump_to_fit_level(FitLevel::Good);
+        assert_eq!(app.selected_row, 1);
+        app.jump_to_fit_level(FitLevel::Marginal);
+        assert_eq!(app.selected_row, 2);
+        assert_eq!(app.filtered_fits.len(), 4);
+
+        app.jump_to_fit_level(FitLevel::TooTight);
+        assert_eq!(app.selected_row, 2);
+        assert!(app.pull_status.is_some());
+    }
+
+    #[test]
+    fn ascending_sort_keeps_installed_models_on_top() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        let fit = |name: &str, score: f64, installed: bool| ModelFit {
+            installed,
+            ..test_fit(name, FitLevel::Good, score)
+        };
+        app.all_fits = vec![
+            fit("low", 10.0, false),
+            fit("installed-high", 90.0, true),
+            fit("high", 80.0, false),
+            fit("installed-low", 20.0, true),
+        ];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.installed_first = true;
+        app.sort_column = SortColumn::Score;
+        app.sort_ascending = true;
+        app.re_sort();
+
+        let names: Vec<&str> = app
+            .filtered_fits
+            .iter()
+            .map(|&i| app.all_fits[i].model.name.as_str())
+            .collect();
+        assert_eq!(names, ["installed-low", "installed-high", "low", "high"]);
+    }
+
+    #[test]
+    fn grouped_view_collapses_families_and_expands_on_enter() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.all_fits = vec![
+            test_fit("Qwen/Qwen2.5-7B-Instruct", FitLevel::Good, 80.0),
+            test_fit("meta-llama/Llama-3.1-8B-Instruct", FitLevel::Good, 75.0),
+            test_fit("Qwen/Qwen2.5-72B-Instruct", FitLevel::TooTight, 90.0),
+            test_fit("Qwen/Qwen2.5-14B-Instruct", FitLevel::Perfect, 70.0),
+        ];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.apply_filters();
+        app.selected_row = 1;
+
+        app.toggle_grouped_view();
+        assert_eq!(app.family_tree.len(), 2);
+        assert_eq!(app.grouped_rows, [GroupedRow::Family(0), GroupedRow::Model]);
+        // The family row shows its best fit: Perfect beats a higher-scoring
+        // model that doesn't fit.
+        let qwen = app.family_at_row(0).unwrap();
+        assert_eq!(qwen.family, "qwen2.5");
+        assert_eq!(qwen.indices.len(), 3);
+        assert_eq!(app.filtered_fits[0], 3);
+        // The selected Llama row stays selected.
+        assert_eq!(app.selected_row, 1);
+
+        app.selected_row = 0;
+        app.activate_selected_row();
+        assert!(!app.show_detail);
+        assert_eq!(
+            app.grouped_rows,
+            [
+                GroupedRow::Family(0),
+                GroupedRow::Variant,
+                GroupedRow::Variant,
+                GroupedRow::Variant,
+                GroupedRow::Model,
+            ]
+        );
+        app.selected_row = 1;
+        app.activate_selected_row();
+        assert!(app.show_detail, "variant rows open the detail view");
+        app.toggle_detail();
+
+        app.selected_row = 0;
+        app.activate_selected_row();
+        assert_eq!(app.filtered_fits.len(), 2);
+
+        app.toggle_grouped_view();
+        assert_eq!(app.filtered_fits.len(), 4);
+        assert!(app.grouped_rows.is_empty());
     }
 
     #[test]
@@ -5497,6 +6827,36 @@ mod tests {
         assert_eq!(app.selected_row, 0);
     }
 
+    #[test]
+    fn search_input_handles_multibyte_characters() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        for c in "🦙 日本".chars() {
+            app.search_input(c);
+        }
+        assert_eq!(app.search_query, "🦙 日本");
+        assert_eq!(app.cursor_position, app.search_query.len());
+
+        // Insert between the CJK characters, then before the emoji.
+        app.search_cursor_left();
+        app.search_input('x');
+        assert_eq!(app.search_query, "🦙 日x本");
+        while app.cursor_position > 0 {
+            app.search_cursor_left();
+        }
+        app.search_input('é');
+        assert_eq!(app.search_query, "é🦙 日x本");
+        app.search_delete();
+        assert_eq!(app.search_query, "é 日x本");
+
+        // A cursor left inside a character snaps back instead of panicking.
+        app.search_query = "🦙".to_string();
+        app.cursor_position = 2;
+        app.search_input('a');
+        assert_eq!(app.search_query, "a🦙");
+        assert_eq!(app.cursor_position, 1);
+    }
+
     #[test]
     fn search_query_with_no_matches_keeps_selection_at_zero() {
         let mut app = test_app();
@@ -5515,6 +6875,76 @@ mod tests {
         assert_eq!(app.selected_row, 0);
     }
 
+    #[test]
+    fn hashtag_search_filters_by_user_tags() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.model_notes = ModelNotes::default();
+        app.all_fits = vec![
+            test_fit("gemma-2b", FitLevel::Good, 90.0),
+            test_fit("llama-7b", FitLevel::Good, 80.0),
+        ];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.apply_filters();
+
+        app.selected_row = app
+            .filtered_fits
+            .iter()
+            .position(|&i| app.all_fits[i].model.name == "llama-7b")
+            .unwrap();
+        app.open_note_editor();
+        assert_eq!(app.input_mode, InputMode::NoteEdit);
+        for c in "good for my RAG #rag".chars() {
+            app.note_input_char(c);
+        }
+        app.save_note();
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.model_notes.get("llama-7b").unwrap().has_tag("#rag"));
+
+        app.search_query = "#RAG".to_string();
+        app.apply_filters();
+        assert_eq!(app.filtered_fits.len(), 1);
+        assert_eq!(app.all_fits[app.filtered_fits[0]].model.name, "llama-7b");
+
+        // Note text is searchable too, but a tag term needs a real tag.
+        app.search_query = "my rag".to_string();
+        app.apply_filters();
+        assert_eq!(app.filtered_fits.len(), 1);
+        app.search_query = "#slow".to_string();
+        app.apply_filters();
+        assert!(app.filtered_fits.is_empty());
+    }
+
+    #[test]
+    fn note_editor_accepts_non_ascii_notes_and_tags() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        app.model_notes = ModelNotes::default();
+        app.all_fits = vec![test_fit("llama-7b", FitLevel::Good, 80.0)];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.apply_filters();
+
+        app.open_note_editor();
+        for c in "très rapide 速い #日本語".chars() {
+            app.note_input_char(c);
+        }
+        assert_eq!(app.note_input, "très rapide 速い #日本語");
+        assert_eq!(app.note_cursor, app.note_input.len());
+
+        // Editing in the middle keeps the cursor on a char boundary.
+        app.note_cursor_left();
+        app.note_input_char('é');
+        assert_eq!(app.note_input, "très rapide 速い #日本é語");
+        app.note_backspace();
+        app.save_note();
+
+        let note = app.model_notes.get("llama-7b").unwrap();
+        assert_eq!(note.note, "très rapide 速い");
+        assert!(note.has_tag("#日本語"));
+    }
+
     /// Build an app with one installed model, primed so open_benchmarks
     /// skips the network fetch (bench_loading = true).
     fn app_with_installed_model(installed: bool) -> App {
@@ -5687,6 +7117,266 @@ mod tests {
         assert_eq!(app.advanced_range_labels(), vec!["7–30B", "mem≥50%"]);
     }
 
+    #[test]
+    fn target_tps_highlights_then_filters_slower_models() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        let fit = |name: &str, tps: f64| ModelFit {
+            estimated_tps: tps,
+            ..test_fit(name, FitLevel::Good, 50.0)
+        };
+        let mut measured = fit("measured-fast", 5.0);
+        measured.measured_tps = Some(llmfit_core::benchmarks::MeasuredTps {
+            tok_s: 40.0,
+            sample_count: 3,
+            hardware_label: "this machine".to_string(),
+            source: Default::default(),
+        });
+        app.all_fits = vec![
+            fit("slow", 8.0),
+            fit("exact", 15.0),
+            fit("fast", 60.0),
+            measured,
+        ];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.apply_filters();
+
+        // No target: nothing is highlighted.
+        assert_eq!(app.meets_target_tps(&app.all_fits[0]), None);
+
+        app.filter_target_tps_input = "15".to_string();
+        let meets: Vec<Option<bool>> = app
+            .all_fits
+            .iter()
+            .map(|f| app.meets_target_tps(f))
+            .collect();
+        // Measured tok/s wins over the estimate, like the tok/s column.
+        assert_eq!(meets, [Some(false), Some(true), Some(true), Some(true)]);
+
+        // Highlight mode keeps every row.
+        app.apply_filters();
+        assert_eq!(app.filtered_fits.len(), 4);
+        assert!(app.advanced_range_labels().is_empty());
+
+        app.filter_target_tps_only = true;
+        app.apply_filters();
+        let names: Vec<&str> = app
+            .filtered_fits
+            .iter()
+            .map(|&i| app.all_fits[i].model.name.as_str())
+            .collect();
+        assert_eq!(names, ["exact", "fast", "measured-fast"]);
+        assert_eq!(app.advanced_range_labels(), vec!["≥15 tok/s"]);
+        assert!(app.has_advanced_filters_active());
+    }
+
+    #[test]
+    fn groq_is_offered_only_for_models_too_big_to_run_locally() {
+        let mut app = test_app();
+        app.groq_models = vec!["llama-3.3-70b-versatile".to_string()];
+        let mut fit = test_fit(
+            "meta-llama/Llama-3.3-70B-Instruct",
+            FitLevel::TooTight,
+            10.0,
+        );
+
+        // No usable GROQ_API_KEY: nothing is offered.
+        app.groq_available = false;
+        assert_eq!(app.groq_model_for(&fit), None);
+
+        app.groq_available = true;
+        assert_eq!(app.groq_model_for(&fit), Some("llama-3.3-70b-versatile"));
+
+        fit.fit_level = FitLevel::Good;
+        assert_eq!(app.groq_model_for(&fit), None);
+    }
+
+    #[test]
+    fn installed_change_message_reports_new_and_removed_models() {
+        let set =
+            |names: &[&str]| -> HashSet<String> { names.iter().map(|n| n.to_string()).collect() };
+        let before = set(&["qwen2.5:7b"]);
+
+        assert_eq!(installed_change_message(&before, &before), None);
+        assert_eq!(
+            installed_change_message(&before, &set(&["qwen2.5:7b", "llama3.1:8b"])).as_deref(),
+            Some("Installed: llama3.1:8b")
+        );
+        assert_eq!(
+            installed_change_message(&before, &set(&["llama3.1:8b"])).as_deref(),
+            Some("Installed: llama3.1:8b; Removed: qwen2.5:7b")
+        );
+        assert_eq!(
+            installed_change_message(&set(&[]), &set(&["e", "d", "c", "b", "a"])).as_deref(),
+            Some("Installed: a, b, c (+2 more)")
+        );
+    }
+
+    #[test]
+    fn pinned_installed_section_leaves_all_fits_order_alone() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        let fit = |name: &str, score: f64, installed: bool| ModelFit {
+            installed,
+            ..test_fit(name, FitLevel::Good, score)
+        };
+        app.all_fits = vec![
+            fit("a", 90.0, false),
+            fit("b", 80.0, true),
+            fit("c", 70.0, false),
+            fit("d", 60.0, true),
+        ];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.apply_filters();
+        app.selected_row = 2; // "c"
+
+        app.toggle_installed_pinned();
+        let names = |app: &App| -> Vec<String> {
+            app.filtered_fits
+                .iter()
+                .map(|&i| app.all_fits[i].model.name.clone())
+                .collect()
+        };
+        assert_eq!(names(&app), ["b", "d", "a", "c"]);
+        assert_eq!(app.pinned_installed_rows(), 2);
+        assert_eq!(app.selected_fit().unwrap().model.name, "c");
+        let order: Vec<&str> = app.all_fits.iter().map(|f| f.model.name.as_str()).collect();
+        assert_eq!(order, ["a", "b", "c", "d"]);
+        assert!(!app.installed_first);
+
+        app.toggle_installed_pinned();
+        assert_eq!(names(&app), ["a", "b", "c", "d"]);
+        assert_eq!(app.pinned_installed_rows(), 0);
+        assert_eq!(app.selected_fit().unwrap().model.name, "c");
+    }
+
+    #[test]
+    fn params_toggle_switches_column_and_filters_to_active_params() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        let mut moe = test_fit("mixtral", FitLevel::Good, 80.0);
+        moe.model.parameter_count = "8x7B".to_string();
+        moe.model.parameters_raw = Some(46_700_000_000);
+        moe.model.is_moe = true;
+        moe.model.active_parameters = Some(12_900_000_000);
+        app.all_fits = vec![moe, test_fit("dense", FitLevel::Good, 70.0)];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        let names = |app: &App| -> Vec<String> {
+            app.filtered_fits
+                .iter()
+                .map(|&i| app.all_fits[i].model.name.clone())
+                .collect()
+        };
+
+        assert_eq!(app.params_cell_text(&app.all_fits[0].model), "8x7B");
+        app.filter_params_max_input = "15".to_string();
+        app.apply_filters();
+        assert_eq!(names(&app), ["dense"]);
+
+        app.toggle_params_active();
+        assert_eq!(app.params_cell_text(&app.all_fits[0].model), "12.9B");
+        assert_eq!(app.params_cell_text(&app.all_fits[1].model), "7B");
+        assert_eq!(names(&app), ["mixtral", "dense"]);
+
+        // Buckets follow the same number: only 7-14B selected.
+        app.filter_params_max_input.clear();
+        app.selected_params_buckets = vec![false, false, true, false, false, false];
+        app.apply_filters();
+        assert_eq!(names(&app), ["mixtral", "dense"]);
+        app.toggle_params_active();
+        assert_eq!(names(&app), ["dense"]);
+        assert_eq!(app.filter_config().params_active, Some(false));
+    }
+
+    #[test]
+    fn params_sort_follows_the_shown_param_count() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        let mut moe = test_fit("mixtral", FitLevel::Good, 80.0);
+        moe.model.parameters_raw = Some(46_700_000_000);
+        moe.model.is_moe = true;
+        moe.model.active_parameters = Some(12_900_000_000);
+        let mut dense = test_fit("dense-32b", FitLevel::Good, 70.0);
+        dense.model.parameters_raw = Some(32_000_000_000);
+        app.all_fits = vec![moe, dense];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.params_active = false;
+        app.sort_column = SortColumn::Params;
+        app.sort_ascending = false;
+        app.re_sort();
+        let names = |app: &App| -> Vec<String> {
+            app.filtered_fits
+                .iter()
+                .map(|&i| app.all_fits[i].model.name.clone())
+                .collect()
+        };
+        assert_eq!(names(&app), ["mixtral", "dense-32b"]);
+        app.selected_row = 0;
+
+        // 12.9B active sorts below the 32B dense model; the selection follows.
+        app.toggle_params_active();
+        assert_eq!(names(&app), ["dense-32b", "mixtral"]);
+        assert_eq!(app.selected_fit().unwrap().model.name, "mixtral");
+
+        app.toggle_params_active();
+        assert_eq!(names(&app), ["mixtral", "dense-32b"]);
+    }
+
+    #[test]
+    fn upgrades_filter_hides_models_at_or_below_best_installed() {
+        let mut app = test_app();
+        clear_persisted_filters(&mut app);
+        let fit = |name: &str, use_case: UseCase, score: f64, installed: bool| ModelFit {
+            use_case,
+            installed,
+            ..test_fit(name, FitLevel::Good, score)
+        };
+        app.all_fits = vec![
+            fit("installed-chat", UseCase::General, 70.0, true),
+            fit("installed-coder", UseCase::Coding, 85.0, true),
+            fit("better", UseCase::General, 90.0, false),
+            fit("between", UseCase::General, 80.0, false),
+            fit("tied", UseCase::General, 70.0, false),
+            fit("worse", UseCase::General, 60.0, false),
+        ];
+        app.providers = vec!["Test".to_string()];
+        app.selected_providers = vec![true];
+        app.use_cases = vec![UseCase::General, UseCase::Coding];
+        app.selected_use_cases = vec![true, true];
+        app.availability_filter = AvailabilityFilter::Upgrades;
+        let names = |app: &App| -> Vec<String> {
+            app.filtered_fits
+                .iter()
+                .map(|&i| app.all_fits[i].model.name.clone())
+                .collect()
+        };
+
+        // Across all use cases the bar is the 85-point coder.
+        app.apply_filters();
+        assert_eq!(app.best_installed_score(), Some(85.0));
+        assert_eq!(names(&app), ["better"]);
+
+        // General only: the bar drops to the installed general model, and a
+        // model tied with it isn't an upgrade.
+        app.selected_use_cases = vec![true, false];
+        app.apply_filters();
+        assert_eq!(app.best_installed_score(), Some(70.0));
+        assert_eq!(names(&app), ["better", "between"]);
+
+        assert_eq!(
+            AvailabilityFilter::from_label(AvailabilityFilter::Upgrades.label()),
+            AvailabilityFilter::Upgrades
+        );
+        assert_eq!(
+            AvailabilityFilter::Installed.next(),
+            AvailabilityFilter::Upgrades
+        );
+    }
+
     #[test]
     fn closing_benchmarks_resets_simulated_card_and_cached_rows() {
         let mut app = test_app();
@@ -5735,4 +7425,74 @@ mod tests {
         assert!(app.bench_search_query.is_empty());
         assert_eq!(app.bench_visible_indices(), vec![0, 1]);
     }
+
+    #[test]
+    fn pull_batch_drains_and_reports_completion_once() {
+        let mut app = test_app();
+        app.download_history = DownloadHistory::default();
+        let (tx, rx) = mpsc::channel();
+        for pct in [25.0, 75.0] {
+            tx.send(PullEvent::Progress {
+                status: format!("pulling {pct}%"),
+                percent: Some(pct),
+            })
+            .unwrap();
+        }
+        tx.send(PullEvent::Done).unwrap();
+        // A stray second Done must not trigger another refresh.
+        tx.send(PullEvent::Done).unwrap();
+        drop(tx);
+        app.pull_active = Some(PullHandle {
+            model_tag: "test".to_string(),
+            receiver: rx,
+            bytes_downloaded: Default::default(),
+        });
+
+        assert!(app.drain_pull_events());
+        assert!(app.pull_active.is_none());
+        assert!(app.pull_percent.is_none());
+        assert_eq!(app.download_history.records.len(), 1);
+        assert!(
+            app.pull_status
+                .as_deref()
+                .is_some_and(|s| s.starts_with("Download complete"))
+        );
+        // Nothing left to drain, so no further refresh is requested.
+        assert!(!app.drain_pull_events());
+    }
+
+    #[test]
+    fn record_pull_stores_model_provider_and_result() {
+        let mut app = test_app();
+        app.download_history = DownloadHistory::default();
+        let model_name = app.all_fits[0].model.name.clone();
+        app.pull_model_name = Some(model_name.clone());
+
+        app.record_pull("Ollama".to_string(), DownloadResult::Error("boom".into()));
+
+        let record = app.download_history.recent(1).next().unwrap();
+        assert_eq!(record.model_name, model_name);
+        assert_eq!(record.provider, "Ollama");
+        assert!(matches!(&record.result, DownloadResult::Error(e) if e == "boom"));
+        // The size comes from the catalog entry for the pulled model.
+        assert!(record.size_gb.is_some());
+    }
+
+    #[test]
+    fn detail_notes_scroll_stops_at_the_drawn_limit() {
+        let mut app = test_app();
+        app.toggle_detail();
+        app.set_detail_notes_max_scroll(3);
+        for _ in 0..10 {
+            app.scroll_detail_notes_down();
+        }
+        assert_eq!(app.detail_notes_scroll, 3);
+        // No dead presses after overshooting: `[` moves straight away.
+        app.scroll_detail_notes_up();
+        assert_eq!(app.detail_notes_scroll, 2);
+
+        // A taller pane pulls the offset back.
+        app.set_detail_notes_max_scroll(1);
+        assert_eq!(app.detail_notes_scroll, 1);
+    }
 }
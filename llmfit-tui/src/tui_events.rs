@@ -1,6 +1,9 @@
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use llmfit_core::fit::FitLevel;
+use std::path::Path;
 use std::time::Duration;
 
+use crate::filter_config::VIEW_FILE;
 use crate::tui_app::{App, InputMode};
 
 /// Poll for and handle events. Returns true if an event was processed.
@@ -17,34 +20,43 @@ pub fn handle_events(app: &mut App) -> std::io::Result<bool> {
         if key.kind != KeyEventKind::Press {
             return Ok(false);
         }
-        match app.input_mode {
-            InputMode::Normal => handle_normal_mode(app, key),
-            InputMode::Visual => handle_visual_mode(app, key),
-            InputMode::Select => handle_select_mode(app, key),
-            InputMode::Search => handle_search_mode(app, key),
-            InputMode::Plan => handle_plan_mode(app, key),
-            InputMode::ProviderPopup => handle_provider_popup_mode(app, key),
-            InputMode::UseCasePopup => handle_use_case_popup_mode(app, key),
-            InputMode::CapabilityPopup => handle_capability_popup_mode(app, key),
-            InputMode::DownloadProviderPopup => handle_download_provider_popup_mode(app, key),
-            InputMode::QuantPopup => handle_quant_popup_mode(app, key),
-            InputMode::RunModePopup => handle_run_mode_popup_mode(app, key),
-            InputMode::ParamsBucketPopup => handle_params_bucket_popup_mode(app, key),
-            InputMode::LicensePopup => handle_license_popup_mode(app, key),
-            InputMode::RuntimePopup => handle_runtime_popup_mode(app, key),
-            InputMode::HelpPopup => handle_help_popup_mode(app, key),
-            InputMode::Simulation => handle_simulation_mode(app, key),
-            InputMode::AdvancedConfig => handle_advanced_config_mode(app, key),
-            InputMode::DownloadManager => handle_download_manager_mode(app, key),
-            InputMode::FilterPopup => handle_filter_popup_mode(app, key),
-            InputMode::Benchmarks => handle_benchmarks_mode(app, key),
-            InputMode::BenchOffer => handle_bench_offer_mode(app, key),
-        }
+        dispatch_key(app, key);
         return Ok(true);
     }
     Ok(false)
 }
 
+/// Route a key press to the handler for the active input mode only, so keys
+/// typed into a popup never fall through to the main table.
+fn dispatch_key(app: &mut App, key: KeyEvent) {
+    match app.input_mode {
+        InputMode::Normal => handle_normal_mode(app, key),
+        InputMode::Visual => handle_visual_mode(app, key),
+        InputMode::Select => handle_select_mode(app, key),
+        InputMode::Search => handle_search_mode(app, key),
+        InputMode::Plan => handle_plan_mode(app, key),
+        InputMode::ProviderPopup => handle_provider_popup_mode(app, key),
+        InputMode::UseCasePopup => handle_use_case_popup_mode(app, key),
+        InputMode::CapabilityPopup => handle_capability_popup_mode(app, key),
+        InputMode::DownloadProviderPopup => handle_download_provider_popup_mode(app, key),
+        InputMode::QuantPopup => handle_quant_popup_mode(app, key),
+        InputMode::RunModePopup => handle_run_mode_popup_mode(app, key),
+        InputMode::ParamsBucketPopup => handle_params_bucket_popup_mode(app, key),
+        InputMode::LicensePopup => handle_license_popup_mode(app, key),
+        InputMode::RuntimePopup => handle_runtime_popup_mode(app, key),
+        InputMode::HelpPopup => handle_help_popup_mode(app, key),
+        InputMode::HistoryPopup => handle_history_popup_mode(app, key),
+        InputMode::NewModelsPopup => handle_new_models_popup_mode(app, key),
+        InputMode::Simulation => handle_simulation_mode(app, key),
+        InputMode::AdvancedConfig => handle_advanced_config_mode(app, key),
+        InputMode::DownloadManager => handle_download_manager_mode(app, key),
+        InputMode::FilterPopup => handle_filter_popup_mode(app, key),
+        InputMode::Benchmarks => handle_benchmarks_mode(app, key),
+        InputMode::BenchOffer => handle_bench_offer_mode(app, key),
+        InputMode::NoteEdit => handle_note_edit_mode(app, key),
+    }
+}
+
 fn handle_normal_mode(app: &mut App, key: KeyEvent) {
     // Handle bench quit-confirmation first (overrides all other handlers)
     if app.bench_confirm_quit {
@@ -130,6 +142,8 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
 
         KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => app.half_page_up(),
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => app.half_page_down(),
+        KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => app.page_up(),
+        KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => app.page_down(),
         KeyCode::Up | KeyCode::Char('k') => app.move_up(),
         KeyCode::Down | KeyCode::Char('j') => app.move_down(),
         KeyCode::PageUp => app.page_up(),
@@ -147,6 +161,12 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
         // Fit filter
         KeyCode::Char('f') => app.cycle_fit_filter(),
 
+        // Jump to the first model of a fit level
+        KeyCode::Char('1') => app.jump_to_fit_level(FitLevel::Perfect),
+        KeyCode::Char('2') => app.jump_to_fit_level(FitLevel::Good),
+        KeyCode::Char('3') => app.jump_to_fit_level(FitLevel::Marginal),
+        KeyCode::Char('4') => app.jump_to_fit_level(FitLevel::TooTight),
+
         // Filter popup (range filters, sort direction, fit)
         KeyCode::Char('F') => app.open_filter_popup(),
 
@@ -156,12 +176,28 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
         // TP compatibility filter
         KeyCode::Char('T') => app.cycle_tp_filter(),
 
+        // Batch size (concurrent requests) for KV-cache sizing
+        KeyCode::Char('B') => app.cycle_batch_size(),
+
         // Sort column
         KeyCode::Char('s') => app.cycle_sort_column(),
 
         // Theme
         KeyCode::Char('t') => app.cycle_theme(),
 
+        // Context unit (128k vs 131072)
+        KeyCode::Char('K') => app.cycle_context_unit(),
+
+        // Raw memory arithmetic in the detail view
+        KeyCode::Char('M') if app.show_detail => app.toggle_memory_math(),
+
+        // Scroll the detail view's notes pane
+        KeyCode::Char('[') if app.show_detail => app.scroll_detail_notes_up(),
+        KeyCode::Char(']') if app.show_detail => app.scroll_detail_notes_down(),
+
+        // Re-analyze the detail view at a forced quantization
+        KeyCode::Char('Q') if app.show_detail => app.cycle_detail_force_quant(),
+
         // Plan view
         KeyCode::Char('p') => app.open_plan_mode(),
 
@@ -173,6 +209,16 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
         KeyCode::Char('R') => app.open_runtime_popup(),
         KeyCode::Char('S') => app.open_simulation_popup(),
         KeyCode::Char('h') => app.open_help_popup(),
+        KeyCode::Char('H') => app.open_history_popup(),
+        KeyCode::Char('N') => app.open_new_models_popup(),
+
+        // Export / import the current view (filters, sort, search)
+        KeyCode::Char('E') => app.export_view(Path::new(VIEW_FILE)),
+        // Print an install script for the Perfect/Good models in view, then quit
+        KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.quit_with_install_script()
+        }
+        KeyCode::Char('O') => app.import_view(Path::new(VIEW_FILE)),
 
         // Installed-first sort toggle (any provider)
         KeyCode::Char('i')
@@ -181,11 +227,17 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
                 || app.llamacpp_available
                 || app.lmstudio_available
                 || app.vllm_available
-                || app.ramalama_available =>
+                || app.ramalama_available
+                || app.jan_available
+                || app.gpt4all_available =>
         {
             app.toggle_installed_first()
         }
 
+        // Pinned "Installed" section; leaves the sort order alone
+        KeyCode::Char('z') => app.toggle_installed_pinned(),
+        KeyCode::Char('X') => app.toggle_params_active(),
+
         // Download model via best provider (requires confirmation)
         KeyCode::Char('d')
             if app.ollama_available
@@ -206,7 +258,9 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
                 || app.llamacpp_available
                 || app.lmstudio_available
                 || app.vllm_available
-                || app.ramalama_available =>
+                || app.ramalama_available
+                || app.jan_available
+                || app.gpt4all_available =>
         {
             app.refresh_installed()
         }
@@ -224,14 +278,21 @@ fn handle_normal_mode(app: &mut App, key: KeyEvent) {
         // Advanced Config popup
         KeyCode::Char('A') => app.open_advanced_config_popup(),
 
-        // Detail view
-        KeyCode::Enter => app.toggle_detail(),
+        // Detail view (expands/collapses family rows in the grouped view)
+        KeyCode::Enter => app.activate_selected_row(),
+
+        // Group the list by model family
+        KeyCode::Char('G') => app.toggle_grouped_view(),
 
         // Compare view
         KeyCode::Char('m') => app.mark_selected_for_compare(),
         KeyCode::Char('c') => app.toggle_compare_view(),
         KeyCode::Char('x') => app.clear_compare_mark(),
         KeyCode::Char('y') => app.copy_selected_model_name(),
+        KeyCode::Char('Y') => app.copy_selected_run_command(),
+
+        // Personal notes/tags for the selected model
+        KeyCode::Char('n') => app.open_note_editor(),
 
         _ => {}
     }
@@ -245,6 +306,8 @@ fn handle_visual_mode(app: &mut App, key: KeyEvent) {
         // Navigation (extends selection)
         KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => app.half_page_up(),
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => app.half_page_down(),
+        KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => app.page_up(),
+        KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => app.page_down(),
         KeyCode::Up | KeyCode::Char('k') => app.move_up(),
         KeyCode::Down | KeyCode::Char('j') => app.move_down(),
         KeyCode::PageUp => app.page_up(),
@@ -274,8 +337,15 @@ fn handle_select_mode(app: &mut App, key: KeyEvent) {
         KeyCode::Enter | KeyCode::Char(' ') => app.activate_select_column_filter(),
 
         // Row navigation (still works in select mode)
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => app.half_page_up(),
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => app.half_page_down(),
+        KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => app.page_up(),
+        KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => app.page_down(),
         KeyCode::Up | KeyCode::Char('k') => app.move_up(),
         KeyCode::Down | KeyCode::Char('j') => app.move_down(),
+        KeyCode::PageUp => app.page_up(),
+        KeyCode::PageDown => app.page_down(),
+        KeyCode::Home | KeyCode::Char('g') => app.cycle_top_bottom(),
 
         _ => {}
     }
@@ -305,6 +375,28 @@ fn handle_search_mode(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_note_edit_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.cancel_note(),
+        KeyCode::Enter => app.save_note(),
+
+        KeyCode::Backspace => app.note_backspace(),
+        KeyCode::Delete => app.note_delete(),
+
+        KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.note_input.clear();
+            app.note_cursor = 0;
+        }
+
+        KeyCode::Left => app.note_cursor_left(),
+        KeyCode::Right => app.note_cursor_right(),
+
+        KeyCode::Char(c) if allows_search_text_input(key.modifiers) => app.note_input_char(c),
+
+        _ => {}
+    }
+}
+
 fn handle_provider_popup_mode(app: &mut App, key: KeyEvent) {
     let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
     let shift = key.modifiers.contains(KeyModifiers::SHIFT);
@@ -331,6 +423,9 @@ fn handle_provider_popup_mode(app: &mut App, key: KeyEvent) {
         KeyCode::Char('u') if ctrl => app.provider_search_clear(),
         KeyCode::Char('a') if ctrl => app.provider_popup_select_all(),
         KeyCode::Char('n') if ctrl => app.provider_popup_clear_all(),
+        // Terminals send Ctrl-I as Tab.
+        KeyCode::Char('i') if ctrl => app.provider_popup_invert_selection(),
+        KeyCode::Tab => app.provider_popup_invert_selection(),
 
         // Plain printable ASCII filters the provider list. Reject modified
         // character events such as macOS Option/Command-arrow artifacts.
@@ -512,6 +607,27 @@ fn handle_help_popup_mode(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_history_popup_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('H') | KeyCode::Char('q') => app.close_history_popup(),
+        KeyCode::Char('D') => {
+            app.close_history_popup();
+            app.toggle_downloads();
+        }
+        _ => {}
+    }
+}
+
+fn handle_new_models_popup_mode(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('N') | KeyCode::Char('q') => app.close_new_models_popup(),
+        KeyCode::Up | KeyCode::Char('k') => app.new_models_up(),
+        KeyCode::Down | KeyCode::Char('j') => app.new_models_down(),
+        KeyCode::Enter => app.search_new_model(),
+        _ => {}
+    }
+}
+
 fn handle_simulation_mode(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => app.close_simulation_popup(),
@@ -684,7 +800,8 @@ fn handle_filter_popup_mode(app: &mut App, key: KeyEvent) {
         KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             if matches!(
                 app.filter_field,
-                crate::tui_app::FilterPopupField::SortDirection
+                crate::tui_app::FilterPopupField::TargetTpsOnly
+                    | crate::tui_app::FilterPopupField::SortDirection
                     | crate::tui_app::FilterPopupField::FitFilter
                     | crate::tui_app::FilterPopupField::Availability
             ) {
@@ -693,6 +810,13 @@ fn handle_filter_popup_mode(app: &mut App, key: KeyEvent) {
             app.filter_clear_active_input();
         }
 
+        // Hide models below the target speed
+        KeyCode::Char(' ')
+            if app.filter_field == crate::tui_app::FilterPopupField::TargetTpsOnly =>
+        {
+            app.filter_toggle_target_tps_only()
+        }
+
         // Sort direction toggle
         KeyCode::Char(' ')
             if app.filter_field == crate::tui_app::FilterPopupField::SortDirection =>
@@ -705,7 +829,7 @@ fn handle_filter_popup_mode(app: &mut App, key: KeyEvent) {
             app.cycle_filter_fit()
         }
 
-        // Availability filter cycling (All / GGUF Avail / Installed)
+        // Availability filter cycling (All / GGUF Avail / Installed / Upgrades)
         KeyCode::Char(' ')
             if app.filter_field == crate::tui_app::FilterPopupField::Availability =>
         {
@@ -807,6 +931,8 @@ mod tests {
                 gpus: Vec::new(),
                 cluster_mode: false,
                 cluster_node_count: 0,
+                gpu_cores: None,
+                ram_config: None,
             },
             None,
         );
@@ -843,6 +969,56 @@ mod tests {
         assert_eq!(app.input_mode, InputMode::Normal);
     }
 
+    #[test]
+    fn vim_paging_keys_move_by_half_and_full_pages() {
+        let mut app = plan_mode_app();
+        app.input_mode = InputMode::Normal;
+        app.show_plan = false;
+        // Start from the top whatever selection was restored at launch.
+        app.selected_row = 0;
+        assert!(app.filtered_fits.len() > 20);
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        handle_normal_mode(&mut app, ctrl('f'));
+        assert_eq!(app.selected_row, 10);
+        handle_normal_mode(&mut app, ctrl('u'));
+        assert_eq!(app.selected_row, 5);
+        handle_normal_mode(&mut app, ctrl('d'));
+        assert_eq!(app.selected_row, 10);
+        handle_normal_mode(&mut app, ctrl('b'));
+        assert_eq!(app.selected_row, 0);
+        // Without Ctrl, `f` still cycles the fit filter.
+        let fit_filter = app.fit_filter;
+        handle_normal_mode(&mut app, plain('f'));
+        assert_ne!(app.fit_filter, fit_filter);
+
+        handle_select_mode(&mut app, ctrl('f'));
+        assert_eq!(app.selected_row, 10.min(app.filtered_fits.len() - 1));
+    }
+
+    #[test]
+    fn filter_popup_keeps_navigation_keys_away_from_the_table() {
+        let mut app = plan_mode_app();
+        app.input_mode = InputMode::Normal;
+        app.show_plan = false;
+        app.selected_row = 3;
+
+        app.open_filter_popup();
+        assert_eq!(app.input_mode, InputMode::FilterPopup);
+        for key in [
+            plain('j'),
+            plain('k'),
+            plain('g'),
+            KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::PageDown, KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+        ] {
+            dispatch_key(&mut app, key);
+            assert_eq!(app.selected_row, 3, "{key:?} moved the table selection");
+            assert_eq!(app.input_mode, InputMode::FilterPopup);
+        }
+    }
+
     #[test]
     fn search_text_accepts_unmodified_and_shift_modified_input() {
         assert!(allows_search_text_input(KeyModifiers::NONE));
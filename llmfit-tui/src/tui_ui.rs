@@ -9,15 +9,18 @@ use ratatui::{
     },
 };
 
-use crate::download_history::DownloadResult;
+use crate::download_history::{DownloadResult, format_epoch};
 use crate::theme::ThemeColors;
 use crate::tui_app::{
     AdvConfigField, App, AvailabilityFilter, BenchOfferState, BenchViewMode, DL_DOCKER,
     DL_LLAMACPP, DL_LMSTUDIO, DL_OLLAMA, DL_VLLM, DownloadCapability, DownloadManagerFocus,
-    DownloadProvider, FitFilter, InputMode, PlanField, SimulationField,
+    DownloadProvider, FitFilter, GroupedRow, InputMode, PlanField, SimulationField,
 };
-use llmfit_core::fit::{FitLevel, ModelFit, SortColumn};
-use llmfit_core::hardware::is_running_in_wsl;
+use llmfit_core::fit::{
+    ContextUnit, FitLevel, MemoryBreakdown, ModelFit, RunMode, SortColumn,
+    estimate_quantization_for_target_memory, format_context,
+};
+use llmfit_core::hardware::wsl_info;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -58,7 +61,9 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
     } else if app.show_compare {
         draw_compare(frame, app, outer[2], &tc);
     } else if app.show_detail {
-        draw_detail(frame, app, outer[2], &tc);
+        app.refresh_detail_forced_fit();
+        let notes_max_scroll = draw_detail(frame, app, outer[2], &tc);
+        app.set_detail_notes_max_scroll(notes_max_scroll);
     } else {
         draw_table(frame, app, outer[2], &tc);
     }
@@ -86,6 +91,10 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         draw_runtime_popup(frame, app, &tc);
     } else if app.input_mode == InputMode::HelpPopup {
         draw_help_popup(frame, app, &tc);
+    } else if app.input_mode == InputMode::HistoryPopup {
+        draw_history_popup(frame, app, &tc);
+    } else if app.input_mode == InputMode::NewModelsPopup {
+        draw_new_models_popup(frame, app, &tc);
     } else if app.input_mode == InputMode::Simulation {
         draw_simulation_popup(frame, app, &tc);
     } else if app.input_mode == InputMode::AdvancedConfig {
@@ -107,7 +116,10 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         format!("GPU: none ({})", app.specs.backend.label())
     } else {
         let primary = &app.specs.gpus[0];
-        let backend = primary.backend.label();
+        let backend = match &primary.driver_version {
+            Some(driver) => format!("{}, driver {}", primary.backend.label(), driver),
+            None => primary.backend.label().to_string(),
+        };
         let primary_str = if primary.unified_memory {
             let shared = primary.vram_gb.unwrap_or(0.0);
             match app.specs.gpu_available_gb {
@@ -143,7 +155,13 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     };
 
     let ollama_info = if app.ollama_available {
-        format!("Ollama: ✓ ({} installed)", app.installed.ollama_count)
+        match app.ollama_orphans.len() {
+            0 => format!("Ollama: ✓ ({} installed)", app.installed.ollama_count),
+            orphans => format!(
+                "Ollama: ✓ ({} installed, {} not in db)",
+                app.installed.ollama_count, orphans
+            ),
+        }
     } else if app.ollama_binary_available {
         "Ollama: installed (not running)".to_string()
     } else {
@@ -266,7 +284,7 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                 "{:.1} GB avail / {:.1} GB total{}",
                 app.specs.available_ram_gb,
                 app.specs.total_ram_gb,
-                if is_running_in_wsl() { " (WSL)" } else { "" }
+                wsl_ram_suffix()
             ),
             Style::default().fg(tc.accent),
         ),
@@ -298,6 +316,43 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         Span::styled("  │  ", Style::default().fg(tc.muted)),
         Span::styled(ramalama_info, Style::default().fg(ramalama_color)),
     ];
+    // Jan and GPT4All are desktop apps; only mention them when present.
+    for (available, name, count) in [
+        (app.jan_available, "Jan", app.installed.jan_count),
+        (
+            app.gpt4all_available,
+            "GPT4All",
+            app.installed.gpt4all_count,
+        ),
+    ] {
+        if available {
+            provider_spans.push(Span::styled("  │  ", Style::default().fg(tc.muted)));
+            provider_spans.push(Span::styled(
+                format!("{name}: ✓ ({count} models)"),
+                Style::default().fg(tc.good),
+            ));
+        }
+    }
+    // Groq is a cloud API; it only shows up when GROQ_API_KEY works.
+    if app.groq_available {
+        provider_spans.push(Span::styled("  │  ", Style::default().fg(tc.muted)));
+        provider_spans.push(Span::styled(
+            format!("Groq: ☁ ({} models)", app.groq_models.len()),
+            Style::default().fg(tc.info),
+        ));
+    }
+
+    if !app.new_models.is_empty() {
+        provider_spans.push(Span::styled("  │  ", Style::default().fg(tc.muted)));
+        provider_spans.push(Span::styled(
+            format!(
+                "✦ {} new model{} since last run (N)",
+                app.new_models.len(),
+                if app.new_models.len() == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(tc.good),
+        ));
+    }
 
     if app.backend_hidden_count > 0 {
         provider_spans.push(Span::styled("  │  ", Style::default().fg(tc.muted)));
@@ -329,6 +384,17 @@ fn draw_system_bar(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     frame.render_widget(paragraph, area);
 }
 
+/// RAM label suffix under WSL2, naming the `.wslconfig` cap when one is set.
+fn wsl_ram_suffix() -> String {
+    match wsl_info() {
+        Some(wsl) => match wsl.memory_limit_gb {
+            Some(limit) => format!(" (WSL, {limit:.0} GB cap)"),
+            None => " (WSL)".to_string(),
+        },
+        None => String::new(),
+    }
+}
+
 fn visible_search_query(query: &str, cursor_position: usize, width: usize) -> (String, u16) {
     if width == 0 {
         return (String::new(), 0);
@@ -428,6 +494,7 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
             Constraint::Length(20), // fit filter
             Constraint::Length(20), // availability filter
             Constraint::Length(14), // TP filter
+            Constraint::Length(11), // batch size
             Constraint::Length(16), // theme
         ])
         .split(area);
@@ -454,17 +521,23 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
             | InputMode::LicensePopup
             | InputMode::RuntimePopup
             | InputMode::HelpPopup
+            | InputMode::HistoryPopup
+            | InputMode::NewModelsPopup
             | InputMode::Simulation
             | InputMode::AdvancedConfig
             | InputMode::DownloadManager
             | InputMode::FilterPopup
             | InputMode::Benchmarks
             | InputMode::BenchOffer => Style::default().fg(tc.muted),
+            InputMode::NoteEdit => Style::default().fg(tc.accent),
         }
     };
 
+    let note_edit = app.input_mode == InputMode::NoteEdit;
     let (query, query_cursor) = if bench_search {
         (&app.bench_search_query, app.bench_search_query.len())
+    } else if note_edit {
+        (&app.note_input, app.note_cursor)
     } else {
         (&app.search_query, app.cursor_position)
     };
@@ -484,13 +557,18 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
     let search_block = Block::default()
         .borders(Borders::ALL)
         .border_style(search_style)
-        .title(" Search ")
+        .title(if note_edit {
+            " Note (#tags) "
+        } else {
+            " Search "
+        })
         .title_style(search_style);
 
     let search = Paragraph::new(search_text).block(search_block);
     frame.render_widget(search, chunks[0]);
 
-    if app.input_mode == InputMode::Search || (bench_search && app.bench_search_active) {
+    if app.input_mode == InputMode::Search || note_edit || (bench_search && app.bench_search_active)
+    {
         frame.set_cursor_position((chunks[0].x + cursor_offset + 1, chunks[0].y + 1));
     }
 
@@ -606,7 +684,10 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
     let fit_color = if has_range_filters || app.fit_filter != FitFilter::All {
         match app.fit_filter {
             FitFilter::All => tc.accent,
-            FitFilter::Runnable | FitFilter::Perfect | FitFilter::TurboQuantFit => tc.good,
+            FitFilter::Runnable
+            | FitFilter::Perfect
+            | FitFilter::TurboQuantFit
+            | FitFilter::Installed => tc.good,
             FitFilter::Good => tc.warning,
             FitFilter::Marginal => tc.fit_marginal,
             FitFilter::TooTight => tc.error,
@@ -642,6 +723,7 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
         AvailabilityFilter::All => Style::default().fg(tc.fg),
         AvailabilityFilter::HasGguf => Style::default().fg(tc.info),
         AvailabilityFilter::Installed => Style::default().fg(tc.good),
+        AvailabilityFilter::Upgrades => Style::default().fg(tc.accent),
     };
 
     let avail_block = Block::default()
@@ -672,6 +754,24 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
         Paragraph::new(Line::from(Span::styled(app.tp_filter.label(), tp_style))).block(tp_block);
     frame.render_widget(tp_text, chunks[7]);
 
+    // Batch size: concurrent requests the KV cache is sized for
+    let batch_style = if app.batch_size > 1 {
+        Style::default().fg(tc.accent)
+    } else {
+        Style::default().fg(tc.fg)
+    };
+    let batch_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.border))
+        .title(" Batch [B] ")
+        .title_style(Style::default().fg(tc.muted));
+    let batch_text = Paragraph::new(Line::from(Span::styled(
+        format!("×{}", app.batch_size),
+        batch_style,
+    )))
+    .block(batch_block);
+    frame.render_widget(batch_text, chunks[8]);
+
     // Theme indicator
     let theme_block = Block::default()
         .borders(Borders::ALL)
@@ -684,7 +784,7 @@ fn draw_search_and_filters(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeC
         Style::default().fg(tc.info),
     )))
     .block(theme_block);
-    frame.render_widget(theme_text, chunks[8]);
+    frame.render_widget(theme_text, chunks[9]);
 }
 
 fn fit_color(level: FitLevel, tc: &ThemeColors) -> Color {
@@ -705,6 +805,23 @@ fn fit_indicator(level: FitLevel) -> &'static str {
     }
 }
 
+/// One-letter tag for the provider an installed model runs from, shown after
+/// the ✓ in the "Inst" column. Letters match the download-capability flags.
+fn installed_provider_letter(provider: Option<&str>) -> &'static str {
+    match provider {
+        Some("Ollama") => "O",
+        Some("MLX") => "M",
+        Some("llama.cpp") => "L",
+        Some("Docker") => "D",
+        Some("LM Studio") => "S",
+        Some("vLLM") => "V",
+        Some("RamaLama") => "R",
+        Some("Jan") => "J",
+        Some("GPT4All") => "G",
+        _ => "",
+    }
+}
+
 /// Build a compact animated download indicator for the "Inst" column.
 fn pull_indicator(percent: Option<f64>, tick: u64) -> String {
     const SPINNER: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
@@ -782,9 +899,27 @@ fn model_col_text_width(area: Rect, widths: [Constraint; 14]) -> usize {
 
 fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
     let sort_col = app.sort_column;
+    let params_header = if app.params_active {
+        "Active"
+    } else {
+        "Params"
+    };
     let header_names = [
-        "", "Inst", "Model", "Provider", "Params", "Score", "tok/s*", "Quant", "Disk", "Mode",
-        "Mem %", "Ctx", "Date", "Fit", "Use Case",
+        "",
+        "Inst",
+        "Model",
+        "Provider",
+        params_header,
+        "Score",
+        "tok/s*",
+        "Quant",
+        "Disk",
+        "Mode",
+        "Mem %",
+        "Ctx",
+        "Date",
+        "Fit",
+        "Use Case",
     ];
     let sort_col_idx: Option<usize> = match sort_col {
         SortColumn::Score => Some(5),
@@ -819,26 +954,33 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
     let header = Row::new(header_cells).height(1);
 
     let visual_range = app.visual_range();
+    // Raw token counts ("262144→14336") need more room than "256k→14k".
+    let ctx_width = match app.context_unit {
+        ContextUnit::Compact => 10,
+        ContextUnit::Tokens => 14,
+        ContextUnit::Grouped => 18,
+    };
     let widths = [
-        Constraint::Length(2),  // indicator
-        Constraint::Length(5),  // installed / pull %
-        Constraint::Min(20),    // model name
-        Constraint::Length(12), // provider
-        Constraint::Length(8),  // params
-        Constraint::Length(6),  // score
-        Constraint::Length(6),  // tok/s
-        Constraint::Length(10), // quant (AWQ-4bit, GPTQ-Int4, GPTQ-Int8)
-        Constraint::Length(7),  // mode
-        Constraint::Length(6),  // mem %
-        Constraint::Length(10), // ctx ("262k→14k" when memory-constrained)
-        Constraint::Length(8),  // date (YYYY-MM)
-        Constraint::Length(10), // fit
-        Constraint::Min(10),    // use case
+        Constraint::Length(2),         // indicator
+        Constraint::Length(5),         // installed / pull %
+        Constraint::Min(20),           // model name
+        Constraint::Length(12),        // provider
+        Constraint::Length(8),         // params
+        Constraint::Length(6),         // score
+        Constraint::Length(6),         // tok/s
+        Constraint::Length(10),        // quant (AWQ-4bit, GPTQ-Int4, GPTQ-Int8)
+        Constraint::Length(10),        // mode (runtime icon + run mode)
+        Constraint::Length(6),         // mem %
+        Constraint::Length(ctx_width), // ctx ("256k→14k" when memory-constrained)
+        Constraint::Length(8),         // date (YYYY-MM)
+        Constraint::Length(10),        // fit
+        Constraint::Min(10),           // use case
     ];
 
     let model_col_chars = model_col_text_width(area, widths);
+    let today = llmfit_core::dateutil::days_since_epoch_today();
 
-    let rows: Vec<Row> = app
+    let mut rows: Vec<Row> = app
         .filtered_fits
         .iter()
         .enumerate()
@@ -876,15 +1018,27 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
             if tps_measured {
                 tps_text.push('✓');
             }
+            // Target speed from the Filter popup: green when reached.
+            let tps_style = match app.meets_target_tps(fit) {
+                Some(true) => Style::default().fg(tc.good).add_modifier(Modifier::BOLD),
+                Some(false) => Style::default().fg(tc.muted),
+                None => Style::default().fg(tc.fg),
+            };
 
             let is_pulling = app.pull_active.is_some()
                 && app.pull_model_name.as_deref() == Some(&fit.model.name);
             let capability = app.download_capability_for(&fit.model.name);
+            let on_groq = app.groq_model_for(fit).is_some();
 
             let installed_icon = if fit.installed {
-                " ✓".to_string()
+                format!(
+                    " ✓{}",
+                    installed_provider_letter(fit.installed_provider.as_deref())
+                )
             } else if is_pulling {
                 pull_indicator(app.pull_percent, app.tick_count)
+            } else if on_groq {
+                " ☁".to_string()
             } else {
                 match capability {
                     DownloadCapability::Unknown => " …".to_string(),
@@ -917,6 +1071,8 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
                 tc.good
             } else if is_pulling {
                 tc.warning
+            } else if on_groq {
+                tc.info
             } else {
                 match capability {
                     DownloadCapability::Unknown => tc.muted,
@@ -943,30 +1099,67 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
                 fit_indicator(fit.fit_level).to_string()
             };
 
+            // Recent releases get a NEW badge ahead of the name.
+            let is_new = fit.model.release_date.as_deref().is_some_and(|d| {
+                llmfit_core::fit::released_within_days(
+                    d,
+                    llmfit_core::fit::NEW_RELEASE_WINDOW_DAYS,
+                    today,
+                )
+            });
+            let name_chars = if is_new {
+                model_col_chars.saturating_sub(4)
+            } else {
+                model_col_chars
+            };
+            // Grouped view: family rows summarize their best model.
+            let name = match app.grouped_rows.get(row_idx) {
+                Some(GroupedRow::Family(n)) => {
+                    let group = &app.family_tree[*n];
+                    format!(
+                        "{} {} ({}) · {}",
+                        if group.expanded { "▾" } else { "▸" },
+                        group.family,
+                        group.indices.len(),
+                        fit.model.name
+                    )
+                }
+                Some(GroupedRow::Variant) => format!("  └ {}", fit.model.name),
+                _ => fit.model.name.clone(),
+            };
             let model_text = if row_idx == app.selected_row {
-                marquee_text(&fit.model.name, model_col_chars, app.tick_count)
+                marquee_text(&name, name_chars, app.tick_count)
             } else {
-                truncate_with_ellipsis(&fit.model.name, model_col_chars)
+                truncate_with_ellipsis(&name, name_chars)
             };
+            let mut model_spans = Vec::new();
+            if is_new {
+                model_spans.push(Span::styled(
+                    "NEW ",
+                    Style::default().fg(tc.good).add_modifier(Modifier::BOLD),
+                ));
+            }
+            model_spans.push(Span::styled(model_text, Style::default().fg(tc.fg)));
 
             Row::new(vec![
                 Cell::from(marker).style(Style::default().fg(color)),
                 Cell::from(installed_icon).style(Style::default().fg(installed_color)),
-                Cell::from(model_text).style(Style::default().fg(tc.fg)),
+                Cell::from(Line::from(model_spans)),
                 Cell::from(fit.model.provider.clone()).style(Style::default().fg(tc.muted)),
-                Cell::from(fit.model.parameter_count.clone()).style(Style::default().fg(tc.fg)),
+                Cell::from(app.params_cell_text(&fit.model)).style(Style::default().fg(tc.fg)),
                 Cell::from(format!("{:.0}", fit.score)).style(Style::default().fg(score_color)),
-                Cell::from(tps_text).style(Style::default().fg(tc.fg)),
+                Cell::from(tps_text).style(tps_style),
                 Cell::from(fit.best_quant.clone()).style(Style::default().fg(tc.muted)),
                 Cell::from(format!(
                     "{:.1}G",
                     fit.model.estimate_disk_gb(&fit.best_quant)
                 ))
                 .style(Style::default().fg(tc.muted)),
-                Cell::from(fit.run_mode_text().to_string()).style(Style::default().fg(mode_color)),
+                Cell::from(format!("{} {}", fit.runtime.icon(), fit.run_mode_text()))
+                    .style(Style::default().fg(mode_color)),
                 Cell::from(format!("{:.0}%", fit.utilization_pct))
                     .style(Style::default().fg(color)),
-                Cell::from(fit.context_display()).style(Style::default().fg(
+                Cell::from(fit.context_display_in(app.context_unit)).style(Style::default().fg(
                     if fit.context_severely_limited() {
                         tc.warning
                     } else {
@@ -983,35 +1176,80 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
                 )
                 .style(Style::default().fg(tc.muted)),
                 Cell::from(fit.fit_text().to_string()).style(Style::default().fg(color)),
-                Cell::from(fit.use_case.label().to_string()).style(Style::default().fg(tc.muted)),
+                Cell::from(format!("{} {}", fit.use_case.emoji(), fit.use_case.label()))
+                    .style(Style::default().fg(tc.muted)),
             ])
             .style(row_style)
         })
         .collect();
 
+    // Pinned installed section: a separator row heads the installed models,
+    // shifting the highlighted table row down by one.
+    let separator_rows = usize::from(app.pinned_installed_rows() > 0);
+    if separator_rows > 0 {
+        rows.insert(
+            0,
+            Row::new(vec![
+                Cell::from(""),
+                Cell::from(""),
+                Cell::from("── Installed ──"),
+            ])
+            .style(Style::default().fg(tc.accent)),
+        );
+    }
+
+    // Groq's cloud models follow the local ones in their own section; being
+    // after every fit row, they never shift the selection.
+    if app.groq_available && !app.groq_models.is_empty() {
+        rows.push(
+            Row::new(vec![
+                Cell::from(""),
+                Cell::from(""),
+                Cell::from("── Groq (cloud) ──"),
+            ])
+            .style(Style::default().fg(tc.accent)),
+        );
+        rows.extend(app.groq_models.iter().map(|id| {
+            Row::new(vec![
+                Cell::from(""),
+                Cell::from(" ☁").style(Style::default().fg(tc.info)),
+                Cell::from(id.clone()).style(Style::default().fg(tc.fg)),
+                Cell::from("Groq").style(Style::default().fg(tc.muted)),
+            ])
+        }));
+    }
+
     let widths = [
-        Constraint::Length(2),  // indicator
-        Constraint::Length(5),  // installed / pull %
-        Constraint::Min(20),    // model name
-        Constraint::Length(12), // provider
-        Constraint::Length(8),  // params
-        Constraint::Length(8),  // score
-        Constraint::Length(8),  // tok/s
-        Constraint::Length(10), // quant (AWQ-4bit, GPTQ-Int4, GPTQ-Int8)
-        Constraint::Length(6),  // disk
-        Constraint::Length(7),  // mode
-        Constraint::Length(7),  // mem %
-        Constraint::Length(10), // ctx ("262k→14k" when memory-constrained)
-        Constraint::Length(8),  // date (YYYY-MM)
-        Constraint::Length(10), // fit
-        Constraint::Min(10),    // use case
+        Constraint::Length(2),         // indicator
+        Constraint::Length(5),         // installed / pull %
+        Constraint::Min(20),           // model name
+        Constraint::Length(12),        // provider
+        Constraint::Length(8),         // params
+        Constraint::Length(8),         // score
+        Constraint::Length(8),         // tok/s
+        Constraint::Length(10),        // quant (AWQ-4bit, GPTQ-Int4, GPTQ-Int8)
+        Constraint::Length(6),         // disk
+        Constraint::Length(10),        // mode (runtime icon + run mode)
+        Constraint::Length(7),         // mem %
+        Constraint::Length(ctx_width), // ctx ("256k→14k" when memory-constrained)
+        Constraint::Length(8),         // date (YYYY-MM)
+        Constraint::Length(10),        // fit
+        Constraint::Min(10),           // use case
     ];
 
-    let count_text = format!(
-        " Models ({}/{}) ",
-        app.filtered_fits.len(),
-        app.all_fits.len()
-    );
+    let count_text = if app.grouped_view {
+        format!(
+            " Model families ({} families, {} models) ",
+            app.family_tree.len(),
+            app.all_fits.len()
+        )
+    } else {
+        format!(
+            " Models ({}/{}) ",
+            app.filtered_fits.len(),
+            app.all_fits.len()
+        )
+    };
 
     let table = Table::new(rows, widths)
         .header(header)
@@ -1032,7 +1270,8 @@ fn draw_table(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
     if app.filtered_fits.is_empty() {
         app.table_state.select(None);
     } else {
-        app.table_state.select(Some(app.selected_row));
+        app.table_state
+            .select(Some(app.selected_row + separator_rows));
     }
 
     frame.render_stateful_widget(table, area, &mut app.table_state);
@@ -1114,7 +1353,11 @@ fn draw_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
 
     let sections = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(3), Constraint::Min(10)])
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(10),
+            Constraint::Length(3),
+        ])
         .split(area);
     let cols = Layout::default()
         .direction(Direction::Horizontal)
@@ -1256,6 +1499,23 @@ fn draw_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         right,
         &right_metrics,
     );
+
+    // Plain-English reason for the ranking order
+    let diff =
+        llmfit_core::fit::explain_ranking_with(left, right, &app.calc_config.scoring_weights);
+    let why = Paragraph::new(Line::from(Span::styled(
+        format!(" {}", diff.summary()),
+        Style::default().fg(tc.fg),
+    )))
+    .wrap(Wrap { trim: true })
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(tc.border))
+            .title(" Why this ranking ")
+            .title_style(Style::default().fg(tc.muted)),
+    );
+    frame.render_widget(why, sections[2]);
 }
 
 struct CompareMetrics {
@@ -1615,7 +1875,7 @@ fn draw_multi_compare(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors
         label: "Context",
         values: visible_models
             .iter()
-            .map(|m| format!("{}k", m.model.context_length / 1000))
+            .map(|m| app.context_unit.format(m.model.context_length))
             .collect(),
         styles: visible_models
             .iter()
@@ -1741,7 +2001,27 @@ fn truncate_str(s: &str, max_len: usize) -> String {
     }
 }
 
-fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
+/// Trained vs. rope-scaled windows for the detail Context row; empty when the
+/// advertised window is all native and cannot be stretched further.
+fn rope_context_text(model: &llmfit_core::models::LlmModel) -> String {
+    let native = model.native_context();
+    let extended = model.extended_context();
+    match (
+        native < model.context_length,
+        extended > model.context_length,
+    ) {
+        (false, false) => String::new(),
+        (true, false) => format!(" (trained on {native}, rest via rope scaling)"),
+        (false, true) => format!(" (up to {extended} with rope scaling)"),
+        (true, true) => {
+            format!(" (trained on {native}, up to {extended} with rope scaling)")
+        }
+    }
+}
+
+/// Returns how far the notes pane can scroll, 0 when it all fits or there
+/// is no notes pane.
+fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) -> usize {
     let fit = match app.selected_fit() {
         Some(f) => f,
         None => {
@@ -1749,12 +2029,38 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                 .borders(Borders::ALL)
                 .title(" No model selected ");
             frame.render_widget(block, area);
-            return;
+            return 0;
         }
     };
+    let fit = app
+        .detail_forced_fit
+        .as_ref()
+        .map_or(fit, |(_, forced)| forced);
 
     let color = fit_color(fit.fit_level, tc);
 
+    // GGUF quants only apply to llama.cpp-style runtimes; MLX and vLLM
+    // models keep the quant chosen by the fit analysis.
+    let best_quant_text = match fit.runtime {
+        llmfit_core::fit::InferenceRuntime::LlamaCpp => {
+            // The same pool and KV context the fit level was judged against.
+            let budget = fit.memory_available_gb;
+            let pool = match fit.run_mode {
+                RunMode::CpuOffload | RunMode::CpuOnly => "RAM",
+                _ if app.specs.unified_memory => "unified memory",
+                _ => "VRAM",
+            };
+            let kv_ctx = fit
+                .effective_context_length
+                .saturating_mul(fit.batch_size.max(1));
+            match estimate_quantization_for_target_memory(&fit.model, budget, kv_ctx) {
+                Some(q) => format!(" For your {budget:.0} GB {pool}: use {q}"),
+                None => format!(" Nothing fits your {budget:.0} GB {pool}, even at Q2_K"),
+            }
+        }
+        _ => format!(" {} (for this hardware)", fit.best_quant),
+    };
+
     let mut lines = vec![
         Line::from(""),
         Line::from(vec![
@@ -1778,9 +2084,17 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         ]),
         Line::from(vec![
             Span::styled("  Best Quant:  ", Style::default().fg(tc.muted)),
+            Span::styled(best_quant_text, Style::default().fg(tc.good)),
+        ]),
+        Line::from(vec![
+            Span::styled("  Force quant: ", Style::default().fg(tc.muted)),
             Span::styled(
-                format!(" {} (for this hardware)", fit.best_quant),
-                Style::default().fg(tc.good),
+                format!("{} (Q to cycle)", app.detail_force_quant.unwrap_or("auto")),
+                Style::default().fg(if app.detail_force_quant.is_some() {
+                    tc.warning
+                } else {
+                    tc.fg
+                }),
             ),
         ]),
         Line::from(vec![
@@ -1789,6 +2103,14 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                 format!("{} tokens", fit.model.context_length),
                 Style::default().fg(tc.fg),
             ),
+            Span::styled(
+                rope_context_text(&fit.model),
+                Style::default().fg(if fit.beyond_trained_context() {
+                    tc.warning
+                } else {
+                    tc.muted
+                }),
+            ),
         ]),
         Line::from(vec![
             Span::styled("  Use Case:    ", Style::default().fg(tc.muted)),
@@ -1796,7 +2118,14 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         ]),
         Line::from(vec![
             Span::styled("  Category:    ", Style::default().fg(tc.muted)),
-            Span::styled(fit.use_case.label(), Style::default().fg(tc.accent)),
+            Span::styled(
+                format!("{} {}", fit.use_case.emoji(), fit.use_case.label()),
+                Style::default().fg(tc.accent),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("               ", Style::default().fg(tc.muted)),
+            Span::styled(fit.use_case.description(), Style::default().fg(tc.muted)),
         ]),
         Line::from(vec![
             Span::styled("  Capabilities:", Style::default().fg(tc.muted)),
@@ -1858,9 +2187,19 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                     || app.vllm_available;
 
                 if !installed_providers.is_empty() {
+                    // Ollama reports the real quant and size of its copy.
+                    let ollama_copy = llmfit_core::providers::find_ollama_model(
+                        &fit.model.name,
+                        app.ollama_local_models(),
+                    )
+                    .map(|m| m.summary())
+                    .filter(|s| !s.is_empty());
                     let label = installed_providers
                         .iter()
-                        .map(|p| format!("✓ {p}"))
+                        .map(|p| match (*p, &ollama_copy) {
+                            ("Ollama", Some(copy)) => format!("✓ Ollama ({copy})"),
+                            _ => format!("✓ {p}"),
+                        })
                         .collect::<Vec<_>>()
                         .join("  ");
                     Span::styled(label, Style::default().fg(tc.good).bold())
@@ -1873,6 +2212,38 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         ]),
     ];
 
+    if let Some(groq_id) = app.groq_model_for(fit) {
+        lines.push(Line::from(vec![
+            Span::styled("  Cloud:       ", Style::default().fg(tc.muted)),
+            Span::styled(
+                format!("☁ Too big locally — run it on Groq as {groq_id}"),
+                Style::default().fg(tc.info),
+            ),
+        ]));
+    }
+
+    if let Some(note) = app.model_notes.get(&fit.model.name) {
+        if !note.note.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("  Notes:       ", Style::default().fg(tc.muted)),
+                Span::styled(note.note.clone(), Style::default().fg(tc.fg).italic()),
+            ]));
+        }
+        if !note.tags.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("  Tags:        ", Style::default().fg(tc.muted)),
+                Span::styled(
+                    note.tags
+                        .iter()
+                        .map(|t| format!("#{t}"))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    Style::default().fg(tc.accent),
+                ),
+            ]));
+        }
+    }
+
     // Scoring section
     let score_color = if fit.score >= 70.0 {
         tc.score_high
@@ -1925,6 +2296,16 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             ),
         ]),
     ]);
+    if let Some(watts) = fit.energy_estimate_watts() {
+        lines.push(Line::from(vec![
+            Span::styled("  Power:       ", Style::default().fg(tc.muted)),
+            Span::styled(format!("~{:.0} W", watts), Style::default().fg(tc.fg)),
+            Span::styled(
+                "  (GPU TDP × memory utilization × 0.8)",
+                Style::default().fg(tc.muted),
+            ),
+        ]));
+    }
 
     // MoE Architecture section
     if fit.model.is_moe {
@@ -2009,6 +2390,17 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                 format!("{} {}", fit_indicator(fit.fit_level), fit.fit_text()),
                 Style::default().fg(color).bold(),
             ),
+            Span::styled(
+                fit.fit_level_if_freed
+                    .map(|freed| {
+                        format!(
+                            "  ({} after closing apps)",
+                            llmfit_core::fit::fit_level_text(freed)
+                        )
+                    })
+                    .unwrap_or_default(),
+                Style::default().fg(tc.muted),
+            ),
         ]),
         Line::from(vec![
             Span::styled("  Run Mode:    ", Style::default().fg(tc.muted)),
@@ -2077,6 +2469,11 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                 ),
                 Style::default().fg(tc.muted),
             ),
+            if fit.actual_weights_gb.is_some() {
+                Span::styled("  actual size", Style::default().fg(tc.good))
+            } else {
+                Span::styled("  estimated", Style::default().fg(tc.muted))
+            },
         ]),
         Line::from(vec![
             Span::styled("  Disk (est):  ", Style::default().fg(tc.muted)),
@@ -2116,6 +2513,126 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     }
     lines.push(Line::from(disk_spans));
 
+    // Catalog-minimum (MoE) requirements have no terms to break down.
+    if let Some(breakdown) = fit.memory_breakdown() {
+        let segments = [
+            (breakdown.weights_gb, "█", tc.accent),
+            (breakdown.kv_cache_gb, "▓", tc.info),
+            (breakdown.activation_memory_gb, "▒", tc.warning),
+            (breakdown.overhead_gb, "░", tc.muted),
+        ];
+        let widths = memory_breakdown_widths(&breakdown, 24);
+        let mut bar_spans = vec![Span::styled(
+            "  Breakdown:   ",
+            Style::default().fg(tc.muted),
+        )];
+        for ((_, fill, color), width) in segments.iter().zip(widths) {
+            let cells = if width == 0 {
+                " ".to_string()
+            } else {
+                fill.repeat(width)
+            };
+            bar_spans.push(Span::styled(
+                format!("[{cells}]"),
+                Style::default().fg(*color),
+            ));
+        }
+        lines.push(Line::from(bar_spans));
+        let mut legend_spans = vec![Span::raw("               ")];
+        for ((gb, fill, color), label) in
+            segments
+                .iter()
+                .zip(["weights", "KV", "activations", "overhead"])
+        {
+            legend_spans.push(Span::styled(
+                format!("{fill} {label} {gb:.1}  "),
+                Style::default().fg(*color),
+            ));
+        }
+        legend_spans.push(Span::styled(
+            format!("= {:.1} GB", breakdown.total_gb),
+            Style::default().fg(tc.muted),
+        ));
+        lines.push(Line::from(legend_spans));
+    }
+
+    if app.show_memory_math {
+        lines.push(Line::from(Span::styled(
+            "  -- Memory Math --",
+            Style::default().fg(tc.accent),
+        )));
+        let math_line = |label: &'static str, text: String| {
+            Line::from(vec![
+                Span::styled(label, Style::default().fg(tc.muted)),
+                Span::styled(text, Style::default().fg(tc.fg)),
+            ])
+        };
+        if let Some(terms) = fit.memory_terms() {
+            lines.push(math_line(
+                "  Weights:     ",
+                format!(
+                    "{:.2}B params × {:.2} B/param ({}) = {:.2} GB",
+                    terms.params_b, terms.bytes_per_param, fit.best_quant, terms.weights_gb
+                ),
+            ));
+            // Batched servers hold one KV cache per request.
+            let ctx_text = if fit.batch_size > 1 {
+                format!(
+                    "({} ctx × {} requests)",
+                    fit.effective_context_length, fit.batch_size
+                )
+            } else {
+                format!("{} ctx", terms.context)
+            };
+            let kv_text = match terms.kv_shape {
+                Some(shape) => format!(
+                    "2 × {} layers × {} kv heads × {} dim × {} × {} B = {:.2} GB",
+                    shape.layers,
+                    shape.kv_heads,
+                    shape.head_dim,
+                    ctx_text,
+                    terms.kv_quant.bytes_per_element(),
+                    terms.kv_cache_gb
+                ),
+                None => format!(
+                    "{:.2}B params × {} × 8e-6 (approx.) = {:.2} GB",
+                    terms.params_b, ctx_text, terms.kv_cache_gb
+                ),
+            };
+            lines.push(math_line("  KV cache:    ", kv_text));
+            lines.push(math_line(
+                "  Overhead:    ",
+                format!("{:.2} GB", terms.overhead_gb),
+            ));
+            if let Some(actual) = fit.actual_weights_gb {
+                lines.push(math_line(
+                    "  Installed:   ",
+                    format!(
+                        "{:.2} GB of weights on disk replaces the {:.2} GB estimate",
+                        actual, terms.weights_gb
+                    ),
+                ));
+            }
+            lines.push(math_line(
+                "  Total:       ",
+                format!(
+                    "{:.2} GB vs {:.2} GB available",
+                    fit.memory_required_gb, fit.memory_available_gb
+                ),
+            ));
+        } else {
+            lines.push(Line::from(Span::styled(
+                "  Requirement taken from the catalog's published minimums",
+                Style::default().fg(tc.muted),
+            )));
+        }
+    } else {
+        lines.push(Line::from(Span::styled(
+            "  M: show memory math",
+            Style::default().fg(tc.muted),
+        )));
+    }
+
     if fit.model.params_b() > 0.0 {
         lines.push(Line::from(Span::styled(
             "  -- VRAM by Context --",
@@ -2144,7 +2661,7 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
                 tc.error
             };
 
-            let ctx_label = format!("{}K", ctx / 1024);
+            let ctx_label = format_context(ctx);
             lines.push(Line::from(vec![
                 Span::styled(
                     format!("  {:>4} ctx:   ", ctx_label),
@@ -2158,9 +2675,14 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         }
     }
 
-    // Build right-pane content (GGUF sources + notes)
-    let has_right_pane =
-        !fit.model.gguf_sources.is_empty() || !fit.notes.is_empty() || fit.fits_with_turboquant;
+    // Build right-pane content (GGUF sources + Docker command + notes)
+    let docker_command = fit.docker_run_command(app.specs.backend);
+    let advice = fit.contextual_notes(fit.use_case);
+    let has_right_pane = !fit.model.gguf_sources.is_empty()
+        || docker_command.is_some()
+        || !fit.notes.is_empty()
+        || !advice.is_empty()
+        || fit.fits_with_turboquant;
 
     // Pre-compute right pane inner width for line-wrapping decisions
     // (45% of area minus 2 border columns)
@@ -2213,6 +2735,19 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         right_lines.push(Line::from(""));
     }
 
+    if let Some(cmd) = &docker_command {
+        right_lines.push(Line::from(Span::styled(
+            "  ── Docker Command ──",
+            Style::default().fg(tc.accent),
+        )));
+        right_lines.push(Line::from(""));
+        right_lines.push(Line::from(Span::styled(
+            format!("  {}", cmd),
+            Style::default().fg(tc.muted),
+        )));
+        right_lines.push(Line::from(""));
+    }
+
     if !fit.notes.is_empty() {
         right_lines.push(Line::from(Span::styled(
             "  ── Notes ──",
@@ -2220,10 +2755,25 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         )));
         right_lines.push(Line::from(""));
         for note in &fit.notes {
-            right_lines.push(Line::from(Span::styled(
-                format!("  {}", note),
-                Style::default().fg(tc.fg),
-            )));
+            for line in wrap_note(note, right_inner_width) {
+                right_lines.push(Line::from(Span::styled(line, Style::default().fg(tc.fg))));
+            }
+        }
+    }
+
+    if !advice.is_empty() {
+        if !fit.notes.is_empty() {
+            right_lines.push(Line::from(""));
+        }
+        right_lines.push(Line::from(Span::styled(
+            format!("  ── {} Tips ──", fit.use_case.label()),
+            Style::default().fg(tc.accent),
+        )));
+        right_lines.push(Line::from(""));
+        for note in &advice {
+            for line in wrap_note(note, right_inner_width) {
+                right_lines.push(Line::from(Span::styled(line, Style::default().fg(tc.fg))));
+            }
         }
     }
 
@@ -2241,6 +2791,7 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
 
     // Track the left pane area for cursor positioning
     let left_area;
+    let mut notes_max_scroll = 0;
 
     if has_right_pane {
         // Split into left (model info) and right (downloads + notes) panes
@@ -2267,6 +2818,17 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
         } else {
             " Notes "
         };
+        // Notes are pre-wrapped, so the line count is the rendered height.
+        let max_scroll = right_lines
+            .len()
+            .saturating_sub(h_layout[1].height.saturating_sub(2) as usize);
+        notes_max_scroll = max_scroll;
+        let scroll = app.detail_notes_scroll.min(max_scroll);
+        let right_title = if max_scroll > 0 {
+            format!("{right_title}[/] scroll {scroll}/{max_scroll} ")
+        } else {
+            right_title.to_string()
+        };
         let right_block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(tc.border))
@@ -2275,6 +2837,7 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
 
         let right_paragraph = Paragraph::new(right_lines)
             .block(right_block)
+            .scroll((scroll as u16, 0))
             .wrap(Wrap { trim: false });
         frame.render_widget(right_paragraph, h_layout[1]);
     } else {
@@ -2307,6 +2870,7 @@ fn draw_detail(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
             frame.set_cursor_position((x, y));
         }
     }
+    notes_max_scroll
 }
 
 fn draw_plan(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
@@ -2583,7 +3147,7 @@ fn draw_provider_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
     let max_name_len = app.providers.iter().map(|p| p.len()).max().unwrap_or(10);
     // Width must also fit the search box / hint line.
     let popup_width = (max_name_len as u16 + 10)
-        .max(28)
+        .max(36)
         .min(area.width.saturating_sub(4));
     // +2 borders, +1 search row. List body shows at most all matches.
     let list_rows = (filtered.len().max(1) as u16).min(area.height.saturating_sub(6));
@@ -2716,7 +3280,14 @@ fn draw_provider_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
                         .fg(tc.accent_secondary)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(": clear ", Style::default().fg(tc.muted)),
+                Span::styled(": clear | ", Style::default().fg(tc.muted)),
+                Span::styled(
+                    "^i",
+                    Style::default()
+                        .fg(tc.accent_secondary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(": invert ", Style::default().fg(tc.muted)),
             ])
             .centered(),
         );
@@ -2985,9 +3556,13 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
                 );
             }
             let detail_key = if app.show_detail {
-                "Enter:table"
+                "Enter:table  M:mem math"
+            } else if app.family_at_row(app.selected_row).is_some() {
+                "Enter:expand  G:ungroup"
+            } else if app.grouped_view {
+                "Enter:detail  G:ungroup"
             } else {
-                "Enter:detail"
+                "Enter:detail  G:group"
             };
             let any_provider = app.ollama_available
                 || app.mlx_available
@@ -3007,7 +3582,7 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
             };
             (
                 format!(
-                    " S:simulate  A:config  b:benchmarks  I:live-bench  h:help  {}  /:search  f:fit  F:filter  s:sort{}  P:providers  U:use cases  C:caps  R:runtime  q:quit",
+                    " S:simulate  A:config  b:benchmarks  I:live-bench  h:help  {}  /:search  f:fit  1-4:jump fit  F:filter  s:sort{}  P:providers  U:use cases  C:caps  R:runtime  q:quit",
                     detail_key, ollama_keys,
                 ),
                 if app.sim_active {
@@ -3042,6 +3617,10 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
             "  Type to search  Esc:done  Ctrl-U:clear".to_string(),
             "SEARCH".to_string(),
         ),
+        InputMode::NoteEdit => (
+            "  Type a note, #word adds a tag  Enter:save  Esc:cancel  Ctrl-U:clear".to_string(),
+            "NOTE".to_string(),
+        ),
         InputMode::Plan => (
             "  Tab/↑↓:field  ←/→:cursor  type:edit  Backspace/Delete  Ctrl-U:clear  Esc:close"
                 .to_string(),
@@ -3087,6 +3666,14 @@ fn status_keys_and_mode(app: &App) -> (String, String) {
             "  ↑↓/jk:scroll  Esc/h/q:close".to_string(),
             "HELP".to_string(),
         ),
+        InputMode::HistoryPopup => (
+            "  Esc/H/q:close  D:manage downloads".to_string(),
+            "HISTORY".to_string(),
+        ),
+        InputMode::NewModelsPopup => (
+            "  ↑↓/jk:scroll  Enter:search model  Esc/N/q:close".to_string(),
+            "NEW MODELS".to_string(),
+        ),
         InputMode::Simulation => (
             "  Tab/jk:field  type:edit  Enter:apply  Ctrl-R:reset  Esc:close".to_string(),
             "SIMULATION".to_string(),
@@ -3620,15 +4207,22 @@ fn draw_help_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
         ("Navigation", ""),
         ("  ↑ / k", "Move up"),
         ("  ↓ / j", "Move down"),
+        ("  Ctrl-D/U", "Half page down / up"),
+        ("  Ctrl-F/B", "Page down / up (also PgDn/PgUp)"),
         ("  Enter", "Toggle detail view"),
+        ("  M", "Show memory math (in detail view)"),
+        ("  Q", "Cycle forced quant (in detail view)"),
+        ("  [ / ]", "Scroll notes (in detail view)"),
         ("  /", "Search"),
         ("  Ctrl-U", "Clear search"),
         ("", ""),
         ("Filters", ""),
         ("  f", "Cycle fit filter"),
-        ("  F", "Filter popup (range, sort dir)"),
+        ("  1-4", "Jump to Perfect/Good/Marginal/Tight"),
+        ("  F", "Filter popup (range, target tok/s, sort dir)"),
         ("  a", "Cycle availability filter"),
         ("  T", "Cycle tensor-parallel filter"),
+        ("  B", "Cycle batch size (1/2/4/8/16 requests)"),
         ("  P", "Provider filter"),
         ("  U", "Use case filter"),
         ("  C", "Capability filter"),
@@ -3638,13 +4232,22 @@ fn draw_help_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
         ("Sorting & Display", ""),
         ("  s", "Cycle sort column"),
         ("  i", "Toggle installed-first sort"),
+        ("  z", "Pin installed models above the rest"),
+        ("  G", "Group by model family (Enter expands)"),
+        ("  X", "Params: total / active (MoE)"),
         ("  t", "Cycle theme"),
+        ("  K", "Cycle context units (128k / 131072 / 131,072)"),
+        ("  E", "Export view to llmfit-view.json"),
+        ("  O", "Import view from llmfit-view.json"),
         ("", ""),
         ("Actions", ""),
         ("  S", "Hardware simulation"),
         ("  A", "Advanced configuration"),
         ("  d", "Download/pull model"),
+        ("  Ctrl-E", "Quit and print install script (Perfect/Good)"),
         ("  r", "Refresh installed models"),
+        ("  H", "Recent download history"),
+        ("  N", "Models added since last run"),
         ("  p", "Plan mode"),
         ("  b", "Community Leaderboard (localmaxxing.com)"),
         (
@@ -3654,6 +4257,8 @@ fn draw_help_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
         ("  H", "Change GPU (in community leaderboard view)"),
         ("  /", "Search results (in community leaderboard view)"),
         ("  y", "Copy model name"),
+        ("  Y", "Copy run command (ollama/llama.cpp/MLX/vLLM)"),
+        ("  n", "Edit note/#tags (search #tag to filter)"),
         ("", ""),
         ("Comparison", ""),
         ("  m", "Mark model for compare"),
@@ -3716,6 +4321,151 @@ fn draw_help_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
     frame.render_widget(paragraph, popup_area);
 }
 
+fn draw_new_models_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
+    let area = frame.area();
+
+    let popup_width = 72.min(area.width.saturating_sub(4));
+    let popup_height = (app.new_models.len().max(1) as u16 + 2).min(area.height.saturating_sub(4));
+
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.accent_secondary))
+        .title(format!(
+            " {} New Models Since Last Run ",
+            app.new_models.len()
+        ))
+        .title_style(
+            Style::default()
+                .fg(tc.accent_secondary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    if app.new_models.is_empty() {
+        let paragraph = Paragraph::new(Line::from(Span::styled(
+            "  No new models since last run",
+            Style::default().fg(tc.muted),
+        )))
+        .block(block);
+        frame.render_widget(paragraph, popup_area);
+        return;
+    }
+
+    let inner_height = popup_height.saturating_sub(2) as usize;
+    let scroll_offset = if app.new_models_cursor >= inner_height {
+        app.new_models_cursor - inner_height + 1
+    } else {
+        0
+    };
+
+    let lines: Vec<Line> = app
+        .new_models
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(inner_height)
+        .map(|(i, name)| {
+            let selected = i == app.new_models_cursor;
+            let style = if selected {
+                Style::default().fg(tc.fg).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(tc.fg)
+            };
+            Line::from(vec![
+                Span::styled(if selected { " ▶ " } else { "   " }, style),
+                Span::styled(name.as_str(), style),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+/// Number of downloads shown in the `H` history popup.
+const HISTORY_POPUP_LIMIT: usize = 20;
+
+fn draw_history_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
+    let area = frame.area();
+
+    let shown = app.download_history.records.len().min(HISTORY_POPUP_LIMIT);
+    let popup_width = 80.min(area.width.saturating_sub(4));
+    let popup_height = (shown.max(1) as u16 + 3).min(area.height.saturating_sub(4));
+
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(tc.accent_secondary))
+        .title(format!(" Download History (last {}) ", shown))
+        .title_style(
+            Style::default()
+                .fg(tc.accent_secondary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    if shown == 0 {
+        let paragraph = Paragraph::new(Line::from(Span::styled(
+            "  No downloads yet",
+            Style::default().fg(tc.muted),
+        )))
+        .block(block);
+        frame.render_widget(paragraph, popup_area);
+        return;
+    }
+
+    let header = Row::new(vec![
+        Cell::from("Date").style(Style::default().fg(tc.accent).bold()),
+        Cell::from("Model").style(Style::default().fg(tc.accent).bold()),
+        Cell::from("Provider").style(Style::default().fg(tc.accent).bold()),
+        Cell::from("Size").style(Style::default().fg(tc.accent).bold()),
+        Cell::from("Status").style(Style::default().fg(tc.accent).bold()),
+    ]);
+
+    let rows: Vec<Row> = app
+        .download_history
+        .recent(HISTORY_POPUP_LIMIT)
+        .map(|record| {
+            let (status_text, status_color) = match &record.result {
+                DownloadResult::Success => ("✓ Done", tc.good),
+                DownloadResult::Error(_) => ("✗ Error", tc.error),
+            };
+            let size = record
+                .size_gb
+                .map(|gb| format!("{:.1} GB", gb))
+                .unwrap_or_else(|| "—".to_string());
+            Row::new(vec![
+                Cell::from(format_epoch(record.timestamp)).style(Style::default().fg(tc.muted)),
+                Cell::from(record.model_name.clone()).style(Style::default().fg(tc.fg)),
+                Cell::from(record.provider.clone()).style(Style::default().fg(tc.muted)),
+                Cell::from(size).style(Style::default().fg(tc.muted)),
+                Cell::from(status_text).style(Style::default().fg(status_color)),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Min(24),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Length(8),
+    ];
+
+    frame.render_widget(
+        Table::new(rows, widths).header(header).block(block),
+        popup_area,
+    );
+}
+
 fn draw_runtime_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
     let area = frame.area();
 
@@ -4346,53 +5096,6 @@ fn draw_dm_history(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
     frame.render_widget(table, area);
 }
 
-/// Format epoch seconds as a simple date string.
-fn format_epoch(epoch: u64) -> String {
-    // Simple date formatting without external crate
-    let secs_per_day: u64 = 86400;
-    let days = epoch / secs_per_day;
-
-    // Days since 1970-01-01
-    let mut y = 1970i32;
-    let mut remaining = days;
-    loop {
-        let days_in_year = if y % 4 == 0 && (y % 100 != 0 || y % 400 == 0) {
-            366u64
-        } else {
-            365
-        };
-        if remaining < days_in_year {
-            break;
-        }
-        remaining -= days_in_year;
-        y += 1;
-    }
-    let leap = y % 4 == 0 && (y % 100 != 0 || y % 400 == 0);
-    let month_days = [
-        31,
-        if leap { 29 } else { 28 },
-        31,
-        30,
-        31,
-        30,
-        31,
-        31,
-        30,
-        31,
-        30,
-        31,
-    ];
-    let mut m = 0usize;
-    for &md in &month_days {
-        if remaining < md {
-            break;
-        }
-        remaining -= md;
-        m += 1;
-    }
-    format!("{:04}-{:02}-{:02}", y, m + 1, remaining + 1)
-}
-
 fn draw_benchmarks(frame: &mut Frame, app: &mut App, area: Rect, tc: &ThemeColors) {
     let block = Block::default()
         .borders(Borders::ALL)
@@ -4745,7 +5448,7 @@ fn draw_filter_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
 
     let area = frame.area();
     let popup_width = 56u16.min(area.width.saturating_sub(4));
-    let popup_height = 21u16.min(area.height.saturating_sub(4));
+    let popup_height = 25u16.min(area.height.saturating_sub(4));
     let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
     let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
     let popup_area = Rect::new(x, y, popup_width, popup_height);
@@ -4848,6 +5551,41 @@ fn draw_filter_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
 
     lines.push(Line::from(""));
 
+    // Target Speed (tok/s)
+    lines.push(Line::from(Span::styled(
+        "  Target Speed (tok/s):",
+        Style::default().fg(tc.accent).bold(),
+    )));
+
+    let is_target = app.filter_field == FilterPopupField::TargetTps;
+    let target_val = if app.filter_target_tps_input.is_empty() && !is_target {
+        "none".to_string()
+    } else {
+        app.filter_target_tps_input.clone()
+    };
+    lines.push(Line::from(vec![
+        Span::styled("    Min: ", label_style(is_target)),
+        Span::styled(format!("{:<12}", target_val), value_style(is_target)),
+    ]));
+
+    let is_target_only = app.filter_field == FilterPopupField::TargetTpsOnly;
+    let (only_text, only_color) = if app.filter_target_tps_only {
+        ("Hide slower", tc.good)
+    } else {
+        ("Highlight", tc.fg)
+    };
+    let only_val_style = if is_target_only {
+        Style::default().fg(only_color).bg(tc.highlight_bg)
+    } else {
+        Style::default().fg(only_color)
+    };
+    lines.push(Line::from(vec![
+        Span::styled("    Mode:", label_style(is_target_only)),
+        Span::styled(format!(" {:>12}", only_text), only_val_style),
+    ]));
+
+    lines.push(Line::from(""));
+
     // Sort Direction
     lines.push(Line::from(Span::styled(
         "  Sort:",
@@ -4881,7 +5619,10 @@ fn draw_filter_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
     let is_fit = app.filter_field == FilterPopupField::FitFilter;
     let fit_color = match app.fit_filter {
         FitFilter::All => tc.fg,
-        FitFilter::Runnable | FitFilter::Perfect | FitFilter::TurboQuantFit => tc.good,
+        FitFilter::Runnable
+        | FitFilter::Perfect
+        | FitFilter::TurboQuantFit
+        | FitFilter::Installed => tc.good,
         FitFilter::Good => tc.warning,
         FitFilter::Marginal => tc.fit_marginal,
         FitFilter::TooTight => tc.error,
@@ -4909,6 +5650,7 @@ fn draw_filter_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
         AvailabilityFilter::All => tc.fg,
         AvailabilityFilter::HasGguf => tc.info,
         AvailabilityFilter::Installed => tc.good,
+        AvailabilityFilter::Upgrades => tc.accent,
     };
     let avail_val_style = if is_avail {
         Style::default().fg(avail_color).bg(tc.highlight_bg)
@@ -4937,17 +5679,20 @@ fn draw_filter_popup(frame: &mut Frame, app: &App, tc: &ThemeColors) {
     // Row offsets account for section headers and blank separator lines:
     //  0: "Parameters (B):"    1: Min  2: Max  3: (blank)
     //  4: "Memory Usage (%):"  5: Min  6: Max  7: (blank)
-    //  8: "Sort:"              9: Direction     10: (blank)
-    // 11: "Fit Filter:"       12: Fit           13: (blank)
-    // 14: "Availability:"     15: Show
+    //  8: "Target Speed:"      9: Min 10: Mode  11: (blank)
+    // 12: "Sort:"             13: Direction     14: (blank)
+    // 15: "Fit Filter:"       16: Fit           17: (blank)
+    // 18: "Availability:"     19: Show
     let field_row: u16 = match app.filter_field {
         FilterPopupField::ParamsMin => 1,
         FilterPopupField::ParamsMax => 2,
         FilterPopupField::MemPctMin => 5,
         FilterPopupField::MemPctMax => 6,
-        FilterPopupField::SortDirection => 9,
-        FilterPopupField::FitFilter => 12,
-        FilterPopupField::Availability => 15,
+        FilterPopupField::TargetTps => 9,
+        FilterPopupField::TargetTpsOnly => 10,
+        FilterPopupField::SortDirection => 13,
+        FilterPopupField::FitFilter => 16,
+        FilterPopupField::Availability => 19,
     };
 
     // "    Min: " / "    Max: " = 9 chars label
@@ -4979,6 +5724,71 @@ fn bench_bar(score: f64, width: usize) -> String {
     format!("{}{}", "█".repeat(filled), "░".repeat(empty))
 }
 
+/// Word-wrap a note for the detail view's notes pane: every line is indented
+/// two columns and fits in `width` terminal cells. Words wider than a line
+/// are split across lines.
+fn wrap_note(note: &str, width: usize) -> Vec<String> {
+    const INDENT: &str = "  ";
+    let room = width.saturating_sub(INDENT.len()).max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in note.split_whitespace() {
+        let word_width = word.width();
+        let needed = if line.is_empty() {
+            word_width
+        } else {
+            line_width + 1 + word_width
+        };
+        if needed <= room {
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+            line_width = needed;
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(format!("{INDENT}{line}"));
+            line.clear();
+            line_width = 0;
+        }
+        for grapheme in word.graphemes(true) {
+            let grapheme_width = grapheme.width();
+            if line_width + grapheme_width > room && !line.is_empty() {
+                lines.push(format!("{INDENT}{line}"));
+                line.clear();
+                line_width = 0;
+            }
+            line.push_str(grapheme);
+            line_width += grapheme_width;
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(format!("{INDENT}{line}"));
+    }
+    lines
+}
+
+/// Cells per segment of the detail pane's memory breakdown bar: weights, KV
+/// cache, activations and overhead in proportion to their share of the total
+/// across `width` cells, with at least one cell for any nonzero component.
+fn memory_breakdown_widths(breakdown: &MemoryBreakdown, width: usize) -> [usize; 4] {
+    let parts = [
+        breakdown.weights_gb,
+        breakdown.kv_cache_gb,
+        breakdown.activation_memory_gb,
+        breakdown.overhead_gb,
+    ];
+    parts.map(|gb| {
+        if gb <= 0.0 || breakdown.total_gb <= 0.0 {
+            0
+        } else {
+            ((gb / breakdown.total_gb * width as f64).round() as usize).max(1)
+        }
+    })
+}
+
 fn bench_get_role_quality(
     results: &[llmfit_core::quality::ModelQualityResult],
     model: &str,
@@ -5649,6 +6459,43 @@ fn draw_bench(frame: &mut Frame, app: &App, area: Rect, tc: &ThemeColors) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn wrap_note_fits_long_notes_to_the_pane_width() {
+        let note = "Fit improves to Perfect after closing other apps (12.4 GB of RAM in use) \
+                    and the model would run entirely on the GPU";
+        let lines = wrap_note(note, 32);
+        assert_eq!(lines.len(), 4);
+        assert!(lines.iter().all(|l| l.starts_with("  ") && l.width() <= 32));
+        let rejoined = lines.join(" ");
+        assert!(rejoined.split_whitespace().eq(note.split_whitespace()));
+
+        // Short notes stay on one line; words wider than the pane are split.
+        assert_eq!(wrap_note("No GPU", 32), vec!["  No GPU"]);
+        assert_eq!(wrap_note("abcdefghij", 7), vec!["  abcde", "  fghij"]);
+        assert_eq!(wrap_note("", 32), vec!["  "]);
+    }
+
+    #[test]
+    fn memory_breakdown_widths_are_proportional_with_a_floor() {
+        let breakdown = MemoryBreakdown {
+            weights_gb: 6.0,
+            kv_cache_gb: 1.5,
+            activation_memory_gb: 0.05,
+            overhead_gb: 0.0,
+            total_gb: 7.55,
+        };
+        // Tiny components still get a cell; empty ones get none.
+        assert_eq!(memory_breakdown_widths(&breakdown, 24), [19, 5, 1, 0]);
+        let empty = MemoryBreakdown {
+            weights_gb: 0.0,
+            kv_cache_gb: 0.0,
+            activation_memory_gb: 0.0,
+            overhead_gb: 0.0,
+            total_gb: 0.0,
+        };
+        assert_eq!(memory_breakdown_widths(&empty, 24), [0; 4]);
+    }
+
     #[test]
     fn truncate_str_handles_multibyte_utf8() {
         // ASCII — no truncation needed
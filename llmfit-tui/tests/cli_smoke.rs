@@ -78,6 +78,50 @@ fn list_json_returns_non_empty_catalog() {
     assert!(first.contains_key("provider"));
 }
 
+#[test]
+fn system_json_exposes_gpu_fields_for_scripting() {
+    let json = run_json_command(&["--no-dashboard", "--json", "system"]);
+    let system = &json["system"];
+
+    for key in ["total_ram_gb", "cpu_name", "has_gpu", "gpu_vram_gb", "gpus"] {
+        assert!(system.get(key).is_some(), "system JSON missing {key}");
+    }
+}
+
+#[test]
+fn list_format_json_matches_global_json_flag() {
+    let via_format = run_json_command(&["--no-dashboard", "list", "--format", "json"]);
+    let via_flag = run_json_command(&["--no-dashboard", "--json", "list"]);
+    // Catalog order isn't stable across runs, so compare sizes only.
+    assert_eq!(
+        via_format.as_array().map(Vec::len),
+        via_flag.as_array().map(Vec::len)
+    );
+
+    let first = &via_format[0];
+    for key in ["name", "provider", "parameter_count", "context_length"] {
+        assert!(first.get(key).is_some(), "model JSON missing {key}");
+    }
+}
+
+#[test]
+fn list_format_csv_has_header_and_rows() {
+    let output = Command::cargo_bin("llmfit")
+        .expect("failed to locate llmfit test binary")
+        .args(["--no-dashboard", "list", "--format", "csv"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let text = String::from_utf8(output).expect("CSV output was not UTF-8");
+    let mut lines = text.lines();
+    let header = lines.next().expect("CSV output was empty");
+    assert!(header.starts_with("name,provider,parameter_count,"));
+    assert!(lines.next().is_some(), "CSV output has no model rows");
+}
+
 #[test]
 fn fit_json_obeys_limit_and_contains_models_field() {
     let json = run_json_command(&[
@@ -156,6 +200,34 @@ fn recommend_capability_filter_does_not_ignore_unknown_or_tts() {
     );
 }
 
+#[test]
+fn recommend_honours_global_quant_flag() {
+    let json = run_json_command(&[
+        "--no-dashboard",
+        "--json",
+        "--memory",
+        "24G",
+        "--ram",
+        "32G",
+        "--cpu-cores",
+        "8",
+        "--quant",
+        "Q2_K",
+        "recommend",
+        "--runtime",
+        "llamacpp",
+        "-n",
+        "5",
+    ]);
+    let models = models_array(&json);
+    assert!(!models.is_empty());
+    assert!(
+        models
+            .iter()
+            .all(|model| model.get("best_quant").and_then(Value::as_str) == Some("Q2_K"))
+    );
+}
+
 #[test]
 fn fit_json_returns_empty_models_when_no_perfect_matches() {
     let json = run_json_command(&[
@@ -190,3 +262,49 @@ fn cpu_cores_parser_rejects_zero() {
         .assert()
         .failure();
 }
+
+#[test]
+fn backend_parser_rejects_unknown_names() {
+    let output = Command::cargo_bin("llmfit")
+        .expect("failed to locate llmfit test binary")
+        .args(["--backend", "quantum", "--json", "system"])
+        .output()
+        .expect("failed to run llmfit");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("unknown backend: quantum"), "{stderr}");
+    assert!(stderr.contains("--help"), "{stderr}");
+}
+
+#[test]
+fn export_install_script_prints_a_bash_script() {
+    let output = Command::cargo_bin("llmfit")
+        .expect("failed to locate llmfit test binary")
+        .args([
+            "--no-dashboard",
+            "--memory",
+            "64G",
+            "export-install-script",
+            "--filter",
+            "fit=marginal+",
+            "--provider",
+            "ollama",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let text = String::from_utf8(output).expect("script output was not UTF-8");
+    assert!(text.starts_with("#!/usr/bin/env bash\n"));
+    for line in text
+        .lines()
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+    {
+        assert!(
+            line == "set -euo pipefail" || line.starts_with("ollama pull "),
+            "unexpected script line: {line}"
+        );
+    }
+}
@@ -370,6 +370,16 @@ CONTEXT_LENGTH_OVERRIDES = {
     "NC-AI-consortium-VAETKI/VAETKI": 32_768,
 }
 
+# Longest window reachable with rope scaling beyond context_length, where the
+# model card documents one that config.json doesn't (Qwen2.5: YaRN to 128k).
+MAX_CONTEXT_OVERRIDES = {
+    "Qwen/Qwen2.5-7B-Instruct": 131_072,
+    "Qwen/Qwen2.5-72B-Instruct": 131_072,
+    "Qwen/Qwen2.5-Coder-7B-Instruct": 131_072,
+    "Qwen/Qwen2.5-Coder-14B-Instruct": 131_072,
+    "Qwen/Qwen2.5-Coder-32B-Instruct": 131_072,
+}
+
 
 def fetch_model_info(repo_id: str) -> dict | None:
     """Fetch model info from HuggingFace API."""
@@ -711,6 +721,29 @@ def infer_context_length(config: dict | None) -> int:
     return 4096
 
 
+def infer_trained_context(config: dict | None, context_length: int) -> int | None:
+    """Pre-training window when ``context_length`` was reached by RoPE scaling.
+
+    ``rope_scaling.original_max_position_embeddings`` is preferred (YaRN
+    configs carry it); otherwise the un-scaled ``max_position_embeddings``.
+    Returns ``None`` when the advertised window is all native.
+    """
+    if not config:
+        return None
+    for cfg in (config, config.get("text_config")):
+        if not isinstance(cfg, dict):
+            continue
+        rope = cfg.get("rope_scaling")
+        if not isinstance(rope, dict):
+            continue
+        native = rope.get("original_max_position_embeddings") or cfg.get(
+            "max_position_embeddings"
+        )
+        if isinstance(native, int) and 0 < native < context_length:
+            return native
+    return None
+
+
 def fetch_config_json(repo_id: str) -> dict | None:
     """Fetch the full config.json from a HF repo (has max_position_embeddings)."""
     url = f"https://huggingface.co/{repo_id}/resolve/main/config.json"
@@ -999,6 +1032,8 @@ def scrape_model(repo_id: str) -> dict | None:
         "quantization": default_quant,
         "format": model_format,
         "context_length": context_length,
+        "trained_context": infer_trained_context(full_config or config, context_length),
+        "max_context": MAX_CONTEXT_OVERRIDES.get(repo_id),
         "use_case": use_case_str,
         "capabilities": infer_capabilities(repo_id, pipeline_tag, use_case_str),
         "languages": infer_languages(info, full_config or config),
@@ -1700,6 +1735,8 @@ def _build_discovered_model(listing: dict) -> dict | None:
         "quantization": default_quant,
         "format": model_format,
         "context_length": context_length,
+        "trained_context": infer_trained_context(full_config or config, context_length),
+        "max_context": MAX_CONTEXT_OVERRIDES.get(repo_id),
         "use_case": use_case_str,
         "capabilities": infer_capabilities(repo_id, pipeline_tag, use_case_str),
         "languages": infer_languages(listing, full_config or config),